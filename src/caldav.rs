@@ -0,0 +1,957 @@
+// CALDAV: Two-way sync with a self-hosted calendar collection (Radicale, Nextcloud and the like).
+// Every exported task is one resource, <collection>/<task id>.ics, written by export::task_ics.
+// The ledger remembers each resource's ETag and which version of the task the server holds:
+// - a task changed here is PUT with If-Match, so an edit made on the server meanwhile is never overwritten blindly;
+//   so is one whose ICS took another shape because the export settings changed;
+// - a resource whose ETag moved was changed on the server, and its times come back into the task;
+// - both at once is a conflict, and the newer side (LAST-MODIFIED against the task's last change) wins.
+// Tasks deleted or archived here are deleted on the server; one that only left the export stays there, as last sent.
+// A resource deleted on the server is written again.
+// https:// goes through the system's TLS library; plain http:// is meant for a server on this computer.
+// The password is kept in the OS keyring (settings.json only holds it when no keyring is available).
+
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::future::Future;
+use std::io::{Read, Write};
+use std::net::TcpStream;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+use std::thread;
+use std::time::Duration;
+use chrono::{DateTime, Local};
+use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use crate::export;
+use crate::ics;
+use crate::settings::SettingsState;
+use crate::store::{data_file_path, AppState};
+
+const LEDGER_FILE: &str = "caldav.json";
+/// How long one request may take before the sync gives up
+const TIMEOUT: Duration = Duration::from_secs(15);
+const PROPFIND_BODY: &str = r#"<?xml version="1.0" encoding="utf-8"?><d:propfind xmlns:d="DAV:"><d:prop><d:getetag/></d:prop></d:propfind>"#;
+
+// ─── HTTP ────────────────────────────────────────────────────────────────────
+
+/// CLIENT: The collection's address and credentials
+#[derive(Debug, Clone, PartialEq)]
+pub struct Client {
+    host: String,
+    port: u16,
+    path: String,         // The collection path, always ending in '/'
+    auth: Option<String>, // Basic credentials, already encoded
+    tls: bool,            // https://
+}
+
+/// A connection to the server, plain or encrypted
+trait Connection: Read + Write {}
+
+impl<T: Read + Write> Connection for T {}
+
+/// A server answer, with the body already de-chunked
+struct Response {
+    status: u16,
+    headers: Vec<(String, String)>,
+    body: String,
+}
+
+impl Response {
+    fn header(&self, name: &str) -> Option<&str> {
+        self.headers.iter().find(|(n, _)| n.eq_ignore_ascii_case(name)).map(|(_, v)| v.as_str())
+    }
+}
+
+/// Precondition of a PUT: the resource must not exist yet, or must still have this ETag
+pub enum Expect<'a> {
+    New,
+    Etag(&'a str),
+}
+
+/// Outcome of a PUT
+#[derive(Debug, PartialEq)]
+pub enum Put {
+    Stored(Option<String>), // Written; the new ETag when the server sends it
+    Conflict,               // The precondition failed: the server copy changed (412)
+}
+
+impl Client {
+    /// Reads a collection URL such as https://cloud.example.com/remote.php/dav/calendars/me/tasks/
+    /// or http://localhost:5232/me/tasks/
+    pub fn new(url: &str, username: &str, password: &str) -> Result<Client, String> {
+        let url = url.trim();
+        let (rest, tls) = match url.split_once("://") {
+            Some((scheme, rest)) if scheme.eq_ignore_ascii_case("http") => (rest, false),
+            Some((scheme, rest)) if scheme.eq_ignore_ascii_case("https") => (rest, true),
+            _ => return Err(format!("Not an http:// or https:// address: {url}")),
+        };
+        let (authority, path) = rest.split_once('/').map(|(a, p)| (a, format!("/{p}"))).unwrap_or((rest, "/".to_string()));
+        // An IPv6 literal is bracketed ("[::1]:5232"), so its own colons are not the port separator
+        let (host, port) = match authority.strip_prefix('[') {
+            Some(bracketed) => match bracketed.split_once(']') {
+                Some((host, "")) => (host, None),
+                Some((host, after)) => (host, Some(after.strip_prefix(':').ok_or_else(|| format!("Bad port in {url}"))?)),
+                None => return Err(format!("Unclosed [ in {url}")),
+            },
+            None => match authority.rsplit_once(':') {
+                Some((host, port)) => (host, Some(port)),
+                None => (authority, None),
+            },
+        };
+        let port = match port {
+            Some(port) => port.parse::<u16>().map_err(|_| format!("Bad port in {url}"))?,
+            None => if tls { 443 } else { 80 },
+        };
+        if host.is_empty() {
+            return Err(format!("No host in {url}"));
+        }
+        let path = if path.ends_with('/') { path } else { format!("{path}/") };
+        let auth = (!username.is_empty()).then(|| format!("Basic {}", base64(format!("{username}:{password}").as_bytes())));
+        Ok(Client { host: host.to_string(), port, path, auth, tls })
+    }
+
+    /// Whether credentials would cross the network unencrypted (plain http:// to another machine)
+    pub fn sends_in_clear(&self) -> bool {
+        !self.tls && !matches!(self.host.as_str(), "localhost" | "::1") && !self.host.starts_with("127.")
+    }
+
+    /// The path of a task's resource
+    fn resource(&self, name: &str) -> String {
+        format!("{}{name}", self.path)
+    }
+
+    /// Sends one request and reads the whole answer (one connection per request)
+    fn request(&self, method: &str, path: &str, headers: &[(&str, &str)], body: &str) -> Result<Response, String> {
+        let fail = |e: std::io::Error| format!("{}:{}: {e}", self.host, self.port);
+        let tcp = TcpStream::connect((self.host.as_str(), self.port)).map_err(fail)?;
+        tcp.set_read_timeout(Some(TIMEOUT)).map_err(fail)?;
+        tcp.set_write_timeout(Some(TIMEOUT)).map_err(fail)?;
+        let mut stream: Box<dyn Connection> = if self.tls {
+            let tls_fail = |e: &dyn std::fmt::Display| format!("{}:{}: TLS: {e}", self.host, self.port);
+            let connector = native_tls::TlsConnector::new().map_err(|e| tls_fail(&e))?;
+            Box::new(connector.connect(&self.host, tcp).map_err(|e| tls_fail(&e))?)
+        } else {
+            Box::new(tcp)
+        };
+
+        let host = if self.host.contains(':') { format!("[{}]", self.host) } else { self.host.clone() };
+        let mut head = format!("{method} {path} HTTP/1.1\r\nHost: {host}:{}\r\nConnection: close\r\nContent-Length: {}\r\n", self.port, body.len());
+        if let Some(auth) = &self.auth {
+            head.push_str(&format!("Authorization: {auth}\r\n"));
+        }
+        for (name, value) in headers {
+            head.push_str(&format!("{name}: {value}\r\n"));
+        }
+        head.push_str("\r\n");
+        stream.write_all(head.as_bytes()).map_err(fail)?;
+        stream.write_all(body.as_bytes()).map_err(fail)?;
+
+        let mut raw = Vec::new();
+        stream.read_to_end(&mut raw).map_err(fail)?;
+        let response = parse_response(&raw).ok_or_else(|| format!("{method} {path}: unreadable answer"))?;
+        match response.status {
+            401 | 403 => Err(format!("{method} {path}: the server refused the credentials ({})", response.status)),
+            _ => Ok(response),
+        }
+    }
+
+    /// LIST: The ETag of every resource in the collection, by file name
+    pub fn list(&self) -> Result<HashMap<String, String>, String> {
+        let headers = [("Depth", "1"), ("Content-Type", "application/xml; charset=utf-8")];
+        let response = self.request("PROPFIND", &self.path, &headers, PROPFIND_BODY)?;
+        if response.status != 207 {
+            return Err(format!("Listing {} failed ({})", self.path, response.status));
+        }
+        Ok(read_multistatus(&response.body))
+    }
+
+    /// ETAG: A single resource's current ETag, for servers that leave it out of PUT and GET answers
+    pub fn etag(&self, name: &str) -> Result<Option<String>, String> {
+        let headers = [("Depth", "0"), ("Content-Type", "application/xml; charset=utf-8")];
+        let response = self.request("PROPFIND", &self.resource(name), &headers, PROPFIND_BODY)?;
+        match response.status {
+            207 => Ok(read_multistatus(&response.body).remove(name).filter(|etag| !etag.is_empty())),
+            404 | 410 => Ok(None),
+            status => Err(format!("Reading the ETag of {name} failed ({status})")),
+        }
+    }
+
+    /// GET: A resource's text and ETag (None once it is gone)
+    pub fn get(&self, name: &str) -> Result<Option<(String, Option<String>)>, String> {
+        let response = self.request("GET", &self.resource(name), &[], "")?;
+        match response.status {
+            200 => {
+                let etag = response.header("ETag").map(str::to_string);
+                Ok(Some((response.body, etag)))
+            }
+            404 | 410 => Ok(None),
+            status => Err(format!("Reading {name} failed ({status})")),
+        }
+    }
+
+    /// PUT: Writes a resource if `expect` still holds
+    pub fn put(&self, name: &str, ics: &str, expect: Expect) -> Result<Put, String> {
+        let condition = match expect {
+            Expect::New => ("If-None-Match", "*"),
+            Expect::Etag(etag) => ("If-Match", etag),
+        };
+        let headers = [("Content-Type", "text/calendar; charset=utf-8"), condition];
+        let response = self.request("PUT", &self.resource(name), &headers, ics)?;
+        match response.status {
+            200 | 201 | 204 => Ok(Put::Stored(response.header("ETag").map(str::to_string))),
+            412 => Ok(Put::Conflict),
+            status => Err(format!("Writing {name} failed ({status})")),
+        }
+    }
+
+    /// DELETE: Removes a resource unless it changed since `etag` (false = it had changed, so it stays)
+    pub fn delete(&self, name: &str, etag: Option<&str>) -> Result<bool, String> {
+        let headers: Vec<(&str, &str)> = etag.map(|e| ("If-Match", e)).into_iter().collect();
+        let response = self.request("DELETE", &self.resource(name), &headers, "")?;
+        match response.status {
+            200 | 202 | 204 | 404 | 410 => Ok(true),
+            412 => Ok(false),
+            status => Err(format!("Deleting {name} failed ({status})")),
+        }
+    }
+}
+
+/// Splits a raw HTTP answer into status, headers and body
+fn parse_response(raw: &[u8]) -> Option<Response> {
+    let split = raw.windows(4).position(|w| w == b"\r\n\r\n")?;
+    let head = String::from_utf8_lossy(&raw[..split]);
+    let mut lines = head.lines();
+    let status = lines.next()?.split_whitespace().nth(1)?.parse().ok()?;
+    let headers: Vec<(String, String)> = lines
+        .filter_map(|line| line.split_once(':'))
+        .map(|(name, value)| (name.trim().to_string(), value.trim().to_string()))
+        .collect();
+    let mut response = Response { status, headers, body: String::new() };
+
+    let mut body = raw[split + 4..].to_vec();
+    if response.header("Transfer-Encoding").is_some_and(|t| t.eq_ignore_ascii_case("chunked")) {
+        body = dechunk(&body)?;
+    } else if let Some(length) = response.header("Content-Length").and_then(|l| l.parse::<usize>().ok()) {
+        body.truncate(length);
+    }
+    response.body = String::from_utf8_lossy(&body).into_owned();
+    Some(response)
+}
+
+/// Joins a chunked body back together
+fn dechunk(mut raw: &[u8]) -> Option<Vec<u8>> {
+    let mut body = Vec::new();
+    loop {
+        let line_end = raw.windows(2).position(|w| w == b"\r\n")?;
+        let size_text = String::from_utf8_lossy(&raw[..line_end]);
+        let size = usize::from_str_radix(size_text.split(';').next()?.trim(), 16).ok()?;
+        raw = &raw[line_end + 2..];
+        if size == 0 {
+            return Some(body);
+        }
+        body.extend_from_slice(raw.get(..size)?);
+        raw = raw.get(size + 2..)?;
+    }
+}
+
+/// ETags by file name from a PROPFIND answer; the collection itself is left out
+fn read_multistatus(xml: &str) -> HashMap<String, String> {
+    elements(xml, "response")
+        .into_iter()
+        .filter_map(|response| {
+            let href = unescape_xml(elements(response, "href").first()?.trim());
+            let etag = unescape_xml(elements(response, "getetag").first()?.trim());
+            let name = href.strip_suffix('/').map_or_else(|| href.rsplit('/').next().map(str::to_string), |_| None)?;
+            Some((name, etag))
+        })
+        .collect()
+}
+
+/// The contents of every `<prefix:name>` element, whatever the namespace prefix
+fn elements<'a>(xml: &'a str, name: &str) -> Vec<&'a str> {
+    let mut found = Vec::new();
+    let mut rest = xml;
+    while let Some(open) = rest.find('<') {
+        rest = &rest[open + 1..];
+        let tag_end = rest.find(|c: char| c == '>' || c == '/' || c.is_whitespace()).unwrap_or(rest.len());
+        let tag = &rest[..tag_end];
+        if tag.rsplit(':').next() != Some(name) {
+            continue;
+        }
+        let Some(close) = rest.find('>') else { break };
+        if rest[..close].ends_with('/') {
+            found.push("");
+            continue;
+        }
+        let inner = &rest[close + 1..];
+        let Some(end) = inner.find(&format!("</{tag}>")) else { break };
+        found.push(&inner[..end]);
+        rest = &inner[end..];
+    }
+    found
+}
+
+fn unescape_xml(text: &str) -> String {
+    text.replace("&quot;", "\"").replace("&apos;", "'").replace("&lt;", "<").replace("&gt;", ">").replace("&amp;", "&")
+}
+
+/// Standard base64 with padding, for the Authorization header
+fn base64(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut out = String::new();
+    for chunk in bytes.chunks(3) {
+        let n = chunk.iter().enumerate().fold(0u32, |n, (i, b)| n | (*b as u32) << (16 - 8 * i));
+        for i in 0..4 {
+            out.push(if i <= chunk.len() { ALPHABET[(n >> (18 - 6 * i) & 63) as usize] as char } else { '=' });
+        }
+    }
+    out
+}
+
+// ─── PASSWORD ────────────────────────────────────────────────────────────────
+
+const KEYRING_SERVICE: &str = "errday";
+const KEYRING_USER: &str = "caldav";
+
+/// Puts the CalDAV password in the OS keyring (an empty one removes it)
+pub fn store_password(password: &str) -> Result<(), String> {
+    let entry = keyring::Entry::new(KEYRING_SERVICE, KEYRING_USER).map_err(|e| e.to_string())?;
+    let stored = if password.is_empty() {
+        match entry.delete_credential() {
+            Err(keyring::Error::NoEntry) => Ok(()),
+            other => other,
+        }
+    } else {
+        entry.set_password(password)
+    };
+    stored.map_err(|e| e.to_string())
+}
+
+/// Reads the CalDAV password from the OS keyring (empty when none was saved)
+pub fn load_password() -> Result<String, String> {
+    let entry = keyring::Entry::new(KEYRING_SERVICE, KEYRING_USER).map_err(|e| e.to_string())?;
+    match entry.get_password() {
+        Ok(password) => Ok(password),
+        Err(keyring::Error::NoEntry) => Ok(String::new()),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+// ─── SYNC ────────────────────────────────────────────────────────────────────
+
+/// SYNCED ITEM: What the ledger knows about one task's resource
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Synced {
+    pub etag: Option<String>,           // The server's ETag when we last wrote or read it
+    pub seen: Option<DateTime<Local>>,  // The task's last change the server copy includes (None = not in step)
+    #[serde(default)]
+    pub shape: Option<u64>,             // `shape` of the ICS last sent (None = unknown, send it again)
+}
+
+/// LEDGER: The synced resources of one collection, saved as caldav.json
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Ledger {
+    pub url: String,
+    pub items: HashMap<Uuid, Synced>,
+}
+
+impl Ledger {
+    /// Reads the ledger for `url`; another collection starts from scratch
+    pub fn load(url: &str) -> Ledger {
+        fs::read_to_string(data_file_path(LEDGER_FILE))
+            .ok()
+            .and_then(|content| serde_json::from_str::<Ledger>(&content).ok())
+            .filter(|ledger| ledger.url == url)
+            .unwrap_or_else(|| Ledger { url: url.to_string(), items: HashMap::new() })
+    }
+
+    pub fn save(&self) {
+        if let Ok(content) = serde_json::to_string_pretty(self) {
+            let _ = fs::write(data_file_path(LEDGER_FILE), content);
+        }
+    }
+}
+
+/// OUTGOING: A task as the server should hold it, built on the UI side
+pub struct Outgoing {
+    pub id: Uuid,
+    pub touched: DateTime<Local>, // The task's last change
+    pub start: Option<DateTime<Local>>,
+    pub end: Option<DateTime<Local>>,
+    pub ics: String,
+}
+
+/// PULLED TIMES: A task's new slot as the server has it
+#[derive(Debug, Clone, PartialEq)]
+pub struct Pulled {
+    pub id: Uuid,
+    pub touched: DateTime<Local>, // The task's last change when the sync read it; a newer one wins
+    pub start: Option<DateTime<Local>>,
+    pub end: Option<DateTime<Local>>,
+}
+
+/// What one sync did; `error` stops it early, keeping everything done until then
+#[derive(Debug, Default)]
+pub struct Outcome {
+    pub pushed: usize,
+    pub pulled: Vec<Pulled>,
+    pub removed: usize,
+    pub conflicts: usize,
+    pub error: Option<String>,
+}
+
+/// SYNC: Brings the collection and `outgoing` (every task the export covers) into step, updating `ledger`.
+/// `live` holds every task still in the app; only synced tasks missing from it are deleted on the server.
+/// Times to change here come back in `pulled`; applying them must not count as a local change.
+pub fn sync(client: &Client, outgoing: &[Outgoing], live: &HashSet<Uuid>, ledger: &mut Ledger) -> Outcome {
+    let mut outcome = Outcome::default();
+    if let Err(e) = sync_into(client, outgoing, live, ledger, &mut outcome) {
+        outcome.error = Some(e);
+    }
+    outcome
+}
+
+fn sync_into(client: &Client, outgoing: &[Outgoing], live: &HashSet<Uuid>, ledger: &mut Ledger, outcome: &mut Outcome) -> Result<(), String> {
+    let remote = client.list()?;
+
+    for task in outgoing {
+        let name = format!("{}.ics", task.id);
+        let known = ledger.items.get(&task.id).cloned();
+        let changed_here = known.as_ref().is_none_or(|k| k.seen != Some(task.touched));
+        let reshaped = known.as_ref().is_none_or(|k| k.shape != Some(shape(&task.ics)));
+
+        let expect = match (remote.get(&name), &known) {
+            // Not on the server (new, or deleted there): write it
+            (None, _) => Expect::New,
+            // The server copy is the one we know: send ours if it changed
+            (Some(etag), Some(k)) if k.etag.as_ref() == Some(etag) => {
+                if !changed_here && !reshaped {
+                    ledger.items.insert(task.id, Synced { etag: Some(etag.clone()), ..k.clone() });
+                    continue;
+                }
+                Expect::Etag(etag)
+            }
+            // Changed on the server: take its times unless ours are newer
+            (Some(_), _) => {
+                let Some((body, etag)) = client.get(&name)? else {
+                    continue; // Deleted while we looked; written again next time
+                };
+                // Without an ETag we could neither send ours (If-Match) nor tell the next server edit apart
+                let etag = match etag {
+                    Some(etag) => Some(etag),
+                    None => client.etag(&name)?,
+                };
+                let Some(item) = ics::read_items(&body).ok().and_then(|items| items.into_iter().next()) else {
+                    continue;
+                };
+                let ours_newer = changed_here && item.modified.is_none_or(|m| task.touched > m);
+                if changed_here {
+                    outcome.conflicts += 1;
+                }
+                if ours_newer {
+                    if let Some(etag) = etag {
+                        push(client, task, &name, Expect::Etag(&etag), ledger, outcome)?;
+                    }
+                    continue;
+                }
+                if (item.start, item.end) != (task.start, task.end) {
+                    outcome.pulled.push(Pulled { id: task.id, touched: task.touched, start: item.start, end: item.end });
+                }
+                // After a lost conflict our other edits are still to be sent, so the task stays out of step;
+                // the shape stays the one last sent, so a new one still goes out
+                let seen = if changed_here { known.as_ref().and_then(|k| k.seen) } else { Some(task.touched) };
+                ledger.items.insert(task.id, Synced { etag, seen, shape: known.and_then(|k| k.shape) });
+                continue;
+            }
+        };
+        push(client, task, &name, expect, ledger, outcome)?;
+    }
+
+    // Tasks deleted or archived here; those only left out of the export keep their server copy
+    let gone: Vec<Uuid> = ledger.items.keys().filter(|id| !live.contains(id)).copied().collect();
+    for id in gone {
+        let name = format!("{id}.ics");
+        if remote.contains_key(&name) {
+            let etag = ledger.items[&id].etag.clone();
+            if client.delete(&name, etag.as_deref())? {
+                outcome.removed += 1;
+            } else {
+                outcome.conflicts += 1; // Edited on the server meanwhile: it stays there, no longer synced
+            }
+        }
+        ledger.items.remove(&id);
+    }
+    Ok(())
+}
+
+/// Writes `task`, recording the new ETag (a failed precondition is sorted out by the next sync).
+/// When the PUT answer carries none, it is read back so later server edits can still be told apart.
+fn push(client: &Client, task: &Outgoing, name: &str, expect: Expect, ledger: &mut Ledger, outcome: &mut Outcome) -> Result<(), String> {
+    match client.put(name, &task.ics, expect)? {
+        Put::Stored(etag) => {
+            let etag = match etag {
+                Some(etag) => Some(etag),
+                None => client.etag(name)?,
+            };
+            ledger.items.insert(task.id, Synced { etag, seen: Some(task.touched), shape: Some(shape(&task.ics)) });
+            outcome.pushed += 1;
+        }
+        Put::Conflict => outcome.conflicts += 1,
+    }
+    Ok(())
+}
+
+/// SHAPE: A hash of a task's ICS without its times, stamps and UIDs, so a change in the export settings
+/// (to-dos instead of events, other description fields) is noticed and sent again.
+/// Times are left out because the task's last change covers them, and times pulled from the server
+/// must not look like an edit made here. The task's UID is its resource name; a reminder's is new on every render.
+fn shape(ics: &str) -> u64 {
+    const SKIPPED: [&str; 6] = ["DTSTAMP", "LAST-MODIFIED", "DTSTART", "DTEND", "DUE", "UID"];
+    // FNV-1a: the same on every build, unlike std's hasher, so the saved ledger stays valid
+    ics.lines()
+        .filter(|line| {
+            let name = line.split([':', ';']).next().unwrap_or_default();
+            !SKIPPED.iter().any(|skipped| name.eq_ignore_ascii_case(skipped))
+        })
+        .flat_map(|line| line.bytes().chain(std::iter::once(b'\n')))
+        .fold(0xcbf2_9ce4_8422_2325, |hash, byte| (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3))
+}
+
+// ─── IN THE APP ──────────────────────────────────────────────────────────────
+
+/// CALDAV STATE: Whether a sync is running and what the last one did, shared by the settings page and the palette
+#[derive(Clone, Copy)]
+pub struct CalDavState {
+    pub running: Signal<bool>,
+    pub status: Signal<Option<String>>,
+}
+
+impl CalDavState {
+    pub fn new() -> Self {
+        Self { running: Signal::new(false), status: Signal::new(None) }
+    }
+
+    /// SYNC NOW: Syncs the tasks the export covers with the configured collection.
+    /// The network part runs on its own thread; pulled times are applied here once it is done.
+    pub async fn sync_now(self, app_state: AppState, settings_state: SettingsState) {
+        let (mut running, mut status) = (self.running, self.status);
+        if running() {
+            return;
+        }
+        let i18n = settings_state.i18n();
+        let (prefs, export_prefs) = {
+            let settings = settings_state.settings.peek();
+            (settings.caldav.clone(), settings.export.clone())
+        };
+        let password = match prefs.in_keyring {
+            true => match load_password() {
+                Ok(password) => password,
+                Err(e) => return status.set(Some(i18n.tf("Could not read the password from the system keyring: {error}", &[("error", &e)]))),
+            },
+            false => prefs.password.clone(),
+        };
+        let client = match Client::new(&prefs.url, &prefs.username, &password) {
+            Ok(client) => client,
+            Err(e) => return status.set(Some(e)),
+        };
+        let live: HashSet<Uuid> = app_state.tasks.peek().iter().map(|t| t.id).collect();
+        let outgoing: Vec<Outgoing> = export::selection(&app_state.tasks.peek(), &export_prefs, None, None)
+            .into_iter()
+            .map(|t| Outgoing {
+                id: t.id,
+                touched: t.last_touched(),
+                start: t.scheduled_start,
+                end: t.scheduled_end,
+                ics: export::task_ics(t, &export_prefs, i18n),
+            })
+            .collect();
+        let mut ledger = Ledger::load(&prefs.url);
+
+        running.set(true);
+        status.set(Some(i18n.t("Syncing…").to_string()));
+        let (outcome, ledger) = off_thread(move || (sync(&client, &outgoing, &live, &mut ledger), ledger)).await;
+        ledger.save();
+        app_state.apply_remote_times(&outcome.pulled);
+        running.set(false);
+
+        let summary = i18n.tf(
+            "{time}: {sent} sent, {pulled} updated here, {removed} removed, {conflicts} conflicts",
+            &[
+                ("time", &i18n.time(&Local::now())),
+                ("sent", &outcome.pushed),
+                ("pulled", &outcome.pulled.len()),
+                ("removed", &outcome.removed),
+                ("conflicts", &outcome.conflicts),
+            ],
+        );
+        status.set(Some(match outcome.error {
+            Some(e) => i18n.tf("{summary}. Stopped: {error}", &[("summary", &summary), ("error", &e)]),
+            None => summary,
+        }));
+    }
+}
+
+/// Runs `work` on a thread of its own and resolves with its result, so the window stays responsive
+fn off_thread<T: Send + 'static>(work: impl FnOnce() -> T + Send + 'static) -> impl Future<Output = T> {
+    let handoff = Arc::new(Mutex::new(Handoff { result: None, waker: None }));
+    let shared = handoff.clone();
+    thread::spawn(move || {
+        let result = work();
+        if let Ok(mut handoff) = shared.lock() {
+            handoff.result = Some(result);
+            if let Some(waker) = handoff.waker.take() {
+                waker.wake();
+            }
+        }
+    });
+    Pending(handoff)
+}
+
+struct Handoff<T> {
+    result: Option<T>,
+    waker: Option<Waker>,
+}
+
+struct Pending<T>(Arc<Mutex<Handoff<T>>>);
+
+impl<T> Future for Pending<T> {
+    type Output = T;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<T> {
+        let mut handoff = self.0.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        match handoff.result.take() {
+            Some(result) => Poll::Ready(result),
+            None => {
+                handoff.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::{Ipv4Addr, TcpListener};
+    use chrono::TimeZone;
+    use crate::i18n::{Clock, I18n, Locale};
+    use crate::models::{Quadrant, Task};
+    use crate::settings::ExportPrefs;
+
+    /// A CalDAV stand-in: one collection at /cal/ kept in memory, with numbered ETags
+    struct StandIn {
+        port: u16,
+        items: Arc<Mutex<HashMap<String, (String, String)>>>, // File name -> (ETag, text)
+    }
+
+    impl StandIn {
+        fn start() -> StandIn {
+            Self::start_with(true)
+        }
+
+        /// A server that only reports ETags in PROPFIND answers, never on PUT or GET
+        fn start_without_etag_headers() -> StandIn {
+            Self::start_with(false)
+        }
+
+        fn start_with(etag_headers: bool) -> StandIn {
+            let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).unwrap();
+            let items = Arc::new(Mutex::new(HashMap::new()));
+            let stand_in = StandIn { port: listener.local_addr().unwrap().port(), items: items.clone() };
+            thread::spawn(move || {
+                let mut version = 0;
+                for stream in listener.incoming().flatten() {
+                    version += 1;
+                    serve(stream, &items, version, etag_headers);
+                }
+            });
+            stand_in
+        }
+
+        fn client(&self) -> Client {
+            Client::new(&format!("http://127.0.0.1:{}/cal", self.port), "me", "secret").unwrap()
+        }
+
+        /// An edit made by another calendar app
+        fn edit(&self, name: &str, ics: String) {
+            let mut items = self.items.lock().unwrap();
+            // A new ETag on every edit, as a real server would give
+            let etag = items.get(name).map_or("\"other\"".to_string(), |(etag, _)| format!("\"{}+\"", etag.trim_matches('"')));
+            items.insert(name.to_string(), (etag, ics));
+        }
+
+        fn text(&self, name: &str) -> Option<String> {
+            self.items.lock().unwrap().get(name).map(|(_, text)| text.clone())
+        }
+    }
+
+    fn serve(mut stream: TcpStream, items: &Mutex<HashMap<String, (String, String)>>, version: u32, etag_headers: bool) {
+        let mut raw = Vec::new();
+        let mut chunk = [0u8; 4096];
+        let (head, body) = loop {
+            let read = stream.read(&mut chunk).unwrap();
+            raw.extend_from_slice(&chunk[..read]);
+            if let Some(split) = raw.windows(4).position(|w| w == b"\r\n\r\n") {
+                let head = String::from_utf8_lossy(&raw[..split]).into_owned();
+                let length: usize = head.lines().find_map(|l| l.strip_prefix("Content-Length: ")).and_then(|l| l.parse().ok()).unwrap_or(0);
+                while raw.len() < split + 4 + length {
+                    let read = stream.read(&mut chunk).unwrap();
+                    raw.extend_from_slice(&chunk[..read]);
+                }
+                break (head, String::from_utf8_lossy(&raw[split + 4..]).into_owned());
+            }
+        };
+        let header = |name: &str| head.lines().find_map(|l| l.strip_prefix(&format!("{name}: "))).map(str::to_string);
+        let mut request_line = head.lines().next().unwrap().split_whitespace();
+        let (method, path) = (request_line.next().unwrap(), request_line.next().unwrap());
+        let name = path.trim_start_matches("/cal/").to_string();
+        let mut items = items.lock().unwrap();
+        let current = items.get(&name).map(|(etag, _)| etag.clone());
+        let precondition_fails = header("If-None-Match").is_some() && current.is_some()
+            || header("If-Match").is_some_and(|etag| current.as_ref() != Some(&etag));
+
+        let reply = if header("Authorization").as_deref() != Some("Basic bWU6c2VjcmV0") {
+            "HTTP/1.1 401 Unauthorized\r\nContent-Length: 0\r\n\r\n".to_string()
+        } else if method == "PROPFIND" {
+            // Chunked, with the collection itself and absolute URLs, as real servers answer
+            let mut xml = r#"<?xml version="1.0"?><d:multistatus xmlns:d="DAV:"><d:response><d:href>/cal/</d:href><d:propstat><d:prop><d:getetag/></d:prop></d:propstat></d:response>"#.to_string();
+            for (name, (etag, _)) in items.iter() {
+                let etag = etag.replace('"', "&quot;");
+                xml.push_str(&format!("<d:response><d:href>http://127.0.0.1/cal/{name}</d:href><d:propstat><d:prop><d:getetag>{etag}</d:getetag></d:prop></d:propstat></d:response>"));
+            }
+            xml.push_str("</d:multistatus>");
+            format!("HTTP/1.1 207 Multi-Status\r\nTransfer-Encoding: chunked\r\n\r\n{:x}\r\n{xml}\r\n0\r\n\r\n", xml.len())
+        } else if precondition_fails {
+            "HTTP/1.1 412 Precondition Failed\r\nContent-Length: 0\r\n\r\n".to_string()
+        } else if method == "PUT" {
+            let etag = format!("\"v{version}\"");
+            items.insert(name, (etag.clone(), body));
+            let etag = if etag_headers { format!("ETag: {etag}\r\n") } else { String::new() };
+            format!("HTTP/1.1 201 Created\r\n{etag}Content-Length: 0\r\n\r\n")
+        } else if let Some((etag, text)) = items.get(&name).cloned() {
+            let etag = if etag_headers { format!("ETag: {etag}\r\n") } else { String::new() };
+            match method {
+                "GET" => format!("HTTP/1.1 200 OK\r\n{etag}Content-Length: {}\r\n\r\n{text}", text.len()),
+                _ => {
+                    items.remove(&name);
+                    "HTTP/1.1 204 No Content\r\n\r\n".to_string()
+                }
+            }
+        } else {
+            "HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\n\r\n".to_string()
+        };
+        let _ = stream.write_all(reply.as_bytes());
+    }
+
+    const EN: I18n = I18n { locale: Locale::En, clock: Clock::H24 };
+
+    fn at(hour: u32, minute: u32) -> DateTime<Local> {
+        Local.with_ymd_and_hms(2026, 3, 2, hour, minute, 0).unwrap()
+    }
+
+    /// A scheduled task last changed at `touched`
+    fn task(title: &str, start: DateTime<Local>, touched: DateTime<Local>) -> Task {
+        let mut task = Task::new(title.to_string());
+        task.quadrant = Quadrant::DoFirst;
+        task.created_at = at(6, 0);
+        task.updated_at = Some(touched);
+        task.scheduled_start = Some(start);
+        task.scheduled_end = Some(start + chrono::Duration::hours(1));
+        task
+    }
+
+    fn outgoing(tasks: &[&Task]) -> Vec<Outgoing> {
+        let prefs = ExportPrefs::default();
+        tasks
+            .iter()
+            .map(|t| Outgoing { id: t.id, touched: t.last_touched(), start: t.scheduled_start, end: t.scheduled_end, ics: export::task_ics(t, &prefs, EN) })
+            .collect()
+    }
+
+    fn live(tasks: &[&Task]) -> HashSet<Uuid> {
+        tasks.iter().map(|t| t.id).collect()
+    }
+
+    /// The task as another app saves it after moving it to `start` at `touched`
+    fn moved(task: &Task, start: DateTime<Local>, touched: DateTime<Local>) -> String {
+        let other = Task { updated_at: Some(touched), scheduled_start: Some(start), scheduled_end: Some(start + chrono::Duration::hours(1)), ..task.clone() };
+        export::task_ics(&other, &ExportPrefs::default(), EN)
+    }
+
+    #[test]
+    fn first_sync_writes_every_task_then_nothing_is_left_to_do() {
+        let server = StandIn::start();
+        let client = server.client();
+        let (a, b) = (task("Report", at(9, 0), at(7, 0)), task("Call", at(11, 0), at(7, 0)));
+        let mut ledger = Ledger::default();
+
+        let first = sync(&client, &outgoing(&[&a, &b]), &live(&[&a, &b]), &mut ledger);
+        assert_eq!((first.pushed, first.conflicts, first.error), (2, 0, None));
+        assert!(server.text(&format!("{}.ics", a.id)).unwrap().contains("SUMMARY:Report"));
+        assert_eq!(ledger.items.len(), 2);
+
+        let second = sync(&client, &outgoing(&[&a, &b]), &live(&[&a, &b]), &mut ledger);
+        assert_eq!((second.pushed, second.pulled.len(), second.conflicts), (0, 0, 0));
+    }
+
+    #[test]
+    fn times_moved_on_the_server_come_back() {
+        let server = StandIn::start();
+        let client = server.client();
+        let mut a = task("Report", at(9, 0), at(7, 0));
+        let mut ledger = Ledger::default();
+        sync(&client, &outgoing(&[&a]), &live(&[&a]), &mut ledger);
+
+        server.edit(&format!("{}.ics", a.id), moved(&a, at(14, 0), at(8, 0)));
+        let outcome = sync(&client, &outgoing(&[&a]), &live(&[&a]), &mut ledger);
+        assert_eq!(outcome.pulled, vec![Pulled { id: a.id, touched: a.last_touched(), start: Some(at(14, 0)), end: Some(at(15, 0)) }]);
+        assert_eq!((outcome.pushed, outcome.conflicts), (0, 0));
+
+        // Applied without touching the task, so nothing goes back
+        a.scheduled_start = Some(at(14, 0));
+        a.scheduled_end = Some(at(15, 0));
+        let after = sync(&client, &outgoing(&[&a]), &live(&[&a]), &mut ledger);
+        assert_eq!((after.pushed, after.pulled.len()), (0, 0));
+    }
+
+    #[test]
+    fn local_edits_are_sent_and_the_newer_side_wins_a_conflict() {
+        let server = StandIn::start();
+        let client = server.client();
+        let a = task("Report", at(9, 0), at(7, 0));
+        let name = format!("{}.ics", a.id);
+        let mut ledger = Ledger::default();
+        sync(&client, &outgoing(&[&a]), &live(&[&a]), &mut ledger);
+
+        // Only changed here: sent with If-Match
+        let a = task_from(&a, at(10, 0), at(8, 0));
+        assert_eq!(sync(&client, &outgoing(&[&a]), &live(&[&a]), &mut ledger).pushed, 1);
+        assert!(server.text(&name).unwrap().contains(&export_time(at(10, 0))));
+
+        // Both changed, ours later: ours replaces the server copy
+        server.edit(&name, moved(&a, at(14, 0), at(8, 30)));
+        let a = task_from(&a, at(11, 0), at(9, 0));
+        let ours = sync(&client, &outgoing(&[&a]), &live(&[&a]), &mut ledger);
+        assert_eq!((ours.pushed, ours.pulled.len(), ours.conflicts), (1, 0, 1));
+        assert!(server.text(&name).unwrap().contains(&export_time(at(11, 0))));
+
+        // Both changed, the server's later: its times win, and our other edits go out next time
+        server.edit(&name, moved(&a, at(16, 0), at(12, 0)));
+        let a = Task { title: "Report v2".to_string(), ..task_from(&a, at(11, 0), at(10, 0)) };
+        let theirs = sync(&client, &outgoing(&[&a]), &live(&[&a]), &mut ledger);
+        assert_eq!((theirs.pushed, theirs.conflicts), (0, 1));
+        assert_eq!(theirs.pulled[0].start, Some(at(16, 0)));
+        let a = Task { scheduled_start: Some(at(16, 0)), scheduled_end: Some(at(17, 0)), ..a };
+        assert_eq!(sync(&client, &outgoing(&[&a]), &live(&[&a]), &mut ledger).pushed, 1);
+        assert!(server.text(&name).unwrap().contains("SUMMARY:Report v2"));
+    }
+
+    #[test]
+    fn stale_etags_are_refused_and_removed_tasks_are_deleted() {
+        let server = StandIn::start();
+        let client = server.client();
+        let a = task("Report", at(9, 0), at(7, 0));
+        let name = format!("{}.ics", a.id);
+        let mut ledger = Ledger::default();
+        sync(&client, &outgoing(&[&a]), &live(&[&a]), &mut ledger);
+
+        assert_eq!(client.put(&name, "x", Expect::Etag("\"stale\"")).unwrap(), Put::Conflict);
+        assert_eq!(client.put(&name, "x", Expect::New).unwrap(), Put::Conflict);
+
+        let outcome = sync(&client, &[], &HashSet::new(), &mut ledger);
+        assert_eq!(outcome.removed, 1);
+        assert!(server.text(&name).is_none() && ledger.items.is_empty());
+    }
+
+    #[test]
+    fn new_export_settings_are_sent_and_tasks_left_out_stay_on_the_server() {
+        let server = StandIn::start();
+        let client = server.client();
+        let (a, b) = (task("Report", at(9, 0), at(7, 0)), task("Call", at(11, 0), at(7, 0)));
+        let name = format!("{}.ics", a.id);
+        let mut ledger = Ledger::default();
+        sync(&client, &outgoing(&[&a, &b]), &live(&[&a, &b]), &mut ledger);
+
+        // To-dos instead of events: untouched tasks go out again in the new shape
+        let prefs = ExportPrefs { as_todos: true, ..ExportPrefs::default() };
+        let todos: Vec<Outgoing> = [&a, &b]
+            .iter()
+            .map(|t| Outgoing { id: t.id, touched: t.last_touched(), start: t.scheduled_start, end: t.scheduled_end, ics: export::task_ics(t, &prefs, EN) })
+            .collect();
+        assert_eq!(sync(&client, &todos, &live(&[&a, &b]), &mut ledger).pushed, 2);
+        assert!(server.text(&name).unwrap().contains("BEGIN:VTODO"));
+        assert_eq!(sync(&client, &todos, &live(&[&a, &b]), &mut ledger).pushed, 0);
+
+        // A narrower export leaves the server copy alone; deleting the task removes it
+        let narrowed = sync(&client, &todos[1..], &live(&[&a, &b]), &mut ledger);
+        assert_eq!((narrowed.pushed, narrowed.removed), (0, 0));
+        assert!(server.text(&name).is_some());
+        assert_eq!(sync(&client, &todos[1..], &live(&[&b]), &mut ledger).removed, 1);
+        assert!(server.text(&name).is_none());
+    }
+
+    #[test]
+    fn etags_missing_from_put_and_get_are_read_back() {
+        let server = StandIn::start_without_etag_headers();
+        let client = server.client();
+        let a = task("Report", at(9, 0), at(7, 0));
+        let name = format!("{}.ics", a.id);
+        let mut ledger = Ledger::default();
+        sync(&client, &outgoing(&[&a]), &live(&[&a]), &mut ledger);
+        assert!(ledger.items[&a.id].etag.is_some());
+
+        // A later server edit is noticed rather than taken for the copy we wrote
+        server.edit(&name, moved(&a, at(14, 0), at(8, 0)));
+        let outcome = sync(&client, &outgoing(&[&a]), &live(&[&a]), &mut ledger);
+        assert_eq!(outcome.pulled.len(), 1);
+
+        // Both changed, ours later: sent even though the GET carried no ETag, and settled afterwards
+        server.edit(&name, moved(&a, at(15, 0), at(8, 30)));
+        let a = task_from(&a, at(11, 0), at(9, 0));
+        let ours = sync(&client, &outgoing(&[&a]), &live(&[&a]), &mut ledger);
+        assert_eq!((ours.pushed, ours.conflicts), (1, 1));
+        assert!(server.text(&name).unwrap().contains(&export_time(at(11, 0))));
+        let after = sync(&client, &outgoing(&[&a]), &live(&[&a]), &mut ledger);
+        assert_eq!((after.pushed, after.pulled.len(), after.conflicts), (0, 0, 0));
+    }
+
+    #[test]
+    fn wrong_credentials_stop_the_sync() {
+        let server = StandIn::start();
+        let client = Client::new(&format!("http://127.0.0.1:{}/cal/", server.port), "me", "guess").unwrap();
+        let outcome = sync(&client, &outgoing(&[&task("Report", at(9, 0), at(7, 0))]), &HashSet::new(), &mut Ledger::default());
+        assert!(outcome.error.unwrap().contains("401"));
+        assert_eq!(outcome.pushed, 0);
+    }
+
+    #[test]
+    fn reads_collection_urls() {
+        let client = Client::new(" http://localhost:5232/me/tasks ", "", "").unwrap();
+        assert_eq!((client.host.as_str(), client.port, client.path.as_str(), client.auth), ("localhost", 5232, "/me/tasks/", None));
+        assert_eq!(Client::new("http://nas", "", "").unwrap().port, 80);
+        let cloud = Client::new("https://cloud.example.com/dav/", "", "").unwrap();
+        assert_eq!((cloud.port, cloud.tls, cloud.sends_in_clear()), (443, true, false));
+        assert!(Client::new("http://nas.local/dav/", "", "").unwrap().sends_in_clear());
+        assert!(!Client::new("http://127.0.0.1:5232/", "", "").unwrap().sends_in_clear());
+        assert!(Client::new("localhost:5232", "", "").is_err());
+        // IPv6 literals keep their colons and lose the brackets
+        let v6 = Client::new("http://[::1]/dav", "", "").unwrap();
+        assert_eq!((v6.host.as_str(), v6.port, v6.sends_in_clear()), ("::1", 80, false));
+        let v6 = Client::new("https://[2001:db8::5]:8443/dav/", "", "").unwrap();
+        assert_eq!((v6.host.as_str(), v6.port, v6.path.as_str()), ("2001:db8::5", 8443, "/dav/"));
+        assert!(Client::new("http://[::1/dav", "", "").is_err());
+        assert!(Client::new("http://[::1]8443/dav", "", "").is_err());
+        assert_eq!(base64(b"Aladdin:open sesame"), "QWxhZGRpbjpvcGVuIHNlc2FtZQ==");
+    }
+
+    fn task_from(task: &Task, start: DateTime<Local>, touched: DateTime<Local>) -> Task {
+        Task { updated_at: Some(touched), scheduled_start: Some(start), scheduled_end: Some(start + chrono::Duration::hours(1)), ..task.clone() }
+    }
+
+    /// How a start time appears in the written ICS
+    fn export_time(start: DateTime<Local>) -> String {
+        start.with_timezone(&chrono::Utc).format("DTSTART:%Y%m%dT%H%M%SZ").to_string()
+    }
+}
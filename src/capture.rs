@@ -0,0 +1,186 @@
+// BULK CAPTURE PARSER: Turns a pasted block of text (meeting notes, markdown lists)
+// into separate inbox items. Kept free of UI code so the Inbox only has to render it.
+
+/// CAPTURED ITEM: One top-level line of the pasted text plus any indented lines below it
+#[derive(Debug, Clone, PartialEq)]
+pub struct CapturedItem {
+    pub title: String,      // The text of the bullet, without its marker
+    pub notes: Vec<String>, // Indented lines that belong to this bullet
+    pub checked: bool,      // Was it written as a ticked checkbox ("- [x]")?
+}
+
+impl CapturedItem {
+    /// Joins the indented lines into a task description (None when there are none)
+    pub fn description(&self) -> Option<String> {
+        if self.notes.is_empty() {
+            None
+        } else {
+            Some(self.notes.iter().map(|n| format!("- {}", n)).collect::<Vec<_>>().join("\n"))
+        }
+    }
+}
+
+/// MULTI-LINE CHECK: True when the text holds more than one non-empty line
+pub fn is_multiline(text: &str) -> bool {
+    text.lines().filter(|l| !l.trim().is_empty()).count() > 1
+}
+
+/// PARSING: Splits pasted text into items.
+/// Understands "-", "*", "+" and "•" bullets, "[ ]"/"[x]" checkboxes and "1." / "1)" numbering.
+/// Lines indented deeper than the top level become notes of the item above them, whatever that item looks like.
+/// A plain line ending in ":" with a list right under it ("Action items:") is a heading: it is left out
+/// and the list becomes the top level.
+pub fn parse_capture(text: &str) -> Vec<CapturedItem> {
+    // Stage 1: Every non-empty line as (indent, text, ticked, had a marker)
+    let lines: Vec<(usize, String, bool, bool)> = text
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let (content, checked, marked) = strip_marker(line.trim());
+            (indent_width(line), content, checked, marked)
+        })
+        .filter(|(_, content, _, _)| !content.is_empty())
+        .collect();
+
+    // Stage 2: Top-level lines become items, deeper ones their notes
+    let mut items: Vec<CapturedItem> = Vec::new();
+    let mut base_indent: Option<usize> = None;
+    for (i, (indent, content, checked, marked)) in lines.iter().enumerate() {
+        // A line left of the top level starts a new one
+        let base = *base_indent.insert(base_indent.map_or(*indent, |b| b.min(*indent)));
+        if *indent <= base && !marked && content.ends_with(':') {
+            let next = lines.get(i + 1).filter(|(next_indent, _, _, next_marked)| *next_marked && next_indent >= indent);
+            if let Some((next_indent, ..)) = next {
+                base_indent = Some(*next_indent);
+                continue;
+            }
+        }
+        match items.last_mut() {
+            // Deeper than the top level: attach to the previous item
+            Some(parent) if *indent > base => parent.notes.push(content.clone()),
+            _ => items.push(CapturedItem { title: content.clone(), notes: Vec::new(), checked: *checked }),
+        }
+    }
+
+    items
+}
+
+/// Counts leading whitespace, treating a tab as four spaces
+fn indent_width(line: &str) -> usize {
+    line.chars()
+        .take_while(|c| c.is_whitespace())
+        .map(|c| if c == '\t' { 4 } else { 1 })
+        .sum()
+}
+
+/// Removes list markers from the start of a trimmed line.
+/// Returns the remaining text, whether a ticked checkbox was found and whether there was any marker.
+/// A marker on its own ("-", "1.") leaves no text, so the line is skipped.
+fn strip_marker(line: &str) -> (String, bool, bool) {
+    let mut rest = line;
+    let mut marked = false;
+
+    // Step 1: Bullets ("- ", "* ", "+ ", "• ") or numbering ("1. ", "12) ")
+    if ["-", "*", "+", "•"].contains(&rest) {
+        return (String::new(), false, true);
+    }
+    if let Some(stripped) = ["- ", "* ", "+ ", "• "].iter().find_map(|m| rest.strip_prefix(m)) {
+        rest = stripped;
+        marked = true;
+    } else {
+        let digits = rest.chars().take_while(|c| c.is_ascii_digit()).count();
+        if digits > 0 {
+            let after = &rest[digits..];
+            if after == "." || after == ")" {
+                return (String::new(), false, true);
+            }
+            if let Some(stripped) = after.strip_prefix(". ").or_else(|| after.strip_prefix(") ")) {
+                rest = stripped;
+                marked = true;
+            }
+        }
+    }
+
+    // Step 2: Checkbox syntax ("[ ] ", "[x] ")
+    let rest = rest.trim_start();
+    let mut checked = false;
+    let rest = if let Some(stripped) = rest.strip_prefix("[ ]") {
+        marked = true;
+        stripped
+    } else if let Some(stripped) = rest.strip_prefix("[x]").or_else(|| rest.strip_prefix("[X]")) {
+        marked = true;
+        checked = true;
+        stripped
+    } else {
+        rest
+    };
+
+    (rest.trim().to_string(), checked, marked)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn titles(text: &str) -> Vec<String> {
+        parse_capture(text).into_iter().map(|item| item.title).collect()
+    }
+
+    #[test]
+    fn bullets_and_numbering_are_stripped() {
+        assert_eq!(titles("- Call Sam\n* Book room\n+ Send deck\n• Pay invoice"), vec!["Call Sam", "Book room", "Send deck", "Pay invoice"]);
+        assert_eq!(titles("1. First\n2) Second\n10. Tenth"), vec!["First", "Second", "Tenth"]);
+        // Not a list marker: kept as written
+        assert_eq!(titles("-5 degrees\n2024 plan"), vec!["-5 degrees", "2024 plan"]);
+    }
+
+    #[test]
+    fn checkboxes_record_whether_they_were_ticked() {
+        let items = parse_capture("- [ ] Open\n- [x] Done\n[X] Also done");
+        assert_eq!(items.iter().map(|i| (i.title.as_str(), i.checked)).collect::<Vec<_>>(), vec![("Open", false), ("Done", true), ("Also done", true)]);
+    }
+
+    #[test]
+    fn deeper_lines_become_notes_and_tabs_count_as_indent() {
+        let items = parse_capture("- Launch\n  - Write copy\n\t- Ping design\n- Retro");
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].notes, vec!["Write copy", "Ping design"]);
+        assert_eq!(items[0].description().as_deref(), Some("- Write copy\n- Ping design"));
+        assert_eq!(items[1].description(), None);
+    }
+
+    #[test]
+    fn bare_markers_are_skipped() {
+        assert_eq!(titles("- Real task\n-\n*\n3.\n- Another"), vec!["Real task", "Another"]);
+    }
+
+    #[test]
+    fn headings_above_bullets_are_left_out() {
+        assert_eq!(titles("Action items:\n  - Call Sam\n  - Book room\nFollow-ups:\n  - Send deck"), vec!["Call Sam", "Book room", "Send deck"]);
+        // Plain lines with plain notes under them are still tasks
+        let items = parse_capture("Plan offsite\n  venue ideas\nBudget");
+        assert_eq!(items.iter().map(|i| i.title.as_str()).collect::<Vec<_>>(), vec!["Plan offsite", "Budget"]);
+        assert_eq!(items[0].notes, vec!["venue ideas"]);
+    }
+
+    #[test]
+    fn headings_level_with_their_list_are_left_out() {
+        assert_eq!(titles("Action items:\n- Call Sam\n- Book room\nNotes:\n1. Send deck"), vec!["Call Sam", "Book room", "Send deck"]);
+        // Without a list under it a line ending in ":" is still a task
+        assert_eq!(titles("- Call Sam\nAsk Lee about:"), vec!["Call Sam", "Ask Lee about:"]);
+    }
+
+    #[test]
+    fn plain_lines_keep_the_bullets_under_them() {
+        let items = parse_capture("Plan offsite\n  - Book venue\n  - Order food\nBudget");
+        assert_eq!(items.iter().map(|i| i.title.as_str()).collect::<Vec<_>>(), vec!["Plan offsite", "Budget"]);
+        assert_eq!(items[0].notes, vec!["Book venue", "Order food"]);
+        assert_eq!(items[0].description().as_deref(), Some("- Book venue\n- Order food"));
+    }
+
+    #[test]
+    fn only_several_lines_count_as_multiline() {
+        assert!(!is_multiline("One task\n\n"));
+        assert!(is_multiline("One\nTwo"));
+    }
+}
@@ -0,0 +1,37 @@
+use dioxus::prelude::*;
+use crate::models::Task;
+use crate::settings::SettingsState;
+
+/// HEAT: Tailwind classes that tint a card or row the longer it sits untouched.
+/// Kept subtle on purpose: a hint, not an alarm.
+pub fn heat_class(idle_days: i64) -> &'static str {
+    match idle_days {
+        d if d >= 30 => "border-l-2 border-l-red-500/70 bg-red-500/[0.05]",
+        d if d >= 14 => "border-l-2 border-l-neon-amber/60 bg-neon-amber/[0.04]",
+        d if d >= 7 => "border-l-2 border-l-neon-amber/25",
+        _ => "",
+    }
+}
+
+/// AGE BADGE: "12d · 3d idle" with the exact timestamps on hover
+#[component]
+pub fn AgeBadge(task: Task) -> Element {
+    let age = task.age_days();
+    let idle = task.idle_days();
+    let i18n = use_context::<SettingsState>().i18n();
+    let tooltip = i18n.tf(
+        "Captured {when}\nLast touched {when_touched}",
+        &[("when", &i18n.date_time(&task.created_at)), ("when_touched", &i18n.date_time(&task.last_touched()))],
+    );
+    let age_label = i18n.tf("{days}d", &[("days", &age)]);
+    let idle_label = i18n.tf(" · {days}d idle", &[("days", &idle)]);
+
+    rsx! {
+        span { class: "text-[9px] font-mono tracking-widest text-gray-500 whitespace-nowrap", title: "{tooltip}",
+            "{age_label}"
+            if idle > 0 && idle != age {
+                "{idle_label}"
+            }
+        }
+    }
+}
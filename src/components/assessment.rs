@@ -0,0 +1,142 @@
+use dioxus::prelude::*;
+use crate::models::{Quadrant, Task};
+use crate::settings::SettingsState;
+use crate::store::AppState;
+
+/// QUESTION: One prompt of the questionnaire and its answers (label, score 0.0 – 1.0)
+struct Question {
+    prompt: &'static str,
+    answers: [(&'static str, f32); 4],
+}
+
+/// IMPORTANCE AXIS: Does the task matter to the mission?
+const IMPORTANCE_QUESTIONS: [Question; 2] = [
+    Question {
+        prompt: "Does this move a key metric or goal?",
+        answers: [("No", 0.0), ("A little", 0.33), ("Clearly", 0.67), ("It's the main lever", 1.0)],
+    },
+    Question {
+        prompt: "Who would notice if it never got done?",
+        answers: [("Nobody", 0.0), ("Just me", 0.33), ("My team", 0.67), ("Customers or investors", 1.0)],
+    },
+];
+
+/// URGENCY AXIS: Does the task need attention now?
+const URGENCY_QUESTIONS: [Question; 2] = [
+    Question {
+        prompt: "What happens if it slips a week?",
+        answers: [("Nothing", 0.0), ("Minor annoyance", 0.33), ("Someone is blocked", 0.67), ("A deadline is missed", 1.0)],
+    },
+    Question {
+        prompt: "When is it actually due?",
+        answers: [("No date", 0.0), ("This month", 0.33), ("This week", 0.67), ("Today or tomorrow", 1.0)],
+    },
+];
+
+/// SCORE ASSISTANT: A modal questionnaire that scores a task on both axes and suggests a quadrant.
+/// `onplace` takes over "Move to …" (e.g. so triage can count the decision); without it the task is placed here.
+#[component]
+pub fn ScoreAssistant(task: Task, onclose: EventHandler<()>, onplace: Option<EventHandler<(f32, f32)>>) -> Element {
+    let app_state = use_context::<AppState>();
+    let settings_state = use_context::<SettingsState>();
+    let i18n = settings_state.i18n();
+
+    // One selected answer (index) per question; importance questions first, then urgency
+    let mut answers = use_signal(|| [None::<usize>; 4]);
+
+    // Average the answered questions of each axis once every question has an answer
+    let axis_score = |questions: &[Question], offset: usize| -> Option<f32> {
+        let picked = answers.read();
+        let mut total = 0.0;
+        for (i, q) in questions.iter().enumerate() {
+            total += q.answers[picked[offset + i]?].1;
+        }
+        Some(total / questions.len() as f32)
+    };
+    let importance = axis_score(&IMPORTANCE_QUESTIONS, 0);
+    let urgency = axis_score(&URGENCY_QUESTIONS, IMPORTANCE_QUESTIONS.len());
+    let suggestion = match (urgency, importance) {
+        (Some(u), Some(i)) => Some((u, i, Quadrant::from_scores(u, i))),
+        _ => None,
+    };
+
+    let sections = [
+        ("IMPORTANCE", &IMPORTANCE_QUESTIONS, 0),
+        ("URGENCY", &URGENCY_QUESTIONS, IMPORTANCE_QUESTIONS.len()),
+    ];
+
+    rsx! {
+        // BACKDROP: Clicking outside the panel closes the questionnaire
+        div { class: "fixed inset-0 z-50 bg-black/60 backdrop-blur-sm flex items-center justify-center p-8",
+            onclick: move |_| onclose.call(()),
+            div { class: "glass-panel rounded-2xl p-8 w-full max-w-2xl space-y-6",
+                onclick: move |evt| evt.stop_propagation(),
+                div { class: "border-b border-space-700 pb-4",
+                    p { class: "hud-text mb-1", "{i18n.t(\"PRIORITY ASSESSMENT\")}" }
+                    h3 { class: "text-2xl font-bold text-white tracking-tight", "{task.title}" }
+                }
+
+                for (axis, questions, offset) in sections {
+                    div { class: "space-y-4",
+                        p { class: "text-[10px] font-mono tracking-widest text-gray-500", "{i18n.t(axis)}" }
+                        for (qi, question) in questions.iter().enumerate() {
+                            div { class: "space-y-2",
+                                p { class: "text-sm text-gray-200 font-medium", "{i18n.t(question.prompt)}" }
+                                div { class: "grid grid-cols-4 gap-2",
+                                    for (ai, (label, _)) in question.answers.iter().enumerate() {
+                                        button {
+                                            class: if answers.read()[offset + qi] == Some(ai) { "text-xs px-2 py-2 rounded border border-neon-cyan bg-neon-cyan/10 text-neon-cyan" } else { "text-xs px-2 py-2 rounded border border-space-700 text-gray-400 hover:text-white hover:border-gray-500 transition-colors" },
+                                            onclick: move |_| answers.write()[offset + qi] = Some(ai),
+                                            "{i18n.t(label)}"
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                // RESULT: The suggested quadrant plus the option to apply it or only keep the scores
+                div { class: "flex justify-between items-center border-t border-space-700 pt-4",
+                    if let Some((u, i, quadrant)) = suggestion.clone() {
+                        div {
+                            p { class: "text-[10px] font-mono tracking-widest text-gray-500", "{i18n.t(\"SUGGESTED SECTOR\")}" }
+                            p { class: "text-lg font-bold text-white", "{i18n.t(quadrant.label())}" }
+                            p { class: "text-[10px] font-mono text-gray-500", {i18n.tf("URGENCY {urgency} // IMPORTANCE {importance}", &[("urgency", &format!("{:.0}", u * 10.0)), ("importance", &format!("{:.0}", i * 10.0))])} }
+                        }
+                    } else {
+                        p { class: "text-xs font-mono text-gray-500", "{i18n.t(\"Answer every question to get a suggestion.\")}" }
+                    }
+                    div { class: "flex gap-3",
+                        button {
+                            class: "px-4 py-2 rounded border border-space-700 text-gray-400 hover:text-white transition-colors text-sm font-bold uppercase tracking-wide",
+                            onclick: move |_| onclose.call(()),
+                            "{i18n.t(\"Cancel\")}"
+                        }
+                        if let Some((u, i, quadrant)) = suggestion {
+                            button {
+                                class: "px-4 py-2 rounded border border-space-700 text-gray-300 hover:text-white transition-colors text-sm font-bold uppercase tracking-wide",
+                                onclick: move |_| {
+                                    app_state.update_task_scores(task.id, u, i);
+                                    onclose.call(());
+                                },
+                                "{i18n.t(\"Save Scores\")}"
+                            }
+                            button {
+                                class: "btn-primary",
+                                onclick: move |_| {
+                                    match onplace {
+                                        Some(onplace) => onplace.call((u, i)),
+                                        None => app_state.place_task(task.id, u, i, &settings_state.settings.peek().wip_limits),
+                                    }
+                                    onclose.call(());
+                                },
+                                {i18n.tf("Move to {quadrant}", &[("quadrant", &i18n.t(quadrant.label()))])}
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
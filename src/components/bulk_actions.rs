@@ -0,0 +1,169 @@
+use dioxus::prelude::*;
+use chrono::{Local, NaiveDate, NaiveDateTime};
+use crate::components::selection::Selection;
+use crate::models::Quadrant;
+use crate::settings::SettingsState;
+use crate::store::AppState;
+
+/// BULK ACTION BAR: Floats at the bottom of the screen while cards are selected.
+/// Every button is a single store change covering the whole selection.
+#[component]
+pub fn BulkActionBar() -> Element {
+    let app_state = use_context::<AppState>();
+    let selection = use_context::<Selection>();
+    let settings_state = use_context::<SettingsState>();
+    let i18n = settings_state.i18n();
+    let mut tag_val = use_signal(String::new);
+    let mut schedule_val = use_signal(String::new);
+    let mut project_val = use_signal(String::new);
+
+    let count = selection.ids.read().len();
+    if count == 0 {
+        return rsx! {};
+    }
+
+    let move_targets = [
+        (Quadrant::DoFirst, "DO", "hover:text-neon-pink hover:border-neon-pink"),
+        (Quadrant::Schedule, "SCH", "hover:text-neon-cyan hover:border-neon-cyan"),
+        (Quadrant::Delegate, "DEL", "hover:text-neon-amber hover:border-neon-amber"),
+        (Quadrant::Delete, "BIN", "hover:text-gray-200 hover:border-gray-400"),
+        (Quadrant::Unsorted, "INBOX", "hover:text-white hover:border-white"),
+    ];
+
+    rsx! {
+        div { class: "fixed bottom-6 left-1/2 -translate-x-1/2 z-40 glass-panel rounded-xl px-5 py-3 flex items-center gap-5 text-xs font-mono",
+            // Stop the surrounding lasso from treating clicks here as a new selection
+            onmousedown: move |evt| evt.stop_propagation(),
+
+            span { class: "text-neon-cyan font-bold tracking-widest whitespace-nowrap", {i18n.tf("{count} SELECTED", &[("count", &count)])} }
+
+            // MOVE: Send the selection to a quadrant (or back to the inbox)
+            div { class: "flex items-center gap-1",
+                span { class: "text-gray-500 mr-1", "{i18n.t(\"MOVE\")}" }
+                for (quadrant, label, hover) in move_targets {
+                    button {
+                        class: "px-2 py-1 rounded border border-space-700 text-gray-400 transition-colors {hover}",
+                        title: i18n.t(quadrant.label()),
+                        onclick: move |_| {
+                            app_state.move_to_quadrant(&selection.snapshot(), quadrant.clone(), &settings_state.settings.peek().wip_limits);
+                            selection.clear();
+                        },
+                        "{i18n.t(label)}"
+                    }
+                }
+            }
+
+            // TAG: Add one label to every selected task
+            input {
+                class: "w-28 bg-space-900 border border-space-700 rounded px-2 py-1 text-gray-200 placeholder-gray-600 focus:border-neon-cyan focus:outline-none",
+                placeholder: i18n.t("#tag ↵"),
+                value: "{tag_val}",
+                oninput: move |evt| tag_val.set(evt.value()),
+                onkeydown: move |evt| {
+                    if evt.key() == Key::Enter && !tag_val.read().trim().is_empty() {
+                        app_state.tag_tasks(&selection.snapshot(), &tag_val.read());
+                        tag_val.set(String::new());
+                    }
+                },
+            }
+
+            // PROJECT: File the selection under a project (empty ↵ clears it)
+            input {
+                class: "w-28 bg-space-900 border border-space-700 rounded px-2 py-1 text-gray-200 placeholder-gray-600 focus:border-neon-cyan focus:outline-none",
+                placeholder: i18n.t("project ↵"),
+                value: "{project_val}",
+                oninput: move |evt| project_val.set(evt.value()),
+                onkeydown: move |evt| {
+                    if evt.key() == Key::Enter {
+                        app_state.set_project(&selection.snapshot(), Some(project_val.read().clone()));
+                        project_val.set(String::new());
+                    }
+                },
+            }
+
+            // DUE: Deadline for every selected task (clearing the field removes it)
+            label { class: "flex items-center gap-1",
+                span { class: "text-gray-500", "{i18n.t(\"DUE\")}" }
+                input {
+                    r#type: "date",
+                    class: "bg-space-900 border border-space-700 rounded px-2 py-1 text-gray-200 focus:border-neon-cyan focus:outline-none",
+                    onchange: move |evt| {
+                        let due = NaiveDate::parse_from_str(&evt.value(), "%Y-%m-%d").ok();
+                        app_state.set_due(&selection.snapshot(), due);
+                    },
+                }
+            }
+
+            // ESTIMATE: Expected effort in minutes, used by "Plan my week" (clearing the field removes it)
+            label { class: "flex items-center gap-1",
+                span { class: "text-gray-500", "{i18n.t(\"EST\")}" }
+                input {
+                    r#type: "number",
+                    min: "5",
+                    step: "5",
+                    class: "w-16 bg-space-900 border border-space-700 rounded px-2 py-1 text-gray-200 placeholder-gray-600 focus:border-neon-cyan focus:outline-none",
+                    placeholder: i18n.t("min"),
+                    onchange: move |evt| {
+                        let minutes = evt.value().trim().parse::<u32>().ok().filter(|m| *m > 0);
+                        app_state.set_estimate(&selection.snapshot(), minutes);
+                    },
+                }
+            }
+
+            // SCHEDULE: Line the selection up on the calendar from a start time
+            div { class: "flex items-center gap-1",
+                input {
+                    r#type: "datetime-local",
+                    class: "bg-space-900 border border-space-700 rounded px-2 py-1 text-gray-200 focus:border-neon-cyan focus:outline-none",
+                    value: "{schedule_val}",
+                    oninput: move |evt| schedule_val.set(evt.value()),
+                }
+                button {
+                    class: "px-2 py-1 rounded border border-space-700 text-gray-400 hover:text-neon-green hover:border-neon-green transition-colors",
+                    onclick: move |_| {
+                        let parsed = NaiveDateTime::parse_from_str(&schedule_val.read(), "%Y-%m-%dT%H:%M")
+                            .ok()
+                            .and_then(|dt| dt.and_local_timezone(Local).single());
+                        if let Some(start) = parsed {
+                            let default_length = settings_state.settings.peek().calendar.default_block();
+                            app_state.schedule_tasks(&selection.snapshot(), start, default_length, &settings_state.settings.peek().wip_limits);
+                            selection.clear();
+                        }
+                    },
+                    "{i18n.t(\"SCHEDULE\")}"
+                }
+            }
+
+            button {
+                class: "px-2 py-1 rounded border border-space-700 text-gray-400 hover:text-neon-green hover:border-neon-green transition-colors",
+                onclick: move |_| {
+                    app_state.complete_tasks(&selection.snapshot());
+                    selection.clear();
+                },
+                "{i18n.t(\"DONE\")}"
+            }
+            button {
+                class: "px-2 py-1 rounded border border-space-700 text-gray-400 hover:text-white hover:border-gray-400 transition-colors",
+                onclick: move |_| {
+                    app_state.archive_tasks(&selection.snapshot());
+                    selection.clear();
+                },
+                "{i18n.t(\"ARCHIVE\")}"
+            }
+            button {
+                class: "px-2 py-1 rounded border border-space-700 text-gray-400 hover:text-red-500 hover:border-red-500 transition-colors",
+                onclick: move |_| {
+                    app_state.delete_tasks(&selection.snapshot());
+                    selection.clear();
+                },
+                "{i18n.t(\"DELETE\")}"
+            }
+            button {
+                class: "w-6 h-6 rounded-full text-gray-500 hover:text-white hover:bg-space-700 transition-colors",
+                title: i18n.t("Clear selection"),
+                onclick: move |_| selection.clear(),
+                "×"
+            }
+        }
+    }
+}
@@ -0,0 +1,128 @@
+use dioxus::prelude::*;
+use std::collections::HashSet;
+use crate::models::{Task, TaskStatus};
+use crate::settings::SettingsState;
+use crate::store::AppState;
+
+/// DEMOTE PROMPT: Shown on any page when a quadrant change would break a WIP limit
+/// (see AppState::move_to_quadrant). Asks which residents make room before the move goes through.
+#[component]
+pub fn DemotePrompt() -> Element {
+    let app_state = use_context::<AppState>();
+    let i18n = use_context::<SettingsState>().i18n();
+    let mut chosen = use_signal(HashSet::<uuid::Uuid>::new);
+
+    let Some(pending) = (app_state.pending_move)() else {
+        return rsx! {};
+    };
+    let mut close = move || {
+        chosen.write().clear();
+        app_state.cancel_pending_move();
+    };
+
+    // Tasks already in the target quadrant (the incoming ones can't be demoted)
+    let residents: Vec<Task> = app_state.tasks.read()
+        .iter()
+        .filter(|t| t.quadrant == pending.quadrant && t.status == TaskStatus::Todo && !pending.ids.contains(&t.id))
+        .cloned()
+        .collect();
+    let demote_to = pending.quadrant.demoted();
+    let verb = i18n.t(if demote_to.is_some() { "Demote" } else { "Purge" });
+    let ready = chosen.read().len() >= pending.overflow;
+    let chosen_count = chosen.read().len();
+    let limit = pending.limit.unwrap_or_default();
+    let queued = app_state.queued_moves.read().len();
+
+    rsx! {
+        div { class: "fixed inset-0 z-50 bg-black/60 backdrop-blur-sm flex items-center justify-center p-8",
+            onmousedown: move |evt| evt.stop_propagation(),
+            onclick: move |_| close(),
+            div { class: "glass-panel rounded-2xl p-8 w-full max-w-xl space-y-6",
+                role: "dialog",
+                aria_modal: "true",
+                tabindex: "-1",
+                // Take focus so keys go to the prompt rather than the page behind it
+                onmounted: move |evt| async move {
+                    let _ = evt.data().set_focus(true).await;
+                },
+                onclick: move |evt| evt.stop_propagation(),
+                onkeydown: move |evt| {
+                    if evt.key() == Key::Escape {
+                        close();
+                    }
+                },
+                div {
+                    p { class: "hud-text mb-1", "{i18n.t(\"WIP LIMIT REACHED\")}" }
+                    h3 { class: "text-2xl font-bold text-white tracking-tight", {i18n.tf("{quadrant} is full", &[("quadrant", &i18n.t(pending.quadrant.label()))])} }
+                    if pending.overflow > 0 {
+                        p { class: "text-sm text-gray-400 mt-2",
+                            if let Some(target) = demote_to.clone() {
+                                {i18n.tf("Pick {count} task(s) to move down to {quadrant} to make room.", &[("count", &pending.overflow), ("quadrant", &i18n.t(target.label()))])}
+                            } else {
+                                {i18n.tf("Pick {count} task(s) to delete for good to make room.", &[("count", &pending.overflow)])}
+                            }
+                        }
+                    }
+                    // Even an empty quadrant can't take this many: say so instead of pretending there is a way in
+                    if pending.short > 0 {
+                        p { class: "text-sm text-red-400 mt-2",
+                            {i18n.tf("This move stays {short} over the limit of {limit} even with every task there moved out.", &[("short", &pending.short), ("limit", &limit)])}
+                        }
+                    }
+                    if queued > 0 {
+                        p { class: "text-xs font-mono text-gray-500 mt-2", {i18n.tf("{count} more move(s) waiting after this one.", &[("count", &queued)])} }
+                    }
+                }
+                ul { class: "space-y-2 max-h-72 overflow-y-auto",
+                    for task in residents {
+                        li { key: "{task.id}",
+                            label {
+                                class: if chosen.read().contains(&task.id) { "flex items-center gap-3 p-3 rounded-lg border border-neon-cyan bg-neon-cyan/10 text-white cursor-pointer focus-within:ring-2 focus-within:ring-neon-cyan" } else { "flex items-center gap-3 p-3 rounded-lg border border-space-700 text-gray-300 hover:border-gray-500 cursor-pointer focus-within:ring-2 focus-within:ring-neon-cyan" },
+                                input {
+                                    r#type: "checkbox",
+                                    class: "accent-neon-cyan",
+                                    checked: chosen.read().contains(&task.id),
+                                    onchange: move |evt| {
+                                        let mut chosen = chosen.write();
+                                        if evt.checked() {
+                                            chosen.insert(task.id);
+                                        } else {
+                                            chosen.remove(&task.id);
+                                        }
+                                    },
+                                }
+                                "{task.title}"
+                            }
+                        }
+                    }
+                }
+                div { class: "flex justify-end gap-3",
+                    button {
+                        class: "px-4 py-2 rounded border border-space-700 text-gray-400 hover:text-white transition-colors text-sm font-bold uppercase tracking-wide",
+                        onclick: move |_| close(),
+                        "{i18n.t(\"Cancel\")}"
+                    }
+                    button {
+                        class: "px-4 py-2 rounded border border-space-700 text-gray-300 hover:text-white transition-colors text-sm font-bold uppercase tracking-wide",
+                        onclick: move |_| {
+                            chosen.write().clear();
+                            app_state.finish_pending_move(&HashSet::new());
+                        },
+                        "{i18n.t(\"Move anyway\")}"
+                    }
+                    if pending.overflow > 0 {
+                        button {
+                            class: "btn-primary",
+                            disabled: !ready,
+                            onclick: move |_| {
+                                let demoted = std::mem::take(&mut *chosen.write());
+                                app_state.finish_pending_move(&demoted);
+                            },
+                            {i18n.tf("{verb} {count} & move", &[("verb", &verb), ("count", &chosen_count)])}
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
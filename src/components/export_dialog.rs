@@ -0,0 +1,166 @@
+use dioxus::prelude::*;
+use chrono::NaiveDate;
+use crate::export;
+use crate::models::Quadrant;
+use crate::settings::SettingsState;
+use crate::store::AppState;
+use crate::views::calendar::save_ics;
+
+/// Reminder choices in minutes before the start (None = no reminder)
+const REMINDERS: [Option<u32>; 6] = [None, Some(5), Some(10), Some(15), Some(30), Some(60)];
+
+/// EXPORT DIALOG: Picks what the ICS export writes: a date range, the quadrants, events or to-dos,
+/// and a reminder. The choices other than the range are remembered in the settings.
+#[component]
+pub fn ExportDialog(onclose: EventHandler<()>) -> Element {
+    let app_state = use_context::<AppState>();
+    let settings_state = use_context::<SettingsState>();
+    let i18n = settings_state.i18n();
+    let prefs = settings_state.settings.read().export.clone();
+    let mut from = use_signal(|| None::<NaiveDate>);
+    let mut until = use_signal(|| None::<NaiveDate>);
+
+    let count = export::selection(&app_state.tasks.read(), &prefs, from(), until()).len();
+    let date_value = |date: Option<NaiveDate>| date.map(|d| d.format("%Y-%m-%d").to_string()).unwrap_or_default();
+    let parse_date = |value: String| NaiveDate::parse_from_str(&value, "%Y-%m-%d").ok();
+    let field_class = "bg-space-900 border border-space-700 rounded px-2 py-1 text-sm text-white font-mono focus:border-neon-cyan focus:outline-none";
+    let label_class = "text-[10px] font-mono tracking-widest text-gray-500 uppercase";
+
+    let export = move |_| {
+        let prefs = settings_state.settings.peek().export.clone();
+        let ics = export::build_ics(&export::selection(&app_state.tasks.peek(), &prefs, from(), until()), &prefs, i18n);
+        spawn(save_ics(ics));
+        onclose.call(());
+    };
+
+    rsx! {
+        // BACKDROP: Clicking outside cancels
+        div { class: "fixed inset-0 z-50 bg-black/40 flex items-center justify-center p-8",
+            onmousedown: move |evt| evt.stop_propagation(),
+            onclick: move |_| onclose.call(()),
+            div {
+                role: "dialog",
+                aria_modal: "true",
+                aria_label: i18n.t("Export Calendar (.ics)"),
+                class: "glass-panel rounded-xl p-6 w-[28rem] max-h-full flex flex-col gap-5",
+                onclick: move |evt| evt.stop_propagation(),
+                onkeydown: move |evt| {
+                    evt.stop_propagation();
+                    if evt.key() == Key::Escape {
+                        onclose.call(());
+                    }
+                },
+                h2 { class: "text-lg font-bold text-white", "{i18n.t(\"Export Calendar (.ics)\")}" }
+
+                // ── Date range (empty = open-ended) ────────────────
+                fieldset { class: "space-y-2",
+                    legend { class: label_class, "{i18n.t(\"Dates\")}" }
+                    div { class: "flex items-center gap-2 text-sm text-gray-400",
+                        input {
+                            r#type: "date",
+                            class: field_class,
+                            aria_label: i18n.t("From"),
+                            value: "{date_value(from())}",
+                            onchange: move |evt| from.set(parse_date(evt.value())),
+                        }
+                        span { "–" }
+                        input {
+                            r#type: "date",
+                            class: field_class,
+                            aria_label: i18n.t("Until"),
+                            value: "{date_value(until())}",
+                            onchange: move |evt| until.set(parse_date(evt.value())),
+                        }
+                    }
+                }
+
+                // ── Quadrants ──────────────────────────────────────
+                fieldset { class: "space-y-2",
+                    legend { class: label_class, "{i18n.t(\"Quadrants\")}" }
+                    div { class: "grid grid-cols-2 gap-1 text-sm text-gray-300",
+                        for quadrant in Quadrant::ALL {
+                            label { class: "flex items-center gap-2",
+                                input {
+                                    r#type: "checkbox",
+                                    checked: prefs.quadrants.contains(&quadrant),
+                                    onchange: {
+                                        let quadrant = quadrant.clone();
+                                        move |evt: FormEvent| {
+                                            let quadrant = quadrant.clone();
+                                            settings_state.update(|s| {
+                                                s.export.quadrants.retain(|q| *q != quadrant);
+                                                if evt.checked() {
+                                                    s.export.quadrants.push(quadrant);
+                                                }
+                                            });
+                                        }
+                                    },
+                                }
+                                "{i18n.t(quadrant.label())}"
+                            }
+                        }
+                    }
+                }
+
+                // ── Format ─────────────────────────────────────────
+                fieldset { class: "space-y-2",
+                    legend { class: label_class, "{i18n.t(\"Export as\")}" }
+                    div { class: "flex gap-4 text-sm text-gray-300",
+                        for (as_todos, label) in [(false, i18n.t("Events (VEVENT)")), (true, i18n.t("To-dos (VTODO)"))] {
+                            label { class: "flex items-center gap-2",
+                                input {
+                                    r#type: "radio",
+                                    name: "ics-format",
+                                    checked: prefs.as_todos == as_todos,
+                                    onchange: move |_| settings_state.update(|s| s.export.as_todos = as_todos),
+                                }
+                                "{label}"
+                            }
+                        }
+                    }
+                    if prefs.as_todos {
+                        p { class: "text-xs text-gray-500", "{i18n.t(\"To-dos include tasks without a slot.\")}" }
+                    }
+                }
+
+                // ── Reminder ───────────────────────────────────────
+                div { class: "flex items-center gap-3 text-sm text-gray-400",
+                    label { r#for: "ics-reminder", "{i18n.t(\"Reminder\")}" }
+                    select {
+                        id: "ics-reminder",
+                        class: field_class,
+                        onchange: move |evt| {
+                            let minutes = evt.value().parse::<u32>().ok();
+                            settings_state.update(|s| s.export.reminder_minutes = minutes);
+                        },
+                        for minutes in REMINDERS {
+                            option {
+                                value: minutes.map(|m| m.to_string()).unwrap_or_default(),
+                                selected: prefs.reminder_minutes == minutes,
+                                match minutes {
+                                    Some(m) => i18n.tf("{minutes} min before", &[("minutes", &m)]),
+                                    None => i18n.t("None").to_string(),
+                                }
+                            }
+                        }
+                    }
+                }
+
+                div { class: "flex gap-2 justify-end",
+                    button {
+                        class: "px-3 py-1 rounded border border-space-700 text-gray-400 hover:text-white text-xs font-bold uppercase tracking-wide",
+                        onclick: move |_| onclose.call(()),
+                        "{i18n.t(\"Cancel\")}"
+                    }
+                    button {
+                        class: "btn-primary text-xs disabled:opacity-40",
+                        disabled: count == 0,
+                        onmounted: move |evt| async move { let _ = evt.data().set_focus(true).await; },
+                        onclick: export,
+                        {i18n.tf("Export {count}", &[("count", &count)])}
+                    }
+                }
+            }
+        }
+    }
+}
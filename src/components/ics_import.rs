@@ -0,0 +1,159 @@
+use dioxus::prelude::*;
+use std::collections::HashSet;
+use chrono::Datelike;
+use crate::ics::{ItemKind, TaskItem};
+use crate::models::Quadrant;
+use crate::settings::SettingsState;
+use crate::store::AppState;
+
+/// Where an imported batch can go; the Inbox comes first as the default
+const TARGETS: [(Quadrant, &str); 5] = [
+    (Quadrant::Unsorted, "Inbox"),
+    (Quadrant::DoFirst, "Do First"),
+    (Quadrant::Schedule, "Schedule"),
+    (Quadrant::Delegate, "Delegate"),
+    (Quadrant::Delete, "Delete"),
+];
+
+/// ICS IMPORT DIALOG: Previews the events and to-dos read from `file` before they become tasks.
+/// Items whose UID is already in the tasks or the archive are shown but cannot be imported again,
+/// and finished to-dos start unticked. The whole batch goes to one quadrant as a single undo step.
+#[component]
+pub fn IcsImportDialog(file: String, items: Vec<TaskItem>, onclose: EventHandler<()>) -> Element {
+    let app_state = use_context::<AppState>();
+    let i18n = use_context::<SettingsState>().i18n();
+
+    // UIDs already known: imported before, or exported from here (the task id is the UID)
+    let known: HashSet<String> = use_hook(|| {
+        let tasks = app_state.tasks.peek();
+        let archive = app_state.archive.peek();
+        tasks
+            .iter()
+            .chain(archive.iter())
+            .flat_map(|t| [Some(t.id.to_string()), t.ics_uid.clone()])
+            .flatten()
+            .collect()
+    });
+    let mut keep = use_signal(|| items.iter().map(|item| !known.contains(&item.uid) && !item.done).collect::<Vec<bool>>());
+    let mut target = use_signal(|| Quadrant::Unsorted);
+    let kept = keep.read().iter().filter(|k| **k).count();
+    let duplicates = items.iter().filter(|item| known.contains(&item.uid)).count();
+
+    let import = {
+        let items = items.clone();
+        move |_| {
+            let tasks = items
+                .iter()
+                .zip(keep.read().iter())
+                .filter(|(_, keep)| **keep)
+                .map(|(item, _)| item.to_task(target()))
+                .collect();
+            app_state.add_tasks(tasks);
+            onclose.call(());
+        }
+    };
+
+    rsx! {
+        // BACKDROP: Clicking outside cancels
+        div { class: "fixed inset-0 z-50 bg-black/40 flex items-center justify-center p-8",
+            onclick: move |_| onclose.call(()),
+            div {
+                role: "dialog",
+                aria_modal: "true",
+                aria_label: i18n.t("Import tasks"),
+                class: "glass-panel rounded-xl p-6 w-[40rem] max-h-full flex flex-col gap-4",
+                onclick: move |evt| evt.stop_propagation(),
+                onkeydown: move |evt| {
+                    evt.stop_propagation();
+                    if evt.key() == Key::Escape {
+                        onclose.call(());
+                    }
+                },
+                div {
+                    h2 { class: "text-lg font-bold text-white", "{i18n.t(\"Import tasks\")}" }
+                    p { class: "text-xs font-mono text-gray-500 truncate", "{file}" }
+                }
+
+                if items.is_empty() {
+                    p { class: "text-sm text-gray-400", "{i18n.t(\"No events or to-dos in this file.\")}" }
+                } else {
+                    div { class: "flex items-center gap-3 text-sm text-gray-400",
+                        label { r#for: "ics-import-target", "{i18n.t(\"Add to\")}" }
+                        select {
+                            id: "ics-import-target",
+                            class: "bg-space-900 border border-space-700 rounded px-2 py-1 text-white focus:border-neon-cyan focus:outline-none",
+                            onchange: move |evt| {
+                                if let Some((quadrant, _)) = TARGETS.iter().find(|(_, name)| *name == evt.value()) {
+                                    target.set(quadrant.clone());
+                                }
+                            },
+                            for (quadrant, name) in TARGETS {
+                                option { value: name, selected: target() == quadrant, "{i18n.t(name)}" }
+                            }
+                        }
+                        if duplicates > 0 {
+                            span { class: "ml-auto text-xs text-gray-500", {i18n.tf("{count} already imported", &[("count", &duplicates)])} }
+                        }
+                    }
+                }
+
+                div { class: "flex-1 overflow-y-auto space-y-1 min-h-0",
+                    for (i, item) in items.iter().cloned().enumerate() {
+                        {
+                            let duplicate = known.contains(&item.uid);
+                            let included = keep.read()[i];
+                            let when = match (item.start, item.end, item.due) {
+                                (Some(start), Some(end), _) => format!("{} {}, {}", i18n.weekday_short(start.weekday()), i18n.day_month(start.date_naive()), i18n.time_range(&start, &end)),
+                                (Some(start), None, _) => format!("{} {}, {}", i18n.weekday_short(start.weekday()), i18n.day_month(start.date_naive()), i18n.time(&start)),
+                                (None, _, Some(due)) => i18n.tf("Due {date}", &[("date", &i18n.day_month(due))]),
+                                (None, _, None) => String::new(),
+                            };
+                            let kind = match item.kind {
+                                ItemKind::Event => i18n.t("EVENT"),
+                                ItemKind::Todo => i18n.t("TO-DO"),
+                            };
+                            rsx! {
+                                div { key: "{item.uid}", class: "flex items-center gap-3 px-2 py-1.5 rounded hover:bg-space-700/40",
+                                    input {
+                                        r#type: "checkbox",
+                                        checked: included,
+                                        disabled: duplicate,
+                                        aria_label: "{item.title}",
+                                        onchange: move |evt| keep.write()[i] = evt.checked(),
+                                    }
+                                    span { class: "shrink-0 text-[9px] font-bold font-mono px-1.5 py-0.5 rounded bg-space-800 text-gray-400", "{kind}" }
+                                    div { class: if included { "flex-1 min-w-0" } else { "flex-1 min-w-0 opacity-40" },
+                                        div { class: "text-sm text-white truncate", "{item.title}" }
+                                        if !when.is_empty() {
+                                            div { class: "text-xs font-mono text-gray-400", "{when}" }
+                                        }
+                                    }
+                                    if duplicate {
+                                        span { class: "text-[10px] font-mono text-gray-500 uppercase tracking-widest", "{i18n.t(\"Already imported\")}" }
+                                    } else if item.done {
+                                        span { class: "text-[10px] font-mono text-gray-500 uppercase tracking-widest", "{i18n.t(\"Already done\")}" }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                div { class: "flex gap-2 justify-end",
+                    button {
+                        class: "px-3 py-1 rounded border border-space-700 text-gray-400 hover:text-white text-xs font-bold uppercase tracking-wide",
+                        onclick: move |_| onclose.call(()),
+                        "{i18n.t(\"Cancel\")}"
+                    }
+                    button {
+                        class: "btn-primary text-xs disabled:opacity-40",
+                        disabled: kept == 0,
+                        onmounted: move |evt| async move { let _ = evt.data().set_focus(true).await; },
+                        onclick: import,
+                        {i18n.tf("Import {count}", &[("count", &kept)])}
+                    }
+                }
+            }
+        }
+    }
+}
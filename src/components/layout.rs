@@ -1,10 +1,24 @@
 use dioxus::prelude::*;
-use crate::components::Sidebar;
+use std::collections::HashSet;
+use crate::components::{BulkActionBar, CommandPalette, DemotePrompt, Selection, ShortcutLayer, Sidebar};
 use crate::routes::Route;
+use crate::store::AppState;
 
 /// SHELL COMPONENT: This wraps every page with a consistent sidebar and background
 #[component]
 pub fn SidebarLayout() -> Element {
+    let app_state = use_context::<AppState>();
+    let selection = use_context::<Selection>();
+    let route = use_route::<Route>();
+
+    // The selection belongs to the page it was made on, so the bulk bar closes when leaving it...
+    use_effect(use_reactive((&route,), move |_| selection.clear()));
+    // ...and it never keeps tasks that were deleted or archived
+    use_effect(move || {
+        let live: HashSet<uuid::Uuid> = app_state.tasks.read().iter().map(|t| t.id).collect();
+        selection.retain(&live);
+    });
+
     rsx! {
         div { class: "flex h-screen bg-space-900 text-gray-100 font-sans overflow-hidden",
             // The persistent navigation menu on the left
@@ -17,7 +31,17 @@ pub fn SidebarLayout() -> Element {
                 
                 // This is where the specific page content (Inbox, Matrix, etc.) appears
                 Outlet::<Route> {}
+
+                // Appears whenever cards are selected on the current page
+                BulkActionBar {}
             }
+
+            // Asks what makes room when a move would break a WIP limit
+            DemotePrompt {}
+            // Ctrl/Cmd+K search over tasks and commands
+            CommandPalette {}
+            // App-wide keyboard shortcuts and their cheat sheet
+            ShortcutLayer {}
         }
     }
 }
@@ -1,7 +1,31 @@
 /// COMPONENTS MODULE: Reusable UI pieces used across multiple pages
 pub mod sidebar;
 pub mod layout;
+pub mod assessment;
+pub mod selection;
+pub mod bulk_actions;
+pub mod aging;
+pub mod palette;
+pub mod task_card;
+pub mod shortcuts;
+pub mod task_menu;
+pub mod plan_dialog;
+pub mod ics_import;
+pub mod export_dialog;
+pub mod demote_prompt;
 
 pub use sidebar::Sidebar;
+pub use assessment::ScoreAssistant;
+pub use selection::{use_card_registration, LassoArea, Selection};
+pub use bulk_actions::BulkActionBar;
+pub use aging::{heat_class, AgeBadge};
+pub use palette::CommandPalette;
+pub use task_card::TaskCard;
+pub use shortcuts::{ShortcutLayer, Shortcuts};
+pub use task_menu::{opens_task_menu, TaskMenu};
+pub use plan_dialog::PlanDialog;
+pub use ics_import::IcsImportDialog;
+pub use export_dialog::ExportDialog;
+pub use demote_prompt::DemotePrompt;
 
 
@@ -0,0 +1,242 @@
+use dioxus::prelude::*;
+use crate::caldav::CalDavState;
+use crate::components::{Selection, Shortcuts};
+use crate::export;
+use crate::i18n::I18n;
+use crate::models::{Quadrant, Task};
+use crate::routes::Route;
+use crate::search;
+use crate::settings::SettingsState;
+use crate::store::AppState;
+use crate::views::calendar::save_ics;
+
+/// How many matching tasks the palette lists at most
+const MAX_TASK_RESULTS: usize = 12;
+
+/// PALETTE ACTION: What happens when an entry is picked
+#[derive(Clone, PartialEq)]
+enum PaletteAction {
+    Go(Route),
+    NewTask(String),
+    ExportIcs,
+    SyncCalDav,
+    Open(uuid::Uuid, Route),
+}
+
+/// PALETTE ENTRY: One row of the result list
+#[derive(Clone, PartialEq)]
+struct PaletteEntry {
+    label: String,
+    hint: String,
+    action: PaletteAction,
+}
+
+/// COMMAND PALETTE: Ctrl/Cmd+K opens a fuzzy search over every task (matrix, calendar and
+/// archive) plus a handful of app commands. Picking a task jumps to it in context.
+#[component]
+pub fn CommandPalette() -> Element {
+    let app_state = use_context::<AppState>();
+    let selection = use_context::<Selection>();
+    let settings_state = use_context::<SettingsState>();
+    let caldav = use_context::<CalDavState>();
+    let navigator = use_navigator();
+
+    // Opened by the keyboard layer (Ctrl/Cmd+K by default)
+    let mut open = use_context::<Shortcuts>().palette_open;
+    let mut query = use_signal(String::new);
+    let mut active = use_signal(|| 0usize);
+
+    // Stage 1: Start from an empty query every time the palette opens
+    use_effect(move || {
+        if open() {
+            query.set(String::new());
+            active.set(0);
+        }
+    });
+
+    if !open() {
+        return rsx! {};
+    }
+
+    // Stage 2: Build the result list for the current query
+    let syncs = !settings_state.settings.read().caldav.url.trim().is_empty();
+    let i18n = settings_state.i18n();
+    let entries = palette_entries(&app_state, &query.read(), syncs, i18n);
+    let count = entries.len();
+    let highlighted = active().min(count.saturating_sub(1));
+    let actions: Vec<PaletteAction> = entries.iter().map(|e| e.action.clone()).collect();
+
+    // Closes the palette and carries out the picked entry
+    let mut run = move |action: PaletteAction| {
+        open.set(false);
+        match action {
+            PaletteAction::Go(route) => {
+                navigator.push(route);
+            }
+            PaletteAction::NewTask(title) => {
+                let task = Task::new(title);
+                let id = task.id;
+                app_state.add_tasks(vec![task]);
+                selection.reveal(id);
+                navigator.push(Route::Inbox {});
+            }
+            PaletteAction::ExportIcs => {
+                // Straight to the save dialog with the export choices last made in the calendar
+                let prefs = settings_state.settings.read().export.clone();
+                let ics = export::build_ics(&export::selection(&app_state.tasks.read(), &prefs, None, None), &prefs, settings_state.i18n());
+                spawn(save_ics(ics));
+            }
+            PaletteAction::SyncCalDav => {
+                spawn(caldav.sync_now(app_state, settings_state));
+            }
+            PaletteAction::Open(id, route) => {
+                selection.reveal(id);
+                navigator.push(route);
+            }
+        }
+    };
+
+    rsx! {
+        // BACKDROP: Clicking outside closes the palette
+        div { class: "fixed inset-0 z-50 bg-black/60 backdrop-blur-sm flex justify-center pt-[15vh] px-8",
+            onclick: move |_| open.set(false),
+            div { class: "glass-panel rounded-2xl w-full max-w-2xl h-fit overflow-hidden",
+                onclick: move |evt| evt.stop_propagation(),
+                input {
+                    class: "w-full bg-transparent border-b border-space-700 px-5 py-4 text-lg text-white placeholder-gray-600 focus:outline-none",
+                    placeholder: i18n.t("Search tasks or type a command…"),
+                    value: "{query}",
+                    autofocus: true,
+                    onmounted: move |evt| async move {
+                        let _ = evt.data().set_focus(true).await;
+                    },
+                    oninput: move |evt| {
+                        query.set(evt.value());
+                        active.set(0);
+                    },
+                    onkeydown: move |evt| match evt.key() {
+                        Key::ArrowDown => {
+                            evt.prevent_default();
+                            if count > 0 {
+                                active.set((highlighted + 1) % count);
+                            }
+                        }
+                        Key::ArrowUp => {
+                            evt.prevent_default();
+                            if count > 0 {
+                                active.set((highlighted + count - 1) % count);
+                            }
+                        }
+                        Key::Enter => {
+                            if let Some(action) = actions.get(highlighted) {
+                                run(action.clone());
+                            }
+                        }
+                        Key::Escape => open.set(false),
+                        _ => {}
+                    },
+                }
+
+                // RESULTS: Commands and tasks, best match first
+                div { class: "max-h-[50vh] overflow-y-auto py-2",
+                    if entries.is_empty() {
+                        p { class: "px-5 py-6 text-center text-xs font-mono text-gray-500", "{i18n.t(\"// NO MATCHES\")}" }
+                    }
+                    for (i, entry) in entries.into_iter().enumerate() {
+                        button {
+                            class: if i == highlighted { "w-full flex justify-between items-center gap-4 px-5 py-2.5 text-left bg-neon-cyan/10 text-white" } else { "w-full flex justify-between items-center gap-4 px-5 py-2.5 text-left text-gray-300 hover:bg-space-800" },
+                            onmouseenter: move |_| active.set(i),
+                            onclick: {
+                                let action = entry.action.clone();
+                                move |_| run(action.clone())
+                            },
+                            span { class: "truncate", "{entry.label}" }
+                            span { class: "text-[10px] font-mono tracking-widest uppercase text-gray-500 whitespace-nowrap", "{entry.hint}" }
+                        }
+                    }
+                }
+                div { class: "flex gap-6 px-5 py-2 border-t border-space-700 text-[10px] font-mono tracking-widest uppercase text-gray-600",
+                    span { "↑↓ {i18n.t(\"Navigate\")}" }
+                    span { "↵ {i18n.t(\"Open\")}" }
+                    span { "Esc {i18n.t(\"Close\")}" }
+                }
+            }
+        }
+    }
+}
+
+/// Lists the commands and tasks matching `query`, best match first
+fn palette_entries(app_state: &AppState, query: &str, syncs: bool, i18n: I18n) -> Vec<PaletteEntry> {
+    let query = query.trim();
+
+    // Stage 1: App commands, matched on their label
+    let mut commands = vec![
+        ("Go to Brainstorm", Route::Inbox {}),
+        ("Go to Matrix", Route::Matrix {}),
+        ("Go to Triage", Route::Triage {}),
+        ("Go to Calendar", Route::Calendar {}),
+        ("Go to Today (day view)", Route::CalendarDay { date: chrono::Local::now().date_naive() }),
+        ("Go to Month view", Route::CalendarMonth {}),
+        ("Go to Agenda", Route::CalendarAgenda {}),
+        ("Go to Archive", Route::Archive {}),
+        ("Go to Settings", Route::Settings {}),
+        ("Go to Tutorial", Route::Tutorial {}),
+    ]
+    .into_iter()
+    .map(|(label, route)| (i18n.t(label).to_string(), PaletteAction::Go(route)))
+    .collect::<Vec<_>>();
+    commands.push((i18n.t("Export calendar (ICS)").to_string(), PaletteAction::ExportIcs));
+    if syncs {
+        commands.push((i18n.t("Sync with CalDAV server").to_string(), PaletteAction::SyncCalDav));
+    }
+
+    let mut scored: Vec<(i32, PaletteEntry)> = commands
+        .into_iter()
+        .filter_map(|(label, action)| {
+            let score = search::fuzzy_score(query, &label)?;
+            Some((score, PaletteEntry { label, hint: i18n.t("Command").to_string(), action }))
+        })
+        .collect();
+
+    // Stage 2: Tasks everywhere, only once the user has typed something
+    if !query.is_empty() {
+        let tasks = app_state.tasks.read();
+        let archive = app_state.archive.read();
+        let mut hits: Vec<(i32, PaletteEntry)> = tasks.iter()
+            .map(|t| (t, false))
+            .chain(archive.iter().map(|t| (t, true)))
+            .filter_map(|(task, archived)| {
+                let score = search::task_score(query, task)?;
+                let (route, hint) = task_location(task, archived, i18n);
+                Some((score, PaletteEntry { label: task.title.clone(), hint, action: PaletteAction::Open(task.id, route) }))
+            })
+            .collect();
+        hits.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+        hits.truncate(MAX_TASK_RESULTS);
+        scored.extend(hits);
+
+        // Offer to capture the query as a new task
+        scored.push((i32::MIN, PaletteEntry {
+            label: i18n.tf("New task: {query}", &[("query", &query)]),
+            hint: i18n.t("Command").to_string(),
+            action: PaletteAction::NewTask(query.to_string()),
+        }));
+    }
+
+    scored.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+    scored.into_iter().map(|(_, entry)| entry).collect()
+}
+
+/// Where a task lives in the app, and a short label for the result row
+fn task_location(task: &Task, archived: bool, i18n: I18n) -> (Route, String) {
+    if archived {
+        (Route::Archive {}, i18n.t("Archive").to_string())
+    } else if task.scheduled_start.is_some() && matches!(task.quadrant, Quadrant::DoFirst | Quadrant::Schedule) {
+        let day = task.scheduled_start.map(|s| i18n.day_month(s.date_naive())).unwrap_or_default();
+        (Route::Calendar {}, format!("{} · {day}", i18n.t("Calendar")))
+    } else if task.quadrant == Quadrant::Unsorted {
+        (Route::Inbox {}, i18n.t("Brainstorm").to_string())
+    } else {
+        (Route::Matrix {}, i18n.t(task.quadrant.label()).to_string())
+    }
+}
@@ -0,0 +1,165 @@
+use dioxus::prelude::*;
+use chrono::{Datelike, Local, NaiveDate, NaiveDateTime};
+use crate::planner::{self, Skipped};
+use crate::settings::SettingsState;
+use crate::store::AppState;
+
+/// PROPOSED ROW: One block of the proposal, editable until it is accepted
+#[derive(Clone, PartialEq)]
+struct Row {
+    task_id: uuid::Uuid,
+    start: NaiveDateTime,
+    end: NaiveDateTime,
+    keep: bool,
+}
+
+/// PLAN DIALOG: "Plan my week". Runs the planner from `from` to the end of `until` when it opens
+/// and lists the proposed blocks; each one can be unticked or given another start (keeping its
+/// length) before Accept writes them all as a single undo step.
+#[component]
+pub fn PlanDialog(from: NaiveDateTime, until: NaiveDate, onclose: EventHandler<()>) -> Element {
+    let app_state = use_context::<AppState>();
+    let settings_state = use_context::<SettingsState>();
+    let i18n = settings_state.i18n();
+
+    let plan = use_hook(|| planner::plan(&app_state.tasks.peek(), &app_state.busy.peek(), from, until, &settings_state.settings.peek().calendar));
+    let mut rows = use_signal(|| {
+        plan.blocks
+            .iter()
+            .map(|b| Row { task_id: b.task_id, start: b.start, end: b.end, keep: true })
+            .collect::<Vec<_>>()
+    });
+
+    let title_of = move |id: uuid::Uuid| {
+        app_state.tasks.read().iter().find(|t| t.id == id).map(|t| t.title.clone()).unwrap_or_default()
+    };
+    let skipped: Vec<(uuid::Uuid, String, String)> = plan.skipped
+        .iter()
+        .map(|(id, why)| {
+            let reason = match why {
+                Skipped::NoRoom => i18n.t("No free working time left in this range").to_string(),
+                Skipped::MissesDeadline => i18n.t("Would end after its deadline").to_string(),
+                Skipped::Blocked(dep) => i18n.tf("Waits on {title}", &[("title", &title_of(*dep))]),
+            };
+            (*id, title_of(*id), reason)
+        })
+        .collect();
+    let kept = rows.read().iter().filter(|r| r.keep).count();
+
+    let accept = move |_| {
+        let blocks: Vec<_> = rows.read()
+            .iter()
+            .filter(|r| r.keep)
+            .filter_map(|r| {
+                let start = r.start.and_local_timezone(Local).single()?;
+                let end = r.end.and_local_timezone(Local).single()?;
+                Some((r.task_id, start, end))
+            })
+            .collect();
+        app_state.apply_plan(&blocks);
+        onclose.call(());
+    };
+
+    rsx! {
+        // BACKDROP: Clicking outside cancels
+        div { class: "fixed inset-0 z-50 bg-black/40 flex items-center justify-center p-8",
+            onmousedown: move |evt| evt.stop_propagation(),
+            onclick: move |_| onclose.call(()),
+            div {
+                role: "dialog",
+                aria_modal: "true",
+                aria_label: i18n.t("Plan my week"),
+                class: "glass-panel rounded-xl p-6 w-[40rem] max-h-full flex flex-col gap-4",
+                onclick: move |evt| evt.stop_propagation(),
+                onkeydown: move |evt| {
+                    evt.stop_propagation();
+                    if evt.key() == Key::Escape {
+                        onclose.call(());
+                    }
+                },
+                h2 { class: "text-lg font-bold text-white", "{i18n.t(\"Plan my week\")}" }
+
+                if rows.read().is_empty() && skipped.is_empty() {
+                    p { class: "text-sm text-gray-400",
+                        {i18n.t("Nothing to plan: every open Do First and Schedule task already has a slot.")}
+                    }
+                }
+
+                div { class: "flex-1 overflow-y-auto space-y-4 min-h-0",
+                    // ── Proposed blocks ─────────────────────────────────
+                    if !rows.read().is_empty() {
+                        section { class: "space-y-1",
+                            h3 { class: "text-[10px] font-mono tracking-widest text-gray-500 uppercase", "{i18n.t(\"Proposed blocks\")}" }
+                            p { class: "text-xs text-gray-500 pb-1", "{i18n.t(\"Untick a block to leave it out, or change its start.\")}" }
+                            for (i, row) in rows.read().iter().cloned().enumerate() {
+                                {
+                                    let title = title_of(row.task_id);
+                                    let start_value = row.start.format("%Y-%m-%dT%H:%M").to_string();
+                                    let when = format!("{} {}, {}", i18n.weekday_short(row.start.weekday()), i18n.day_month(row.start.date()), i18n.time_range(&row.start, &row.end));
+                                    rsx! {
+                                        div { key: "{row.task_id}", class: "flex items-center gap-3 px-2 py-1.5 rounded hover:bg-space-700/40",
+                                            input {
+                                                r#type: "checkbox",
+                                                checked: row.keep,
+                                                aria_label: "{title}",
+                                                onchange: move |evt| {
+                                                    rows.write()[i].keep = evt.checked();
+                                                },
+                                            }
+                                            div { class: if row.keep { "flex-1 min-w-0" } else { "flex-1 min-w-0 opacity-40 line-through" },
+                                                div { class: "text-sm text-white truncate", "{title}" }
+                                                div { class: "text-xs font-mono text-gray-400", "{when}" }
+                                            }
+                                            input {
+                                                r#type: "datetime-local",
+                                                aria_label: "{i18n.t(\"Start\")} — {title}",
+                                                class: "bg-space-900 border border-space-700 rounded px-2 py-1 text-xs text-gray-200 font-mono focus:border-neon-cyan focus:outline-none",
+                                                value: "{start_value}",
+                                                onchange: move |evt| {
+                                                    if let Ok(start) = NaiveDateTime::parse_from_str(&evt.value(), "%Y-%m-%dT%H:%M") {
+                                                        let mut rows = rows.write();
+                                                        let length = rows[i].end - rows[i].start;
+                                                        rows[i].start = start;
+                                                        rows[i].end = start + length;
+                                                    }
+                                                },
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+
+                    // ── Tasks left out ──────────────────────────────────
+                    if !skipped.is_empty() {
+                        section { class: "space-y-1",
+                            h3 { class: "text-[10px] font-mono tracking-widest text-gray-500 uppercase", "{i18n.t(\"Not placed\")}" }
+                            for (id, title, reason) in skipped {
+                                div { key: "{id}", class: "flex items-baseline gap-3 px-2 py-1",
+                                    span { class: "flex-1 min-w-0 text-sm text-gray-300 truncate", "{title}" }
+                                    span { class: "text-xs text-neon-amber", "{reason}" }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                div { class: "flex gap-2 justify-end",
+                    button {
+                        class: "px-3 py-1 rounded border border-space-700 text-gray-400 hover:text-white text-xs font-bold uppercase tracking-wide",
+                        onclick: move |_| onclose.call(()),
+                        "{i18n.t(\"Cancel\")}"
+                    }
+                    button {
+                        class: "btn-primary text-xs disabled:opacity-40",
+                        disabled: kept == 0,
+                        onmounted: move |evt| async move { let _ = evt.data().set_focus(true).await; },
+                        onclick: accept,
+                        "{i18n.t(\"Accept\")} ({kept})"
+                    }
+                }
+            }
+        }
+    }
+}
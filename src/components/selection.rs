@@ -0,0 +1,264 @@
+use dioxus::prelude::*;
+use dioxus::html::geometry::{ClientPoint, PixelsRect};
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::rc::Rc;
+use uuid::Uuid;
+
+/// SELECTION: The set of task cards the user has picked for a bulk action.
+/// Shared through context so the Matrix, the Inbox and the bulk action bar see the same picks.
+#[derive(Clone, Copy, PartialEq)]
+pub struct Selection {
+    pub ids: Signal<HashSet<Uuid>>,                   // The selected task ids
+    anchor: Signal<Option<Uuid>>,                     // Last plain/ctrl-clicked card, start of shift ranges
+    cards: Signal<HashMap<Uuid, Rc<MountedData>>>,    // On-screen cards, measured by the lasso
+    revealed: Signal<Option<Uuid>>,                   // Task a search result jumped to, highlighted until the next click
+}
+
+impl Selection {
+    /// INITIALIZATION: Starts with nothing selected
+    pub fn new() -> Self {
+        Self {
+            ids: Signal::new(HashSet::new()),
+            anchor: Signal::new(None),
+            cards: Signal::new(HashMap::new()),
+            revealed: Signal::new(None),
+        }
+    }
+
+    /// Is this task part of the selection?
+    pub fn contains(&self, id: Uuid) -> bool {
+        self.ids.read().contains(&id)
+    }
+
+    /// A copy of the selected ids, handy for passing to the store
+    pub fn snapshot(&self) -> HashSet<Uuid> {
+        self.ids.read().clone()
+    }
+
+    /// Forgets every selected card
+    pub fn clear(&self) {
+        let mut ids = self.ids;
+        ids.write().clear();
+        let mut anchor = self.anchor;
+        anchor.set(None);
+    }
+
+    /// Replaces the selection (used by the lasso)
+    pub fn replace(&self, new_ids: HashSet<Uuid>) {
+        let mut ids = self.ids;
+        ids.set(new_ids);
+    }
+
+    /// CLICK HANDLING: Plain click selects one card, Ctrl/Cmd toggles it,
+    /// Shift selects every card between the anchor and this one in `order`.
+    pub fn click(&self, id: Uuid, modifiers: Modifiers, order: &[Uuid]) {
+        let mut ids = self.ids;
+        let mut anchor = self.anchor;
+        let mut revealed = self.revealed;
+        revealed.set(None);
+
+        if modifiers.shift() {
+            let from = anchor().and_then(|a| order.iter().position(|x| *x == a));
+            let to = order.iter().position(|x| *x == id);
+            if let (Some(from), Some(to)) = (from, to) {
+                let (lo, hi) = if from <= to { (from, to) } else { (to, from) };
+                ids.write().extend(order[lo..=hi].iter().copied());
+                return;
+            }
+            ids.write().insert(id);
+        } else if modifiers.ctrl() || modifiers.meta() {
+            let mut picked = ids.write();
+            if !picked.remove(&id) {
+                picked.insert(id);
+            }
+        } else {
+            let mut picked = ids.write();
+            picked.clear();
+            picked.insert(id);
+        }
+        anchor.set(Some(id));
+    }
+
+    /// PRUNE: Drops selected ids and measured cards whose task is gone (deleted or archived)
+    pub fn retain(&self, live: &HashSet<Uuid>) {
+        if self.ids.peek().iter().any(|id| !live.contains(id)) {
+            let mut ids = self.ids;
+            ids.write().retain(|id| live.contains(id));
+        }
+        if self.cards.peek().keys().any(|id| !live.contains(id)) {
+            let mut cards = self.cards;
+            cards.write().retain(|id, _| live.contains(id));
+        }
+    }
+
+    /// Remembers a card's element so the lasso can measure it
+    pub fn register(&self, id: Uuid, el: Rc<MountedData>) {
+        self.scroll_if_revealed(id, &el);
+        let mut cards = self.cards;
+        cards.write().insert(id, el);
+    }
+
+    /// Forgets a card's element once it unmounts. A newer element registered for the same task
+    /// (the card re-rendered elsewhere) is kept.
+    pub fn unregister(&self, id: Uuid, el: &Rc<MountedData>) {
+        if self.cards.peek().get(&id).is_some_and(|current| Rc::ptr_eq(current, el)) {
+            let mut cards = self.cards;
+            cards.write().remove(&id);
+        }
+    }
+
+    /// REVEAL: Marks the task a search result points at; its card scrolls into view once mounted
+    pub fn reveal(&self, id: Uuid) {
+        let mut revealed = self.revealed;
+        revealed.set(Some(id));
+        if let Some(el) = self.cards.peek().get(&id).cloned() {
+            self.scroll_if_revealed(id, &el);
+        }
+    }
+
+    /// The task last jumped to from search, if it is still highlighted
+    pub fn revealed(&self) -> Option<Uuid> {
+        (self.revealed)()
+    }
+
+    /// Highlight ring for the task last jumped to from search (empty for every other card)
+    pub fn reveal_class(&self, id: Uuid) -> &'static str {
+        if self.revealed() == Some(id) { "ring-2 ring-neon-cyan shadow-[0_0_18px_rgba(0,243,255,0.35)]" } else { "" }
+    }
+
+    /// Scrolls a freshly mounted element into view when it belongs to the revealed task
+    pub fn scroll_if_revealed(&self, id: Uuid, el: &Rc<MountedData>) {
+        if *self.revealed.peek() == Some(id) {
+            let el = el.clone();
+            spawn(async move {
+                let _ = el.scroll_to(ScrollBehavior::Smooth).await;
+            });
+        }
+    }
+}
+
+/// CARD REGISTRATION: Ties a card's element to the lasso for as long as the card is mounted,
+/// so the lasso never measures elements of cards that left the screen (or another page).
+#[derive(Clone)]
+pub struct CardRegistration {
+    id: Uuid,
+    selection: Selection,
+    element: Rc<RefCell<Option<Rc<MountedData>>>>,
+}
+
+impl CardRegistration {
+    /// Called from the card's onmounted
+    pub fn register(&self, el: Rc<MountedData>) {
+        self.selection.register(self.id, el.clone());
+        self.element.replace(Some(el));
+    }
+}
+
+/// Hook for a selectable card: registers it on mount and unregisters it when dropped
+pub fn use_card_registration(id: Uuid) -> CardRegistration {
+    let selection = use_context::<Selection>();
+    let registration = use_hook(|| CardRegistration { id, selection, element: Rc::new(RefCell::new(None)) });
+    use_drop({
+        let registration = registration.clone();
+        move || {
+            if let Some(el) = registration.element.take() {
+                registration.selection.unregister(registration.id, &el);
+            }
+        }
+    });
+    registration
+}
+
+/// LASSO AREA: Wraps a list of cards; dragging across empty space draws a rectangle
+/// and selects every registered card it touches. Hold Ctrl/Cmd/Shift to add to the selection.
+#[component]
+pub fn LassoArea(class: String, children: Element) -> Element {
+    let selection = use_context::<Selection>();
+
+    // Lasso corners in window coordinates, plus the card positions measured when it started
+    let mut origin = use_signal(|| None::<ClientPoint>);
+    let mut cursor = use_signal(|| None::<ClientPoint>);
+    let mut card_rects = use_signal(Vec::<(Uuid, PixelsRect)>::new);
+    let mut base = use_signal(HashSet::<Uuid>::new);
+
+    let lasso_box = match (origin(), cursor()) {
+        (Some(a), Some(b)) if (a.x - b.x).abs() + (a.y - b.y).abs() > 4.0 => Some((
+            a.x.min(b.x),
+            a.y.min(b.y),
+            (a.x - b.x).abs(),
+            (a.y - b.y).abs(),
+        )),
+        _ => None,
+    };
+
+    rsx! {
+        div {
+            class: "{class}",
+            onmousedown: move |evt| {
+                if evt.trigger_button() != Some(dioxus::html::input_data::MouseButton::Primary) {
+                    return;
+                }
+                let mods = evt.modifiers();
+                let additive = mods.ctrl() || mods.meta() || mods.shift();
+                base.set(if additive { selection.snapshot() } else { HashSet::new() });
+                if !additive {
+                    selection.clear();
+                }
+                origin.set(Some(evt.client_coordinates()));
+                cursor.set(Some(evt.client_coordinates()));
+
+                // Measure every mounted card once, when the lasso starts
+                let cards: Vec<(Uuid, Rc<MountedData>)> = selection.cards.read()
+                    .iter()
+                    .map(|(id, el)| (*id, el.clone()))
+                    .collect();
+                spawn(async move {
+                    let mut rects = Vec::new();
+                    for (id, el) in cards {
+                        if let Ok(rect) = el.get_client_rect().await {
+                            rects.push((id, rect));
+                        }
+                    }
+                    card_rects.set(rects);
+                });
+            },
+            onmousemove: move |evt| {
+                let Some(a) = origin() else { return };
+                let b = evt.client_coordinates();
+                cursor.set(Some(b));
+                let (left, right) = (a.x.min(b.x), a.x.max(b.x));
+                let (top, bottom) = (a.y.min(b.y), a.y.max(b.y));
+                let mut picked = base();
+                for (id, rect) in card_rects.read().iter() {
+                    let hit = rect.origin.x < right
+                        && rect.origin.x + rect.size.width > left
+                        && rect.origin.y < bottom
+                        && rect.origin.y + rect.size.height > top;
+                    if hit {
+                        picked.insert(*id);
+                    }
+                }
+                selection.replace(picked);
+            },
+            onmouseup: move |_| {
+                origin.set(None);
+                cursor.set(None);
+            },
+            onmouseleave: move |_| {
+                origin.set(None);
+                cursor.set(None);
+            },
+
+            {children}
+
+            // The rubber band itself
+            if let Some((x, y, w, h)) = lasso_box {
+                div {
+                    class: "fixed z-50 border border-neon-cyan bg-neon-cyan/10 pointer-events-none rounded-sm",
+                    style: "left: {x}px; top: {y}px; width: {w}px; height: {h}px;",
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,197 @@
+use dioxus::prelude::*;
+use crate::components::Selection;
+use crate::keymap::{display_combo, Action};
+use crate::models::Quadrant;
+use crate::routes::Route;
+use crate::settings::SettingsState;
+use crate::store::AppState;
+
+/// Forwards bound key combos to Rust from anywhere in the window.
+/// Plain keys are ignored while typing in a field; so are the usual text-editing combos.
+const LISTENER_JS: &str = r#"
+window.__errdayBindings = window.__errdayBindings || [];
+document.addEventListener('keydown', (e) => {
+    const parts = [];
+    if (e.ctrlKey || e.metaKey) parts.push('mod');
+    if (e.altKey) parts.push('alt');
+    let key = e.key.toLowerCase();
+    // Alt changes the character on some layouts (Option+1 = ¡ on macOS), so use the physical key
+    if (e.altKey && /^(Digit|Key)/.test(e.code)) key = e.code.replace(/^(Digit|Key)/, '').toLowerCase();
+    if (e.shiftKey && key.length > 1) parts.push('shift');
+    if (key === ' ') key = 'space';
+    parts.push(key);
+    const combo = parts.join('+');
+
+    const t = e.target;
+    const typing = t && (t.isContentEditable || ['INPUT', 'TEXTAREA', 'SELECT'].includes(t.tagName));
+    if (typing && (!(e.ctrlKey || e.metaKey || e.altKey) || ['mod+z', 'mod+a', 'mod+c', 'mod+v', 'mod+x', 'mod+y', 'mod+backspace'].includes(combo))) return;
+
+    if (window.__errdayBindings.includes(combo)) {
+        e.preventDefault();
+        dioxus.send(combo);
+    }
+});
+"#;
+
+/// SHORTCUTS: Overlay toggles and view-specific commands driven by the keyboard layer.
+/// Provided next to AppState so any view can react to its own shortcuts.
+#[derive(Clone, Copy, PartialEq)]
+pub struct Shortcuts {
+    pub palette_open: Signal<bool>,               // Ctrl/Cmd+K search
+    pub cheat_sheet_open: Signal<bool>,           // "?" overlay listing every binding
+    pub calendar_command: Signal<Option<Action>>, // Week navigation waiting for the calendar to apply it
+}
+
+impl Shortcuts {
+    /// INITIALIZATION: Everything closed, nothing pending
+    pub fn new() -> Self {
+        Self {
+            palette_open: Signal::new(false),
+            cheat_sheet_open: Signal::new(false),
+            calendar_command: Signal::new(None),
+        }
+    }
+
+    /// Hands the pending calendar command to the calendar (and forgets it)
+    pub fn take_calendar_command(&self) -> Option<Action> {
+        let mut command = self.calendar_command;
+        let action = command();
+        if action.is_some() {
+            command.set(None);
+        }
+        action
+    }
+}
+
+/// SHORTCUT LAYER: Listens for the keymap's combos and runs the matching action.
+/// Renders the cheat sheet when it is open.
+#[component]
+pub fn ShortcutLayer() -> Element {
+    let app_state = use_context::<AppState>();
+    let settings_state = use_context::<SettingsState>();
+    let selection = use_context::<Selection>();
+    let mut shortcuts = use_context::<Shortcuts>();
+    let navigator = use_navigator();
+    let router = router();
+
+    // Stage 1: Tell the listener which combos are bound (again whenever the keymap changes)
+    use_effect(move || {
+        let combos = settings_state.settings.read().keymap.all_combos();
+        let json = serde_json::to_string(&combos).unwrap_or_else(|_| "[]".to_string());
+        document::eval(&format!("window.__errdayBindings = {json};"));
+    });
+
+    // Stage 2: Runs one action
+    let mut run = move |action: Action| {
+        let move_selection = |quadrant: Quadrant| {
+            let ids = selection.snapshot();
+            if !ids.is_empty() {
+                app_state.move_to_quadrant(&ids, quadrant, &settings_state.settings.peek().wip_limits);
+            }
+        };
+        match action {
+            Action::GoInbox => {
+                navigator.push(Route::Inbox {});
+            }
+            Action::GoMatrix => {
+                navigator.push(Route::Matrix {});
+            }
+            Action::GoCalendar => {
+                navigator.push(Route::Calendar {});
+            }
+            Action::NewTask => {
+                navigator.push(Route::Inbox {});
+                // Focus the capture box once the Brainstorm page is on screen
+                document::eval("setTimeout(() => document.getElementById('capture-input')?.focus(), 50);");
+            }
+            Action::DeleteSelected => {
+                let ids = selection.snapshot();
+                if !ids.is_empty() {
+                    app_state.delete_tasks(&ids);
+                    selection.clear();
+                }
+            }
+            Action::Undo => app_state.undo(),
+            Action::MoveToDoFirst => move_selection(Quadrant::DoFirst),
+            Action::MoveToSchedule => move_selection(Quadrant::Schedule),
+            Action::MoveToDelegate => move_selection(Quadrant::Delegate),
+            Action::MoveToDelete => move_selection(Quadrant::Delete),
+            Action::MoveToInbox => move_selection(Quadrant::Unsorted),
+            Action::PreviousWeek | Action::NextWeek | Action::ThisWeek => {
+                // Only the calendar knows which week (day, month) is showing
+                if matches!(router.current::<Route>(), Route::Calendar {} | Route::CalendarDay { .. } | Route::CalendarMonth {}) {
+                    shortcuts.calendar_command.set(Some(action));
+                }
+            }
+            Action::CommandPalette => shortcuts.palette_open.toggle(),
+            Action::CheatSheet => shortcuts.cheat_sheet_open.toggle(),
+        }
+    };
+
+    // Stage 3: Wire the global key listener
+    use_future(move || async move {
+        let mut listener = document::eval(LISTENER_JS);
+        while let Ok(combo) = listener.recv::<String>().await {
+            let action = settings_state.settings.peek().keymap.action_for(&combo);
+            if let Some(action) = action {
+                run(action);
+            }
+        }
+    });
+
+    if !(shortcuts.cheat_sheet_open)() {
+        return rsx! {};
+    }
+
+    rsx! {
+        CheatSheet {}
+    }
+}
+
+/// CHEAT SHEET: Every action and its current bindings, grouped by area
+#[component]
+fn CheatSheet() -> Element {
+    let settings_state = use_context::<SettingsState>();
+    let mut shortcuts = use_context::<Shortcuts>();
+    let keymap = settings_state.settings.read().keymap.clone();
+    let i18n = settings_state.i18n();
+
+    let groups: Vec<(&'static str, Vec<Action>)> = ["Navigation", "Tasks", "Matrix", "Calendar"]
+        .into_iter()
+        .map(|group| (group, Action::ALL.into_iter().filter(|a| a.group() == group).collect()))
+        .collect();
+
+    rsx! {
+        // BACKDROP: Clicking outside closes the cheat sheet
+        div { class: "fixed inset-0 z-50 bg-black/60 backdrop-blur-sm flex items-center justify-center p-8",
+            onclick: move |_| shortcuts.cheat_sheet_open.set(false),
+            div { class: "glass-panel rounded-2xl p-8 w-full max-w-3xl space-y-6",
+                onclick: move |evt| evt.stop_propagation(),
+                div { class: "border-b border-space-700 pb-4 flex justify-between items-end",
+                    div {
+                        p { class: "hud-text mb-1", "{i18n.t(\"KEYBOARD\")}" }
+                        h3 { class: "text-2xl font-bold text-white tracking-tight", "{i18n.t(\"Shortcuts\")}" }
+                    }
+                    p { class: "text-[10px] font-mono text-gray-500", {i18n.t("Remap in settings.json → \"keymap\"")} }
+                }
+                div { class: "grid grid-cols-2 gap-x-10 gap-y-6",
+                    for (group, rows) in groups {
+                        div { class: "space-y-2",
+                            p { class: "text-[10px] font-mono tracking-widest text-gray-500 uppercase", "{i18n.t(group)}" }
+                            for action in rows {
+                                div { class: "flex justify-between items-center gap-4 text-sm",
+                                    span { class: "text-gray-300", "{i18n.t(action.label())}" }
+                                    div { class: "flex gap-1",
+                                        for combo in keymap.bindings(action) {
+                                            kbd { class: "px-2 py-0.5 rounded border border-space-700 bg-space-900 text-[11px] font-mono text-neon-cyan whitespace-nowrap", "{display_combo(&combo)}" }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -1,11 +1,19 @@
 use dioxus::prelude::*;
+use crate::components::Shortcuts;
+use crate::filters::SavedFilter;
 use crate::routes::Route;
+use crate::settings::SettingsState;
 
 /// SIDEBAR COMPONENT: The main navigation menu for the application
 #[component]
 pub fn Sidebar() -> Element {
     // Keep track of whether the sidebar is slim (collapsed) or full-width
     let mut is_collapsed = use_signal(|| false);
+    let settings_state = use_context::<SettingsState>();
+    let navigator = use_navigator();
+    let mut shortcuts = use_context::<Shortcuts>();
+    let saved_filters = settings_state.settings.read().saved_filters.clone();
+    let i18n = settings_state.i18n();
 
     // Calculate styling based on whether we are collapsed or expanded
     let nav_width = if is_collapsed() { "w-20" } else { "w-52" };
@@ -34,7 +42,7 @@ pub fn Sidebar() -> Element {
             // APP BRANDING: Shows "Errday Mission Control" or a small logo when collapsed
             div { class: "px-6 mb-8 whitespace-nowrap overflow-hidden transition-opacity duration-200 {text_visibility}",
                 h1 { class: "text-2xl font-bold font-sans tracking-tight text-white mb-1", "Errday" }
-                p { class: "text-xs text-neon-cyan font-mono tracking-widest uppercase", "Mission Control" }
+                p { class: "text-xs text-neon-cyan font-mono tracking-widest uppercase", "{i18n.t(\"Mission Control\")}" }
             }
              if is_collapsed() {
                 div { class: "mb-8 flex justify-center",
@@ -48,45 +56,94 @@ pub fn Sidebar() -> Element {
                 Link { to: Route::Inbox {}, class: "block p-3 rounded hover:bg-space-800 text-gray-400 hover:text-white transition-colors flex {align_items} gap-3 group relative",
                     active_class: "bg-space-800 text-neon-cyan border-l-2 border-neon-cyan",
                     span { class: "w-1.5 h-1.5 rounded-full bg-current opacity-50 group-hover:opacity-100 transition-opacity" }
-                    span { class: "{text_visibility} whitespace-nowrap", "Brainstorm" }
+                    span { class: "{text_visibility} whitespace-nowrap", "{i18n.t(\"Brainstorm\")}" }
                     if is_collapsed() {
-                        div { class: "absolute left-full top-1/2 -translate-y-1/2 ml-2 bg-space-800 text-xs px-2 py-1 rounded opacity-0 group-hover:opacity-100 transition-opacity whitespace-nowrap pointer-events-none border border-space-700 z-50", "Brainstorm" }
+                        div { class: "absolute left-full top-1/2 -translate-y-1/2 ml-2 bg-space-800 text-xs px-2 py-1 rounded opacity-0 group-hover:opacity-100 transition-opacity whitespace-nowrap pointer-events-none border border-space-700 z-50", "{i18n.t(\"Brainstorm\")}" }
                     }
                 }
                 // 2. Eisenhower Matrix
                 Link { to: Route::Matrix {}, class: "block p-3 rounded hover:bg-space-800 text-gray-400 hover:text-white transition-colors flex {align_items} gap-3 group relative",
                     active_class: "bg-space-800 text-neon-purple border-l-2 border-neon-purple",
                      span { class: "w-1.5 h-1.5 rounded-full bg-current opacity-50 group-hover:opacity-100 transition-opacity" }
-                     span { class: "{text_visibility} whitespace-nowrap", "Matrix Protocol" }
+                     span { class: "{text_visibility} whitespace-nowrap", "{i18n.t(\"Matrix Protocol\")}" }
                      if is_collapsed() {
-                        div { class: "absolute left-full top-1/2 -translate-y-1/2 ml-2 bg-space-800 text-xs px-2 py-1 rounded opacity-0 group-hover:opacity-100 transition-opacity whitespace-nowrap pointer-events-none border border-space-700 z-50", "Matrix Protocol" }
+                        div { class: "absolute left-full top-1/2 -translate-y-1/2 ml-2 bg-space-800 text-xs px-2 py-1 rounded opacity-0 group-hover:opacity-100 transition-opacity whitespace-nowrap pointer-events-none border border-space-700 z-50", "{i18n.t(\"Matrix Protocol\")}" }
                     }
                 }
                 // 3. Calendar
                 Link { to: Route::Calendar {}, class: "block p-3 rounded hover:bg-space-800 text-gray-400 hover:text-white transition-colors flex {align_items} gap-3 group relative",
                     active_class: "bg-space-800 text-neon-green border-l-2 border-neon-green",
                      span { class: "w-1.5 h-1.5 rounded-full bg-current opacity-50 group-hover:opacity-100 transition-opacity" }
-                     span { class: "{text_visibility} whitespace-nowrap", "Calendar" }
+                     span { class: "{text_visibility} whitespace-nowrap", "{i18n.t(\"Calendar\")}" }
                      if is_collapsed() {
-                        div { class: "absolute left-full top-1/2 -translate-y-1/2 ml-2 bg-space-800 text-xs px-2 py-1 rounded opacity-0 group-hover:opacity-100 transition-opacity whitespace-nowrap pointer-events-none border border-space-700 z-50", "Calendar" }
+                        div { class: "absolute left-full top-1/2 -translate-y-1/2 ml-2 bg-space-800 text-xs px-2 py-1 rounded opacity-0 group-hover:opacity-100 transition-opacity whitespace-nowrap pointer-events-none border border-space-700 z-50", "{i18n.t(\"Calendar\")}" }
                     }
                 }
             }
             
+            // SAVED FILTERS: User-defined perspectives, listed under the primary nav
+            div { class: "{px} mb-6 space-y-1",
+                if !is_collapsed() {
+                    p { class: "px-3 mb-2 text-[10px] font-mono tracking-widest text-gray-600 uppercase", "{i18n.t(\"Perspectives\")}" }
+                }
+                for filter in saved_filters {
+                    Link { to: Route::Perspective { id: filter.id }, class: "block px-3 py-2 rounded hover:bg-space-800 text-gray-400 hover:text-white transition-colors text-sm flex {align_items} gap-3 group relative",
+                        active_class: "bg-space-800 text-white",
+                        if is_collapsed() {
+                            span { class: "text-xs font-bold", "{filter.name.chars().next().unwrap_or('#')}" }
+                            div { class: "absolute left-full top-1/2 -translate-y-1/2 ml-2 bg-space-800 text-xs px-2 py-1 rounded opacity-0 group-hover:opacity-100 transition-opacity whitespace-nowrap pointer-events-none border border-space-700 z-50", "{filter.name}" }
+                        } else {
+                            span { class: "truncate", "{filter.name}" }
+                        }
+                    }
+                }
+                button {
+                    class: "w-full px-3 py-2 rounded text-left text-xs text-gray-600 hover:text-neon-cyan hover:bg-space-800 transition-colors flex {align_items}",
+                    onclick: move |_| {
+                        let filter = SavedFilter::new(i18n.t("New filter").to_string());
+                        let id = filter.id;
+                        settings_state.save_filter(filter);
+                        navigator.push(Route::Perspective { id });
+                    },
+                    if is_collapsed() { "+" } else { "{i18n.t(\"+ New filter\")}" }
+                }
+            }
+
             // SYSTEM NAVIGATION: Secondary links for help and info
             div { class: "{px} mt-auto space-y-2 border-t border-space-800 pt-4",
+                Link { to: Route::Archive {}, class: "block p-3 rounded hover:bg-space-800 text-gray-500 hover:text-white transition-colors text-sm flex {align_items} gap-3 group relative",
+                    active_class: "text-white",
+                    if is_collapsed() {  span { class: "text-xs", "▤" } } else { "{i18n.t(\"Archive\")}" }
+                     if is_collapsed() {
+                        div { class: "absolute left-full top-1/2 -translate-y-1/2 ml-2 bg-space-800 text-xs px-2 py-1 rounded opacity-0 group-hover:opacity-100 transition-opacity whitespace-nowrap pointer-events-none border border-space-700 z-50", "{i18n.t(\"Archive\")}" }
+                    }
+                }
+                button { class: "w-full block p-3 rounded hover:bg-space-800 text-gray-500 hover:text-white transition-colors text-sm flex {align_items} gap-3 group relative",
+                    onclick: move |_| shortcuts.cheat_sheet_open.set(true),
+                    if is_collapsed() {  span { class: "text-xs", "⌨" } } else { "{i18n.t(\"Shortcuts\")}" }
+                     if is_collapsed() {
+                        div { class: "absolute left-full top-1/2 -translate-y-1/2 ml-2 bg-space-800 text-xs px-2 py-1 rounded opacity-0 group-hover:opacity-100 transition-opacity whitespace-nowrap pointer-events-none border border-space-700 z-50", "{i18n.t(\"Shortcuts (?)\")}" }
+                    }
+                }
+                Link { to: Route::Settings {}, class: "block p-3 rounded hover:bg-space-800 text-gray-500 hover:text-white transition-colors text-sm flex {align_items} gap-3 group relative",
+                    active_class: "text-white",
+                    if is_collapsed() {  span { class: "text-xs", "⚙" } } else { "{i18n.t(\"Settings\")}" }
+                     if is_collapsed() {
+                        div { class: "absolute left-full top-1/2 -translate-y-1/2 ml-2 bg-space-800 text-xs px-2 py-1 rounded opacity-0 group-hover:opacity-100 transition-opacity whitespace-nowrap pointer-events-none border border-space-700 z-50", "{i18n.t(\"Settings\")}" }
+                    }
+                }
                 Link { to: Route::Tutorial {}, class: "block p-3 rounded hover:bg-space-800 text-gray-500 hover:text-white transition-colors text-sm flex {align_items} gap-3 group relative",
                     active_class: "text-white",
-                    if is_collapsed() {  span { class: "text-xs", "?" } } else { "Tutorial" }
+                    if is_collapsed() {  span { class: "text-xs", "?" } } else { "{i18n.t(\"Tutorial\")}" }
                      if is_collapsed() {
-                        div { class: "absolute left-full top-1/2 -translate-y-1/2 ml-2 bg-space-800 text-xs px-2 py-1 rounded opacity-0 group-hover:opacity-100 transition-opacity whitespace-nowrap pointer-events-none border border-space-700 z-50", "Flight Manual" }
+                        div { class: "absolute left-full top-1/2 -translate-y-1/2 ml-2 bg-space-800 text-xs px-2 py-1 rounded opacity-0 group-hover:opacity-100 transition-opacity whitespace-nowrap pointer-events-none border border-space-700 z-50", "{i18n.t(\"Flight Manual\")}" }
                     }
                 }
                 Link { to: Route::Credits {}, class: "block p-3 rounded hover:bg-space-800 text-gray-500 hover:text-white transition-colors text-sm flex {align_items} gap-3 group relative",
                     active_class: "text-white",
-                    if is_collapsed() {  span { class: "text-xs", "i" } } else { "Credits" }
+                    if is_collapsed() {  span { class: "text-xs", "i" } } else { "{i18n.t(\"Credits\")}" }
                      if is_collapsed() {
-                        div { class: "absolute left-full top-1/2 -translate-y-1/2 ml-2 bg-space-800 text-xs px-2 py-1 rounded opacity-0 group-hover:opacity-100 transition-opacity whitespace-nowrap pointer-events-none border border-space-700 z-50", "Credits" }
+                        div { class: "absolute left-full top-1/2 -translate-y-1/2 ml-2 bg-space-800 text-xs px-2 py-1 rounded opacity-0 group-hover:opacity-100 transition-opacity whitespace-nowrap pointer-events-none border border-space-700 z-50", "{i18n.t(\"Credits\")}" }
                     }
                 }
             }
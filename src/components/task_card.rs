@@ -0,0 +1,108 @@
+use dioxus::prelude::*;
+use chrono::Local;
+use crate::components::{heat_class, opens_task_menu, use_card_registration, AgeBadge, Selection, TaskMenu};
+use crate::models::Task;
+use crate::settings::SettingsState;
+use crate::store::AppState;
+
+/// TASK CARD: One draggable, selectable task as shown in the matrix quadrants and saved filters.
+/// `order` is the on-screen order of the surrounding list (for shift-click ranges);
+/// `dragged_id` is set when the card is picked up. Cards are focusable: Enter/Space selects,
+/// the Menu key, Shift+F10 or a right-click opens the move / schedule menu.
+#[component]
+pub fn TaskCard(task: Task, order: Vec<uuid::Uuid>, dragged_id: Signal<Option<uuid::Uuid>>) -> Element {
+    let app_state = use_context::<AppState>();
+    let i18n = use_context::<SettingsState>().i18n();
+    let selection = use_context::<Selection>();
+    let overdue = task.due.is_some_and(|d| d < Local::now().date_naive());
+    let mut menu_open = use_signal(|| false);
+    let mounted = use_card_registration(task.id);
+
+    rsx! {
+        div {
+            id: "task-{task.id}",
+            class: if selection.contains(task.id) { "bg-space-800 border border-current ring-2 ring-current/40 p-4 rounded-lg group cursor-move transition-all relative focus:outline-none focus-visible:ring-2 focus-visible:ring-neon-cyan {heat_class(task.idle_days())}" } else { "bg-space-800 border border-space-700 p-4 rounded-lg group cursor-move hover:border-current transition-all relative hover:translate-x-1 focus:outline-none focus-visible:ring-2 focus-visible:ring-neon-cyan {heat_class(task.idle_days())} {selection.reveal_class(task.id)}" },
+            role: "listitem",
+            tabindex: 0,
+            aria_label: "{task.title}",
+            aria_haspopup: "menu",
+            aria_current: selection.contains(task.id),
+            draggable: true,
+            // Let the lasso measure this card (and forget it once the card is gone)
+            onmounted: move |evt| mounted.register(evt.data()),
+            // Clicks on a card select it instead of starting a lasso
+            onmousedown: move |evt| evt.stop_propagation(),
+            onclick: {
+                let order = order.clone();
+                move |evt: MouseEvent| selection.click(task.id, evt.modifiers(), &order)
+            },
+            onkeydown: move |evt| {
+                if opens_task_menu(&evt) {
+                    evt.prevent_default();
+                    menu_open.set(true);
+                } else if evt.key() == Key::Enter || evt.key() == Key::Character(" ".to_string()) {
+                    evt.prevent_default();
+                    selection.click(task.id, evt.modifiers(), &order);
+                }
+            },
+            oncontextmenu: move |evt| {
+                evt.prevent_default();
+                menu_open.set(true);
+            },
+            // Update the global 'dragging' signal when the user picks up this task
+            ondragstart: move |_| dragged_id.set(Some(task.id)),
+
+            div { class: "flex justify-between items-start gap-3",
+                div { class: "min-w-0",
+                    span { class: "text-sm font-medium text-current leading-relaxed", "{task.title}" }
+                    // Questionnaire scores on a 0–10 scale, project and deadline
+                    div { class: "flex flex-wrap gap-x-3 mt-1",
+                        AgeBadge { task: task.clone() }
+                        if let (Some(u), Some(i)) = (task.urgency, task.importance) {
+                            span { class: "text-[9px] font-mono tracking-widest text-gray-500", "U{u * 10.0:.0} · I{i * 10.0:.0}" }
+                        }
+                        if let Some(project) = task.project.clone() {
+                            span { class: "text-[9px] font-mono tracking-widest text-gray-400 uppercase", "▸ {project}" }
+                        }
+                        if let Some(due) = task.due {
+                            span { class: if overdue { "text-[9px] font-mono tracking-widest text-red-400" } else { "text-[9px] font-mono tracking-widest text-gray-500" },
+                                {i18n.tf("DUE {date}", &[("date", &i18n.day_month(due))])}
+                            }
+                        }
+                    }
+                    if !task.tags.is_empty() {
+                        div { class: "flex flex-wrap gap-1 mt-2",
+                            for tag in task.tags.iter() {
+                                span { class: "text-[9px] font-mono px-1.5 py-0.5 rounded bg-space-800 text-gray-400", "#{tag}" }
+                            }
+                        }
+                    }
+                }
+                div { class: "opacity-0 group-hover:opacity-100 group-focus-within:opacity-100 transition-opacity flex gap-1",
+                    button {
+                        class: "text-gray-500 hover:text-neon-green w-5 h-5 flex items-center justify-center rounded hover:bg-space-700 transition-colors",
+                        title: "Mark done",
+                        aria_label: "Mark done",
+                        onclick: move |evt| {
+                            evt.stop_propagation();
+                            app_state.toggle_task_status(task.id);
+                        },
+                        "✓"
+                    }
+                    button {
+                        class: "text-gray-500 hover:text-red-500 w-5 h-5 flex items-center justify-center rounded hover:bg-space-700 transition-colors",
+                        aria_label: "Delete task",
+                        onclick: move |evt| {
+                            evt.stop_propagation();
+                            app_state.delete_task(task.id);
+                        },
+                        "×"
+                    }
+                }
+            }
+        }
+        if menu_open() {
+            TaskMenu { task_id: task.id, onclose: move |_| menu_open.set(false) }
+        }
+    }
+}
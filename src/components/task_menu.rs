@@ -0,0 +1,237 @@
+use dioxus::prelude::*;
+use std::collections::HashSet;
+use chrono::{Local, NaiveDateTime, Timelike};
+use crate::models::{Quadrant, TaskStatus};
+use crate::settings::SettingsState;
+use crate::store::AppState;
+
+/// Moves focus between the open menu's items (delta = 1 for down, -1 for up)
+fn focus_item_js(delta: i32) -> String {
+    format!(
+        "const items = [...document.querySelectorAll('#task-menu [role^=menuitem]')];
+         const i = items.indexOf(document.activeElement);
+         items[(i + {delta} + items.length) % items.length]?.focus();"
+    )
+}
+
+/// MENU PAGE: The menu starts with the two commands; each opens its own list
+#[derive(Clone, Copy, PartialEq)]
+enum MenuPage {
+    Main,
+    Move,
+    Schedule,
+    Depends,
+}
+
+/// TASK MENU: The keyboard (and screen reader) alternative to dragging a task.
+/// Opened with right-click, the Menu key or Shift+F10 on any focusable task; offers
+/// "Move to quadrant…", "Schedule at…" and "Depends on…". Focus goes back to the task (id "task-{id}") on close.
+#[component]
+pub fn TaskMenu(task_id: uuid::Uuid, onclose: EventHandler<()>) -> Element {
+    let app_state = use_context::<AppState>();
+    let settings_state = use_context::<SettingsState>();
+    let i18n = settings_state.i18n();
+    let mut page = use_signal(|| MenuPage::Main);
+
+    let Some(task) = app_state.tasks.read().iter().find(|t| t.id == task_id).cloned() else {
+        return rsx! {};
+    };
+
+    // Default for the schedule field: the current slot, or the next full hour
+    let start_default = task
+        .scheduled_start
+        .map(|s| s.naive_local())
+        .unwrap_or_else(|| {
+            let next_hour = Local::now().naive_local() + chrono::Duration::hours(1);
+            next_hour.with_minute(0).and_then(|t| t.with_second(0)).unwrap_or(next_hour)
+        });
+    let mut schedule_val = use_signal(|| start_default.format("%Y-%m-%dT%H:%M").to_string());
+
+    let close = move || {
+        document::eval(&format!("document.getElementById('task-{task_id}')?.focus();"));
+        onclose.call(());
+    };
+
+    // Keeps the task's length when it already has a slot
+    let schedule = move || {
+        let parsed = NaiveDateTime::parse_from_str(&schedule_val.read(), "%Y-%m-%dT%H:%M")
+            .ok()
+            .and_then(|dt| dt.and_local_timezone(Local).single());
+        let Some(start) = parsed else { return };
+        let length = app_state.tasks.peek()
+            .iter()
+            .find(|t| t.id == task_id)
+            .and_then(|t| Some(t.scheduled_end? - t.scheduled_start?))
+            .unwrap_or_else(|| settings_state.settings.peek().calendar.default_block());
+        app_state.update_task_schedule(task_id, Some(start), Some(start + length));
+        close();
+    };
+
+    // Open tasks this one can wait on
+    let candidates: Vec<(uuid::Uuid, String)> = app_state.tasks.read()
+        .iter()
+        .filter(|t| t.id != task_id && t.status == TaskStatus::Todo)
+        .map(|t| (t.id, t.title.clone()))
+        .collect();
+
+    let item_class = "w-full text-left px-4 py-2 rounded text-sm text-gray-300 hover:text-white hover:bg-space-700/60 focus:bg-space-700/60 focus:text-white focus:outline-none";
+    let destinations = [
+        Quadrant::DoFirst,
+        Quadrant::Schedule,
+        Quadrant::Delegate,
+        Quadrant::Delete,
+        Quadrant::Unsorted,
+    ];
+
+    rsx! {
+        // BACKDROP: Clicking outside closes the menu
+        div { class: "fixed inset-0 z-50 bg-black/40 flex items-center justify-center p-8",
+            onmousedown: move |evt| evt.stop_propagation(),
+            onclick: move |evt| {
+                evt.stop_propagation();
+                close();
+            },
+            div {
+                id: "task-menu",
+                role: "menu",
+                aria_label: "{task.title}",
+                class: "glass-panel rounded-xl p-2 w-80 space-y-1",
+                onclick: move |evt| evt.stop_propagation(),
+                onkeydown: move |evt| {
+                    evt.stop_propagation();
+                    match evt.key() {
+                        Key::ArrowDown => {
+                            evt.prevent_default();
+                            document::eval(&focus_item_js(1));
+                        }
+                        Key::ArrowUp => {
+                            evt.prevent_default();
+                            document::eval(&focus_item_js(-1));
+                        }
+                        Key::Escape => {
+                            if page() == MenuPage::Main { close() } else { page.set(MenuPage::Main) }
+                        }
+                        _ => {}
+                    }
+                },
+                p { class: "px-4 pt-2 pb-1 text-[10px] font-mono tracking-widest text-gray-500 uppercase truncate", "{task.title}" }
+
+                match page() {
+                    MenuPage::Main => rsx! {
+                        button { key: "move", role: "menuitem", aria_haspopup: "menu", class: "{item_class}",
+                            onmounted: move |evt| async move { let _ = evt.data().set_focus(true).await; },
+                            onclick: move |_| page.set(MenuPage::Move),
+                            "{i18n.t(\"Move to quadrant…\")}"
+                        }
+                        button { key: "schedule", role: "menuitem", aria_haspopup: "dialog", class: "{item_class}",
+                            onclick: move |_| page.set(MenuPage::Schedule),
+                            "{i18n.t(\"Schedule at…\")}"
+                        }
+                        button { key: "depends", role: "menuitem", aria_haspopup: "menu", class: "{item_class}",
+                            onclick: move |_| page.set(MenuPage::Depends),
+                            "{i18n.t(\"Depends on…\")}"
+                            if !task.depends_on.is_empty() {
+                                span { class: "ml-2 text-gray-500", "({task.depends_on.len()})" }
+                            }
+                        }
+                    },
+                    MenuPage::Move => rsx! {
+                        for (i, quadrant) in destinations.into_iter().enumerate() {
+                            {
+                                let current = task.quadrant == quadrant;
+                                let label = if quadrant == Quadrant::Unsorted { i18n.t("Brainstorm") } else { i18n.t(quadrant.label()) };
+                                rsx! {
+                                    button {
+                                        key: "{i}",
+                                        role: "menuitemradio",
+                                        aria_checked: current,
+                                        class: "{item_class}",
+                                        onmounted: move |evt| async move {
+                                            if i == 0 {
+                                                let _ = evt.data().set_focus(true).await;
+                                            }
+                                        },
+                                        onclick: move |_| {
+                                            app_state.move_to_quadrant(&HashSet::from([task_id]), quadrant.clone(), &settings_state.settings.peek().wip_limits);
+                                            close();
+                                        },
+                                        "{label}"
+                                        if current { span { class: "ml-2 text-neon-cyan", aria_hidden: true, "●" } }
+                                    }
+                                }
+                            }
+                        }
+                    },
+                    MenuPage::Depends => rsx! {
+                        div { class: "max-h-72 overflow-y-auto space-y-1",
+                            if candidates.is_empty() {
+                                p { class: "px-4 py-2 text-sm text-gray-500", "{i18n.t(\"No other open tasks\")}" }
+                            }
+                            for (i, (other, title)) in candidates.into_iter().enumerate() {
+                                {
+                                    let checked = task.depends_on.contains(&other);
+                                    rsx! {
+                                        button {
+                                            key: "{other}",
+                                            role: "menuitemcheckbox",
+                                            aria_checked: checked,
+                                            class: "{item_class} truncate",
+                                            onmounted: move |evt| async move {
+                                                if i == 0 {
+                                                    let _ = evt.data().set_focus(true).await;
+                                                }
+                                            },
+                                            // Stays open so several dependencies can be ticked in a row
+                                            onclick: move |_| app_state.toggle_dependency(task_id, other),
+                                            span { class: "inline-block w-4 text-neon-cyan", aria_hidden: true, if checked { "✓" } }
+                                            "{title}"
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    },
+                    MenuPage::Schedule => rsx! {
+                        div { class: "px-4 py-2 space-y-3",
+                            label { class: "block text-xs text-gray-400", r#for: "task-menu-start", "{i18n.t(\"Start\")}" }
+                            input {
+                                id: "task-menu-start",
+                                r#type: "datetime-local",
+                                class: "w-full bg-space-900 border border-space-700 rounded px-2 py-1 text-sm text-white font-mono focus:border-neon-cyan focus:outline-none",
+                                value: "{schedule_val}",
+                                onmounted: move |evt| async move { let _ = evt.data().set_focus(true).await; },
+                                oninput: move |evt| schedule_val.set(evt.value()),
+                                onkeydown: move |evt| {
+                                    if evt.key() == Key::Enter {
+                                        schedule();
+                                    }
+                                },
+                            }
+                            div { class: "flex gap-2 justify-end",
+                                if task.scheduled_start.is_some() {
+                                    button { role: "menuitem",
+                                        class: "px-3 py-1 rounded border border-space-700 text-gray-400 hover:text-white text-xs font-bold uppercase tracking-wide",
+                                        onclick: move |_| {
+                                            app_state.update_task_schedule(task_id, None, None);
+                                            close();
+                                        },
+                                        "{i18n.t(\"Unschedule\")}"
+                                    }
+                                }
+                                button { role: "menuitem", class: "btn-primary text-xs",
+                                    onclick: move |_| schedule(),
+                                    "{i18n.t(\"Schedule\")}"
+                                }
+                            }
+                        }
+                    },
+                }
+            }
+        }
+    }
+}
+
+/// Whether a keydown on a task should open its menu (Menu key or Shift+F10)
+pub fn opens_task_menu(evt: &KeyboardEvent) -> bool {
+    evt.key() == Key::ContextMenu || (evt.key() == Key::F10 && evt.modifiers().shift())
+}
@@ -0,0 +1,207 @@
+// EXPORT: Writing tasks as iCalendar, shared by the .ics file export, the loopback feed and CalDAV sync.
+// The task id is the UID everywhere, so the same task is the same calendar item in every copy
+// and re-importing an export (or pulling it back from a server) finds the task it came from.
+
+use chrono::{DateTime, NaiveDate, Utc};
+use icalendar::{Alarm, Calendar, Event, EventLike, EventStatus, Property, Todo, TodoStatus, Trigger};
+use crate::i18n::I18n;
+use crate::models::{Quadrant, Task, TaskStatus};
+use crate::settings::ExportPrefs;
+
+/// Property the quadrant is written under, so it never mixes with the user's tags in CATEGORIES
+pub const QUADRANT_PROPERTY: &str = "X-ERRDAY-QUADRANT";
+
+/// SELECTION: Tasks the export writes: those in the chosen quadrants, dated inside `from`..=`until` (either end open).
+/// Events need a slot; to-dos also cover unscheduled tasks, which count as undated unless they have a due date.
+pub fn selection<'a>(tasks: &'a [Task], prefs: &ExportPrefs, from: Option<NaiveDate>, until: Option<NaiveDate>) -> Vec<&'a Task> {
+    tasks
+        .iter()
+        .filter(|t| prefs.quadrants.contains(&t.quadrant))
+        .filter(|t| prefs.as_todos || t.scheduled_start.is_some())
+        .filter(|t| {
+            let day = t.scheduled_start.map(|s| s.date_naive()).or(t.due);
+            match day {
+                Some(day) => from.is_none_or(|f| day >= f) && until.is_none_or(|u| day <= u),
+                None => from.is_none() && until.is_none(),
+            }
+        })
+        .collect()
+}
+
+/// BUILD ICS: One calendar holding `tasks` as events or to-dos (the .ics file and the feed)
+pub fn build_ics(tasks: &[&Task], prefs: &ExportPrefs, i18n: I18n) -> String {
+    let mut cal = Calendar::new();
+    let now = Utc::now();
+    for task in tasks {
+        push_task(&mut cal, task, prefs, i18n, now);
+    }
+    cal.to_string()
+}
+
+/// TASK ICS: A calendar holding just `task`, as one CalDAV resource stores it
+pub fn task_ics(task: &Task, prefs: &ExportPrefs, i18n: I18n) -> String {
+    let mut cal = Calendar::new();
+    push_task(&mut cal, task, prefs, i18n, Utc::now());
+    cal.to_string()
+}
+
+/// Adds `task` to `cal` as an event or a to-do.
+/// The tags become categories and the quadrant gets its own property; the description text is written in the interface language.
+fn push_task(cal: &mut Calendar, task: &Task, prefs: &ExportPrefs, i18n: I18n, now: DateTime<Utc>) {
+    // Build description with Eisenhower status + notes
+    let status_label = i18n.t(match task.quadrant {
+        Quadrant::DoFirst => "Do First (Urgent & Important)",
+        Quadrant::Schedule => "Schedule (Important, Not Urgent)",
+        Quadrant::Delegate => "Delegate (Urgent, Not Important)",
+        Quadrant::Delete => "Delete (Neither)",
+        Quadrant::Unsorted => "Unsorted",
+    });
+    let notes = task.description.as_deref().unwrap_or(i18n.t("No notes."));
+    let desc = i18n.tf("Eisenhower Status: {status}\n\nNotes:\n{notes}", &[("status", &status_label), ("notes", &notes)]);
+    let start = task.scheduled_start.map(|s| s.with_timezone(&Utc));
+    let end = task.scheduled_end.map(|e| e.with_timezone(&Utc));
+    let done = task.status == TaskStatus::Done;
+
+    // Finished tasks need no reminder
+    let reminder = prefs.reminder_minutes.filter(|_| !done && start.is_some()).map(|m| chrono::Duration::minutes(m as i64));
+
+    if prefs.as_todos {
+        let mut todo = Todo::with_uid(&task.id.to_string());
+        if let Some(start) = start {
+            todo.starts(start);
+        }
+        match (end, task.due) {
+            (Some(end), _) => todo.due(end),
+            (None, Some(due)) => todo.due(due),
+            (None, None) => &mut todo,
+        };
+        todo.status(if done { TodoStatus::Completed } else { TodoStatus::NeedsAction });
+        if let Some(completed) = task.completed_at.filter(|_| done) {
+            todo.completed(completed.with_timezone(&Utc));
+        }
+        describe(&mut todo, task, &desc, now, reminder);
+        cal.push(todo);
+    } else {
+        let mut event = Event::with_uid(&task.id.to_string());
+        if let (Some(start), Some(end)) = (start, end.or(start.map(|s| s + chrono::Duration::hours(1)))) {
+            event.starts(start).ends(end);
+        }
+        event.status(EventStatus::Confirmed);
+        describe(&mut event, task, &desc, now, reminder);
+        cal.push(event);
+    }
+}
+
+/// Fields events and to-dos share: title, notes, timestamps, categories and the reminder
+fn describe(item: &mut impl EventLike, task: &Task, description: &str, now: DateTime<Utc>, reminder: Option<chrono::Duration>) {
+    item.summary(&task.title)
+        .description(description)
+        .timestamp(now)
+        .created(task.created_at.with_timezone(&Utc))
+        .last_modified(task.last_touched().with_timezone(&Utc));
+    item.add_property(QUADRANT_PROPERTY, task.quadrant.label());
+    // One CATEGORIES line each, so commas inside a tag stay part of it
+    for tag in &task.tags {
+        item.append_multi_property(Property::new("CATEGORIES", tag.as_str()));
+    }
+    if let Some(before) = reminder {
+        item.alarm(Alarm::display(&task.title, Trigger::before_start(before)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Local, TimeZone};
+    use crate::i18n::{Clock, Locale};
+    use crate::ics::{read_items, ItemKind};
+
+    const EN: I18n = I18n { locale: Locale::En, clock: Clock::H24 };
+
+    fn scheduled(title: &str) -> Task {
+        let mut task = Task::new(title.to_string());
+        task.quadrant = Quadrant::Schedule;
+        task.tags = vec!["deep work".to_string()];
+        task.scheduled_start = Some(Local.with_ymd_and_hms(2026, 3, 2, 9, 0, 0).unwrap());
+        task.scheduled_end = Some(Local.with_ymd_and_hms(2026, 3, 2, 10, 30, 0).unwrap());
+        task
+    }
+
+    #[test]
+    fn events_need_a_slot_but_todos_do_not() {
+        let tasks = vec![scheduled("Slot"), Task { quadrant: Quadrant::Schedule, ..Task::new("Loose".to_string()) }];
+        let mut prefs = ExportPrefs::default();
+        assert_eq!(selection(&tasks, &prefs, None, None).len(), 1);
+        prefs.as_todos = true;
+        assert_eq!(selection(&tasks, &prefs, None, None).len(), 2);
+        // A range leaves out undated tasks
+        let day = NaiveDate::from_ymd_opt(2026, 3, 2);
+        assert_eq!(selection(&tasks, &prefs, day, day).len(), 1);
+    }
+
+    #[test]
+    fn an_exported_task_reads_back_with_its_uid_slot_and_tags() {
+        let task = scheduled("Write report");
+        for as_todos in [false, true] {
+            let prefs = ExportPrefs { as_todos, ..ExportPrefs::default() };
+            let items = read_items(&task_ics(&task, &prefs, EN)).unwrap();
+
+            assert_eq!(items.len(), 1);
+            let item = &items[0];
+            assert_eq!(item.kind, if as_todos { ItemKind::Todo } else { ItemKind::Event });
+            assert_eq!(item.uid, task.id.to_string());
+            assert_eq!((item.start, item.end), (task.scheduled_start, task.scheduled_end));
+            assert_eq!(item.tags, vec!["deep work".to_string()]);
+        }
+    }
+
+    #[test]
+    fn selection_keeps_chosen_quadrants_inside_the_range() {
+        let mut delegated = scheduled("Delegated");
+        delegated.quadrant = Quadrant::Delegate;
+        let mut later = scheduled("Later");
+        later.scheduled_start = Some(Local.with_ymd_and_hms(2026, 4, 1, 9, 0, 0).unwrap());
+        later.scheduled_end = None;
+        let tasks = vec![scheduled("March"), delegated, later];
+        let prefs = ExportPrefs::default();
+        let titles = |from, until| -> Vec<String> {
+            selection(&tasks, &prefs, from, until).iter().map(|t| t.title.clone()).collect()
+        };
+
+        assert_eq!(titles(None, None), vec!["March", "Later"]);
+        assert_eq!(titles(NaiveDate::from_ymd_opt(2026, 3, 15), None), vec!["Later"]);
+        assert_eq!(titles(None, NaiveDate::from_ymd_opt(2026, 3, 2)), vec!["March"]);
+    }
+
+    #[test]
+    fn build_ics_writes_status_reminders_and_tags_for_every_task() {
+        let mut done = scheduled("Done");
+        done.tags = vec!["R,D".to_string()];
+        done.set_status(TaskStatus::Done);
+        let open = scheduled("Open");
+        let prefs = ExportPrefs { as_todos: true, reminder_minutes: Some(10), ..ExportPrefs::default() };
+        let ics = build_ics(&[&open, &done], &prefs, EN);
+
+        // One alarm, for the open task only
+        assert_eq!(ics.matches("BEGIN:VALARM").count(), 1);
+        assert!(ics.contains("TRIGGER;RELATED=START:-PT600S"));
+        assert!(ics.contains("STATUS:NEEDS-ACTION") && ics.contains("STATUS:COMPLETED"));
+
+        let items = read_items(&ics).unwrap();
+        assert_eq!(items.iter().map(|i| i.uid.clone()).collect::<Vec<_>>(), vec![open.id.to_string(), done.id.to_string()]);
+        assert!(!items[0].done && items[1].done);
+        assert!(items[0].description.as_deref().is_some_and(|d| d.starts_with("Eisenhower Status: Schedule (Important, Not Urgent)")));
+        // The quadrant is not read back as a tag, and a comma stays inside its tag
+        assert_eq!(items[1].tags, vec!["R,D".to_string()]);
+    }
+
+    #[test]
+    fn tags_named_like_a_quadrant_survive_a_round_trip() {
+        let mut task = scheduled("Plan sprint");
+        task.tags = vec!["Schedule".to_string(), "Delete".to_string()];
+        let ics = task_ics(&task, &ExportPrefs::default(), EN);
+
+        assert!(ics.contains("X-ERRDAY-QUADRANT:Schedule"));
+        assert_eq!(read_items(&ics).unwrap()[0].tags, vec!["Schedule".to_string(), "Delete".to_string()]);
+    }
+}
@@ -0,0 +1,291 @@
+// FEED: The optional calendar subscription served over loopback HTTP.
+// A background thread listens on 127.0.0.1 only and answers GET /<token>/errday.ics with the latest
+// export; every other path gets a 404, so without the token the feed cannot be found.
+// The server never touches the store: the app publishes fresh ICS text whenever tasks or the
+// export choices change, and subscribed calendar apps pick it up on their next refresh.
+
+use std::io::{self, Read, Write};
+use std::net::{Ipv4Addr, TcpListener, TcpStream};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+use dioxus::prelude::*;
+
+/// File name at the end of the feed URL
+const FEED_NAME: &str = "errday.ics";
+/// Longest request head we read before giving up on a client
+const MAX_REQUEST: usize = 8 * 1024;
+/// How often the listening thread checks whether it should stop
+const POLL: Duration = Duration::from_millis(50);
+/// Longest we wait on a slow client
+const CLIENT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// The subscription URL for a port and token
+pub fn feed_url(port: u16, token: &str) -> String {
+    format!("http://127.0.0.1:{port}/{token}/{FEED_NAME}")
+}
+
+/// A fresh random token for the URL (64 hex digits)
+pub fn new_token() -> String {
+    format!("{}{}", uuid::Uuid::new_v4().simple(), uuid::Uuid::new_v4().simple())
+}
+
+/// What the listening thread serves; the app swaps either without restarting the listener
+#[derive(Default)]
+struct Served {
+    token: String,
+    body: String,
+}
+
+/// RUNNING SERVER: Stops when dropped
+pub struct Server {
+    port: u16, // The port actually bound (differs from the requested one when that was 0)
+    served: Arc<Mutex<Served>>,
+    stop: Arc<AtomicBool>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl Server {
+    /// Binds 127.0.0.1:`port` and starts answering requests for `token`
+    pub fn start(port: u16, token: &str) -> io::Result<Server> {
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, port))?;
+        // Never block in accept, so the thread notices `stop` within one poll
+        listener.set_nonblocking(true)?;
+        let served = Arc::new(Mutex::new(Served { token: token.to_string(), body: String::new() }));
+        let stop = Arc::new(AtomicBool::new(false));
+        let port = listener.local_addr()?.port();
+        let thread = thread::spawn({
+            let (served, stop) = (served.clone(), stop.clone());
+            move || {
+                while !stop.load(Ordering::SeqCst) {
+                    match listener.accept() {
+                        Ok((stream, _)) => {
+                            let _ = answer(stream, &served, &stop);
+                        }
+                        Err(_) => thread::sleep(POLL),
+                    }
+                }
+            }
+        });
+        Ok(Server { port, served, stop, thread: Some(thread) })
+    }
+
+    /// Replaces the ICS text served from now on
+    pub fn publish(&self, ics: String) {
+        if let Ok(mut served) = self.served.lock() {
+            served.body = ics;
+        }
+    }
+
+    /// Answers at a new token from now on (the old URL stops working)
+    pub fn set_token(&self, token: &str) {
+        if let Ok(mut served) = self.served.lock() {
+            served.token = token.to_string();
+        }
+    }
+}
+
+impl Drop for Server {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::SeqCst);
+        // The thread checks the flag at least once per poll, even mid-request, so this wait is short
+        // and the port is free again when it returns
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+/// Reads one request from `stream` and writes the response, giving up early once `stop` is set
+fn answer(mut stream: TcpStream, served: &Mutex<Served>, stop: &AtomicBool) -> io::Result<()> {
+    stream.set_nonblocking(false)?;
+    stream.set_read_timeout(Some(POLL))?;
+    stream.set_write_timeout(Some(CLIENT_TIMEOUT))?;
+    let started = Instant::now();
+    let mut head = Vec::new();
+    let mut chunk = [0u8; 1024];
+    while !head.windows(4).any(|w| w == b"\r\n\r\n") && head.len() < MAX_REQUEST {
+        match stream.read(&mut chunk) {
+            Ok(0) => break,
+            Ok(read) => head.extend_from_slice(&chunk[..read]),
+            Err(e) if matches!(e.kind(), io::ErrorKind::WouldBlock | io::ErrorKind::TimedOut)
+                && !stop.load(Ordering::SeqCst)
+                && started.elapsed() < CLIENT_TIMEOUT => {}
+            Err(e) => return Err(e),
+        }
+    }
+    let head = String::from_utf8_lossy(&head);
+    let request_line = head.lines().next().unwrap_or_default();
+    let reply = match served.lock() {
+        Ok(served) => response(request_line, &served.token, &served.body),
+        Err(_) => response(request_line, "", ""),
+    };
+    stream.write_all(&reply)?;
+    stream.flush()
+}
+
+/// RESPONSE: The bytes answering `request_line` ("GET /path HTTP/1.1")
+pub fn response(request_line: &str, token: &str, ics: &str) -> Vec<u8> {
+    let mut parts = request_line.split_whitespace();
+    let (method, target) = (parts.next().unwrap_or_default(), parts.next().unwrap_or_default());
+    let path = target.split('?').next().unwrap_or_default();
+    let expected = format!("/{token}/{FEED_NAME}");
+
+    let (status, content_type, body) = if !same(path.as_bytes(), expected.as_bytes()) || token.is_empty() {
+        ("404 Not Found", "text/plain; charset=utf-8", "Not found")
+    } else if method != "GET" && method != "HEAD" {
+        ("405 Method Not Allowed", "text/plain; charset=utf-8", "Method not allowed")
+    } else {
+        ("200 OK", "text/calendar; charset=utf-8", ics)
+    };
+    let mut out = format!(
+        "HTTP/1.1 {status}\r\nContent-Type: {content_type}\r\nContent-Length: {}\r\nCache-Control: no-cache\r\nConnection: close\r\n\r\n",
+        body.len()
+    )
+    .into_bytes();
+    if method != "HEAD" {
+        out.extend_from_slice(body.as_bytes());
+    }
+    out
+}
+
+/// Compares without stopping at the first difference, so timing does not leak the token
+fn same(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |diff, (x, y)| diff | (x ^ y)) == 0
+}
+
+/// FEED STATE: The running server (if any) and why the last start failed, shared with the settings page
+#[derive(Clone, Copy)]
+pub struct FeedState {
+    server: Signal<Option<Server>>,
+    pub error: Signal<Option<String>>,
+}
+
+impl FeedState {
+    pub fn new() -> Self {
+        Self { server: Signal::new(None), error: Signal::new(None) }
+    }
+
+    /// Starts, restarts or stops the server to match the settings.
+    /// A new token on the same port keeps the running listener and only changes the URL it answers.
+    pub fn configure(&self, enabled: bool, port: u16, token: &str) {
+        let mut server_sig = self.server;
+        let mut error_sig = self.error;
+        if !enabled || token.is_empty() {
+            server_sig.set(None);
+            error_sig.set(None);
+            return;
+        }
+        if let Some(server) = server_sig.peek().as_ref().filter(|s| s.port == port) {
+            server.set_token(token);
+            error_sig.set(None);
+            return;
+        }
+        // Stop the old server first (dropping it waits for the port to be released)
+        server_sig.set(None);
+        match Server::start(port, token) {
+            Ok(server) => {
+                server_sig.set(Some(server));
+                error_sig.set(None);
+            }
+            Err(e) => error_sig.set(Some(format!("Could not listen on port {port}: {e}"))),
+        }
+    }
+
+    /// Serves `ics` from now on (kept only while a server runs)
+    pub fn publish(&self, ics: String) {
+        if let Some(server) = self.server.read().as_ref() {
+            server.publish(ics);
+        }
+    }
+
+    /// Whether the server is running
+    pub fn is_running(&self) -> bool {
+        self.server.read().is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TOKEN: &str = "abc123";
+
+    fn text(bytes: Vec<u8>) -> String {
+        String::from_utf8(bytes).unwrap()
+    }
+
+    #[test]
+    fn serves_the_calendar_only_at_the_token_path() {
+        let ok = text(response("GET /abc123/errday.ics?x=1 HTTP/1.1", TOKEN, "BEGIN:VCALENDAR"));
+        assert!(ok.starts_with("HTTP/1.1 200 OK\r\n"));
+        assert!(ok.contains("Content-Type: text/calendar"));
+        assert!(ok.ends_with("\r\n\r\nBEGIN:VCALENDAR"));
+
+        for line in ["GET /abc124/errday.ics HTTP/1.1", "GET /errday.ics HTTP/1.1", "GET / HTTP/1.1", ""] {
+            let missing = text(response(line, TOKEN, "BEGIN:VCALENDAR"));
+            assert!(missing.starts_with("HTTP/1.1 404"), "{line}");
+            assert!(!missing.contains("VCALENDAR"));
+        }
+        assert!(text(response("GET //errday.ics HTTP/1.1", "", "x")).starts_with("HTTP/1.1 404"));
+    }
+
+    #[test]
+    fn head_has_no_body_and_other_methods_are_refused() {
+        let head = text(response("HEAD /abc123/errday.ics HTTP/1.1", TOKEN, "BEGIN:VCALENDAR"));
+        assert!(head.contains("Content-Length: 15") && head.ends_with("\r\n\r\n"));
+        assert!(text(response("POST /abc123/errday.ics HTTP/1.1", TOKEN, "x")).starts_with("HTTP/1.1 405"));
+    }
+
+    #[test]
+    fn answers_over_loopback_with_the_latest_publish() {
+        let server = Server::start(0, TOKEN).unwrap();
+        server.publish("first".to_string());
+        server.publish("BEGIN:VCALENDAR".to_string());
+
+        let mut stream = TcpStream::connect((Ipv4Addr::LOCALHOST, server.port)).unwrap();
+        stream.write_all(b"GET /abc123/errday.ics HTTP/1.1\r\nHost: localhost\r\n\r\n").unwrap();
+        let mut reply = String::new();
+        stream.read_to_string(&mut reply).unwrap();
+
+        assert!(reply.starts_with("HTTP/1.1 200 OK"));
+        assert!(reply.ends_with("BEGIN:VCALENDAR"));
+    }
+
+    fn get(port: u16, path: &str) -> String {
+        let mut stream = TcpStream::connect((Ipv4Addr::LOCALHOST, port)).unwrap();
+        stream.write_all(format!("GET {path} HTTP/1.1\r\n\r\n").as_bytes()).unwrap();
+        let mut reply = String::new();
+        stream.read_to_string(&mut reply).unwrap();
+        reply
+    }
+
+    #[test]
+    fn a_new_token_takes_effect_without_a_new_listener() {
+        let server = Server::start(0, TOKEN).unwrap();
+        server.set_token("def456");
+
+        assert!(get(server.port, "/abc123/errday.ics").starts_with("HTTP/1.1 404"));
+        assert!(get(server.port, "/def456/errday.ics").starts_with("HTTP/1.1 200"));
+    }
+
+    #[test]
+    fn dropping_a_server_frees_its_port_for_a_restart() {
+        let port = Server::start(0, TOKEN).unwrap().port;
+        let again = Server::start(port, TOKEN).unwrap();
+        assert!(get(again.port, "/abc123/errday.ics").starts_with("HTTP/1.1 200"));
+    }
+
+    #[test]
+    fn a_silent_client_does_not_hold_up_a_stop() {
+        let server = Server::start(0, TOKEN).unwrap();
+        let _silent = TcpStream::connect((Ipv4Addr::LOCALHOST, server.port)).unwrap();
+        // Let the thread pick the connection up and start waiting on it
+        thread::sleep(POLL * 3);
+
+        let started = Instant::now();
+        drop(server);
+        assert!(started.elapsed() < Duration::from_secs(1));
+    }
+}
@@ -0,0 +1,203 @@
+use chrono::{Datelike, NaiveDate, Weekday};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use crate::models::{Quadrant, Task, TaskStatus};
+
+/// DATE FIELD: Which of a task's dates the date window looks at
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub enum DateField {
+    #[default]
+    Due,       // The deadline
+    Scheduled, // The calendar block start
+}
+
+/// DATE WINDOW: A range relative to today, or fixed dates
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub enum DateWindow {
+    #[default]
+    Any,
+    Overdue,                                          // Before today
+    Today,
+    ThisWeek,                                         // The current week, from the configured first day
+    NextDays(i64),                                    // Today and the following N - 1 days
+    Between(Option<NaiveDate>, Option<NaiveDate>),    // Fixed range, either end may be open
+}
+
+/// SAVED FILTER: A named "perspective" on the task list, shown in the sidebar.
+/// Every criterion is optional; a task has to pass all the ones that are set.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SavedFilter {
+    pub id: Uuid,
+    pub name: String,
+    #[serde(default)]
+    pub quadrants: Vec<Quadrant>,     // Allowed quadrants (empty = any)
+    #[serde(default)]
+    pub status: Option<TaskStatus>,   // None = open and done tasks
+    #[serde(default)]
+    pub tags: Vec<String>,            // Every tag must be present
+    #[serde(default)]
+    pub project: Option<String>,
+    #[serde(default)]
+    pub date_field: DateField,
+    #[serde(default)]
+    pub window: DateWindow,
+    #[serde(default)]
+    pub text: String,                 // Matched against title and notes
+}
+
+impl SavedFilter {
+    /// CREATING A FILTER: Starts with open tasks only, nothing else narrowed down
+    pub fn new(name: String) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            name,
+            quadrants: Vec::new(),
+            status: Some(TaskStatus::Todo),
+            tags: Vec::new(),
+            project: None,
+            date_field: DateField::default(),
+            window: DateWindow::default(),
+            text: String::new(),
+        }
+    }
+
+    /// MATCHING: Does this task pass every criterion of the filter? (`today` keeps it testable;
+    /// `week_starts_on` is the calendar's first day, which "this week" counts from)
+    pub fn matches(&self, task: &Task, today: NaiveDate, week_starts_on: Weekday) -> bool {
+        if !self.quadrants.is_empty() && !self.quadrants.contains(&task.quadrant) {
+            return false;
+        }
+        if self.status.as_ref().is_some_and(|s| *s != task.status) {
+            return false;
+        }
+        if !self.tags.iter().all(|want| task.tags.iter().any(|t| t.eq_ignore_ascii_case(want))) {
+            return false;
+        }
+        if let Some(project) = &self.project {
+            if !task.project.as_deref().is_some_and(|p| p.eq_ignore_ascii_case(project)) {
+                return false;
+            }
+        }
+        let text = self.text.trim().to_lowercase();
+        if !text.is_empty()
+            && !task.title.to_lowercase().contains(&text)
+            && !task.description.as_deref().is_some_and(|d| d.to_lowercase().contains(&text))
+        {
+            return false;
+        }
+        self.window_matches(task, today, week_starts_on)
+    }
+
+    /// Checks the date window against the chosen date field (tasks without that date only pass "Any")
+    fn window_matches(&self, task: &Task, today: NaiveDate, week_starts_on: Weekday) -> bool {
+        if self.window == DateWindow::Any {
+            return true;
+        }
+        let date = match self.date_field {
+            DateField::Due => task.due,
+            DateField::Scheduled => task.scheduled_start.map(|s| s.date_naive()),
+        };
+        let Some(date) = date else { return false };
+        match &self.window {
+            DateWindow::Any => true,
+            DateWindow::Overdue => date < today,
+            DateWindow::Today => date == today,
+            DateWindow::ThisWeek => {
+                let back = (7 + today.weekday().num_days_from_monday() - week_starts_on.num_days_from_monday()) % 7;
+                let first = today - chrono::Duration::days(back as i64);
+                date >= first && date < first + chrono::Duration::days(7)
+            }
+            DateWindow::NextDays(n) => date >= today && date < today + chrono::Duration::days(*n),
+            DateWindow::Between(from, to) => {
+                from.is_none_or(|f| date >= f) && to.is_none_or(|t| date <= t)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Wednesday 14 January 2026
+    fn today() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 1, 14).unwrap()
+    }
+
+    fn task(project: Option<&str>, due: Option<NaiveDate>, tags: &[&str]) -> Task {
+        let mut task = Task::new("Pitch deck".to_string());
+        task.project = project.map(str::to_string);
+        task.due = due;
+        task.tags = tags.iter().map(|t| t.to_string()).collect();
+        task
+    }
+
+    #[test]
+    fn project_and_tags_match_ignoring_case() {
+        let mut filter = SavedFilter::new("Seed".to_string());
+        filter.project = Some("seed round".to_string());
+        filter.tags = vec!["Investors".to_string()];
+
+        assert!(filter.matches(&task(Some("Seed Round"), None, &["investors", "deck"]), today(), Weekday::Mon));
+        assert!(!filter.matches(&task(Some("Seed Round"), None, &["deck"]), today(), Weekday::Mon));
+        assert!(!filter.matches(&task(Some("Hiring"), None, &["investors"]), today(), Weekday::Mon));
+        assert!(!filter.matches(&task(None, None, &["investors"]), today(), Weekday::Mon));
+    }
+
+    #[test]
+    fn due_windows_are_relative_to_today() {
+        let day = |d| NaiveDate::from_ymd_opt(2026, 1, d);
+        let mut filter = SavedFilter::new("Due".to_string());
+        // Tasks without a due date only pass "Any"
+        assert!(filter.matches(&task(None, None, &[]), today(), Weekday::Mon));
+
+        filter.window = DateWindow::Overdue;
+        assert!(filter.matches(&task(None, day(13), &[]), today(), Weekday::Mon));
+        assert!(!filter.matches(&task(None, day(14), &[]), today(), Weekday::Mon));
+        assert!(!filter.matches(&task(None, None, &[]), today(), Weekday::Mon));
+
+        // Monday 12 to Sunday 18 January
+        filter.window = DateWindow::ThisWeek;
+        assert!(filter.matches(&task(None, day(12), &[]), today(), Weekday::Mon));
+        assert!(filter.matches(&task(None, day(18), &[]), today(), Weekday::Mon));
+        assert!(!filter.matches(&task(None, day(19), &[]), today(), Weekday::Mon));
+
+        filter.window = DateWindow::NextDays(3);
+        assert!(filter.matches(&task(None, day(16), &[]), today(), Weekday::Mon));
+        assert!(!filter.matches(&task(None, day(17), &[]), today(), Weekday::Mon));
+
+        filter.window = DateWindow::Between(day(10), None);
+        assert!(filter.matches(&task(None, day(30), &[]), today(), Weekday::Mon));
+        assert!(!filter.matches(&task(None, day(9), &[]), today(), Weekday::Mon));
+    }
+
+    #[test]
+    fn this_week_starts_on_the_configured_day() {
+        let day = |d| NaiveDate::from_ymd_opt(2026, 1, d);
+        let mut filter = SavedFilter::new("This week".to_string());
+        filter.window = DateWindow::ThisWeek;
+
+        // Sunday 11 to Saturday 17 January
+        assert!(filter.matches(&task(None, day(11), &[]), today(), Weekday::Sun));
+        assert!(filter.matches(&task(None, day(17), &[]), today(), Weekday::Sun));
+        assert!(!filter.matches(&task(None, day(18), &[]), today(), Weekday::Sun));
+        assert!(!filter.matches(&task(None, day(10), &[]), today(), Weekday::Sun));
+    }
+
+    #[test]
+    fn every_criterion_has_to_pass() {
+        let mut filter = SavedFilter::new("Due investor work".to_string());
+        filter.project = Some("Seed Round".to_string());
+        filter.tags = vec!["investors".to_string()];
+        filter.window = DateWindow::Today;
+
+        assert!(filter.matches(&task(Some("Seed Round"), Some(today()), &["investors"]), today(), Weekday::Mon));
+        assert!(!filter.matches(&task(Some("Seed Round"), today().succ_opt(), &["investors"]), today(), Weekday::Mon));
+
+        let mut done = task(Some("Seed Round"), Some(today()), &["investors"]);
+        done.status = TaskStatus::Done;
+        assert!(!filter.matches(&done, today(), Weekday::Mon));
+        filter.status = None;
+        assert!(filter.matches(&done, today(), Weekday::Mon));
+    }
+}
@@ -0,0 +1,757 @@
+// I18N: The message catalog and locale-aware date/time formatting.
+// Messages are keyed by their English text: `i18n.t("Today")` gives the active language's
+// string, and English (or a message nobody has translated yet) gives the key back unchanged.
+// Messages with values use {placeholders}, filled in by `tf`.
+// Dates are formatted by hand from the tables below so no locale data has to ship with the app.
+
+use chrono::{DateTime, Datelike, Local, NaiveDate, Timelike, Weekday};
+use serde::{Deserialize, Serialize};
+
+/// LOCALE: A language the interface is translated into
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Locale {
+    #[default]
+    En,
+    Fr,
+}
+
+impl Locale {
+    pub const ALL: [Locale; 2] = [Locale::En, Locale::Fr];
+
+    /// Short code stored in settings.json ("en", "fr")
+    pub fn code(&self) -> &'static str {
+        match self {
+            Locale::En => "en",
+            Locale::Fr => "fr",
+        }
+    }
+
+    pub fn from_code(code: &str) -> Option<Locale> {
+        Locale::ALL.into_iter().find(|l| l.code() == code)
+    }
+
+    /// The language's name in that language, for the picker
+    pub fn native_name(&self) -> &'static str {
+        match self {
+            Locale::En => "English",
+            Locale::Fr => "Français",
+        }
+    }
+
+    /// The language of the operating system (LC_ALL / LC_MESSAGES / LANG), English otherwise
+    pub fn from_system() -> Locale {
+        ["LC_ALL", "LC_MESSAGES", "LANG"]
+            .iter()
+            .filter_map(|var| std::env::var(var).ok())
+            .find(|value| !value.is_empty())
+            .and_then(|value| value.to_lowercase().get(..2).and_then(Locale::from_code))
+            .unwrap_or_default()
+    }
+
+    /// First column of the week where the language is spoken (North American English starts on Sunday)
+    pub fn default_week_start(&self) -> Weekday {
+        match self {
+            Locale::En => Weekday::Sun,
+            Locale::Fr => Weekday::Mon,
+        }
+    }
+
+    pub fn default_clock(&self) -> Clock {
+        match self {
+            Locale::En => Clock::H12,
+            Locale::Fr => Clock::H24,
+        }
+    }
+}
+
+/// CLOCK: How times of day are written
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Clock {
+    #[default]
+    #[serde(rename = "12h")]
+    H12, // 2:30 PM
+    #[serde(rename = "24h")]
+    H24, // 14:30
+}
+
+/// FRENCH: Translations of the English keys
+const FR: &[(&str, &str)] = &[
+    // Quadrants
+    ("Do First", "À faire"),
+    ("Schedule", "Planifier"),
+    ("Delegate", "Déléguer"),
+    ("Delete", "Supprimer"),
+    ("Unsorted", "Non trié"),
+    // Calendar
+    ("Unscheduled", "Non planifiées"),
+    ("Drag to schedule", "Glisser pour planifier"),
+    ("Export Calendar (.ics)", "Exporter le calendrier (.ics)"),
+    ("All tasks scheduled.", "Toutes les tâches sont planifiées."),
+    ("Today", "Aujourd'hui"),
+    ("Week", "Semaine"),
+    ("Day", "Jour"),
+    ("Previous week", "Semaine précédente"),
+    ("Next week", "Semaine suivante"),
+    ("Previous day", "Jour précédent"),
+    ("Next day", "Jour suivant"),
+    ("Month", "Mois"),
+    ("Agenda", "Agenda"),
+    ("Previous month", "Mois précédent"),
+    ("Next month", "Mois suivant"),
+    ("+{count} more", "+{count} autres"),
+    ("{count} tasks", "{count} tâches"),
+    ("Tomorrow", "Demain"),
+    ("Next {days} days", "{days} prochains jours"),
+    ("// NOTHING SCHEDULED", "// RIEN DE PLANIFIÉ"),
+    ("Reschedule…", "Replanifier…"),
+    ("DO", "FAIRE"),
+    ("SCH", "PLAN"),
+    // Inbox
+    ("MISSION CONTROL", "CENTRE DE CONTRÔLE"),
+    ("BRAIN DUMP // CAPTURE", "VIDE-CERVEAU // CAPTURE"),
+    ("What's on your mind?", "À quoi pensez-vous ?"),
+    ("Capture Task", "Capturer"),
+    ("Split into separate tasks?", "Séparer en plusieurs tâches ?"),
+    ("{included} OF {total} ITEMS SELECTED", "{included} SUR {total} ÉLÉMENTS SÉLECTIONNÉS"),
+    ("Keep as one", "Garder en une seule"),
+    ("Split into {count} tasks", "Séparer en {count} tâches"),
+    ("Already done", "Déjà fait"),
+    ("Stale", "Inactives"),
+    ("Untouched for", "Sans activité depuis"),
+    ("days or more", "jours ou plus"),
+    ("Archive {count}", "Archiver {count}"),
+    ("Delete {count}", "Supprimer {count}"),
+    ("// ALL FRESH - NOTHING STALE", "// TOUT EST FRAIS - RIEN D'INACTIF"),
+    ("// SYSTEM CLEAR - NO PENDING TASKS", "// SYSTÈME DÉGAGÉ - AUCUNE TÂCHE EN ATTENTE"),
+    ("Task Description", "Description de la tâche"),
+    ("Age", "Âge"),
+    ("Status", "Statut"),
+    ("Actions", "Actions"),
+    ("Assess", "Évaluer"),
+    ("Captured {when}\nLast touched {when_touched}", "Capturée le {when}\nModifiée le {when_touched}"),
+    ("{days}d", "{days} j"),
+    (" · {days}d idle", " · inactive {days} j"),
+    // ICS export
+    ("Do First (Urgent & Important)", "À faire (urgent et important)"),
+    ("Schedule (Important, Not Urgent)", "Planifier (important, pas urgent)"),
+    ("Delegate (Urgent, Not Important)", "Déléguer (urgent, pas important)"),
+    ("Delete (Neither)", "Supprimer (ni l'un ni l'autre)"),
+    ("No notes.", "Aucune note."),
+    ("Eisenhower Status: {status}\n\nNotes:\n{notes}", "Statut Eisenhower : {status}\n\nNotes :\n{notes}"),
+    // Task menu and keyboard scheduling
+    ("Move to quadrant…", "Déplacer vers le quadrant…"),
+    ("Schedule at…", "Planifier à…"),
+    ("Brainstorm", "Remue-méninges"),
+    ("Start", "Début"),
+    ("Unschedule", "Retirer du calendrier"),
+    ("Picked up {title}. Arrow keys choose where, Enter drops it, Escape cancels.", "{title} saisie. Les flèches choisissent l'emplacement, Entrée la dépose, Échap annule."),
+    ("Scheduled {title} on {when}.", "{title} planifiée le {when}."),
+    ("Cancelled.", "Annulé."),
+    ("Depends on…", "Dépend de…"),
+    ("No other open tasks", "Aucune autre tâche ouverte"),
+    // Overlapping blocks
+    ("{title} overlaps {others}.", "{title} chevauche {others}."),
+    ("Move to next free slot", "Déplacer au prochain créneau libre"),
+    ("Keep it here", "Laisser ici"),
+    ("{title} is outside working hours.", "{title} est en dehors des heures de travail."),
+    ("{title} is in protected time ({label}).", "{title} empiète sur un temps protégé ({label})."),
+    ("Undo", "Annuler"),
+    ("Overlaps another task", "Chevauche une autre tâche"),
+    // Plan my week
+    ("Plan my week", "Planifier ma semaine"),
+    ("Collapse off-hours", "Masquer les heures hors travail"),
+    ("Nothing to plan: every open Do First and Schedule task already has a slot.", "Rien à planifier : toutes les tâches ouvertes À faire et Planifier ont déjà un créneau."),
+    ("Proposed blocks", "Créneaux proposés"),
+    ("Untick a block to leave it out, or change its start.", "Décochez un créneau pour l'écarter, ou changez son début."),
+    ("Not placed", "Non placées"),
+    ("No free working time left in this range", "Plus de temps de travail libre sur cette période"),
+    ("Would end after its deadline", "Finirait après son échéance"),
+    ("Waits on {title}", "Attend {title}"),
+    ("Cancel", "Annuler"),
+    ("Accept", "Accepter"),
+    // Imported calendars
+    ("Import busy time (.ics)", "Importer des indisponibilités (.ics)"),
+    ("Busy calendars", "Agendas importés"),
+    ("Busy", "Occupé"),
+    ("Remove {name}", "Retirer {name}"),
+    ("Could not read {file}: {error}", "Impossible de lire {file} : {error}"),
+    ("{source}: {count} busy blocks", "{source} : {count} créneaux occupés"),
+    ("Import tasks", "Importer des tâches"),
+    ("Import .ics", "Importer .ics"),
+    ("Import events and to-dos from an .ics file", "Importer les événements et tâches d'un fichier .ics"),
+    ("No events or to-dos in this file.", "Aucun événement ni tâche dans ce fichier."),
+    ("Add to", "Ajouter à"),
+    ("Inbox", "Boîte de réception"),
+    ("{count} already imported", "{count} déjà importé(s)"),
+    ("Already imported", "Déjà importé"),
+    ("Due {date}", "Échéance {date}"),
+    ("EVENT", "ÉVÉNEMENT"),
+    ("TO-DO", "TÂCHE"),
+    ("Import {count}", "Importer {count}"),
+    ("Dates", "Dates"),
+    ("From", "Du"),
+    ("Until", "Au"),
+    ("Quadrants", "Quadrants"),
+    ("Export as", "Exporter en"),
+    ("Events (VEVENT)", "Événements (VEVENT)"),
+    ("To-dos (VTODO)", "Tâches (VTODO)"),
+    ("To-dos include tasks without a slot.", "Les tâches incluent celles sans créneau."),
+    ("Reminder", "Rappel"),
+    ("{minutes} min before", "{minutes} min avant"),
+    ("None", "Aucun"),
+    ("Export {count}", "Exporter {count}"),
+    // Settings
+    ("Language & region", "Langue et région"),
+    ("Language", "Langue"),
+    ("Also resets the clock and first day of the week to the language's usual ones", "Règle aussi l'horloge et le premier jour de la semaine selon l'usage de la langue"),
+    ("Clock", "Horloge"),
+    ("How times appear in the calendar and tooltips", "Format des heures dans le calendrier et les infobulles"),
+    ("12-hour", "12 heures"),
+    ("24-hour", "24 heures"),
+    ("SETTINGS", "RÉGLAGES"),
+    ("SYSTEM // PREFERENCES", "SYSTÈME // PRÉFÉRENCES"),
+    ("settings.json could not be read: {error}", "Impossible de lire settings.json : {error}"),
+    ("Appearance", "Apparence"),
+    ("Theme", "Thème"),
+    ("\"System\" follows the light/dark setting of your OS", "« Système » suit le réglage clair/sombre de votre système"),
+    ("System", "Système"),
+    ("Theme files", "Fichiers de thème"),
+    ("JSON with a name and #rrggbb colors; missing colors come from Deep Space", "JSON avec un nom et des couleurs #rrggbb ; les couleurs manquantes viennent de Deep Space"),
+    ("Import…", "Importer…"),
+    ("Export…", "Exporter…"),
+    ("Import theme", "Importer un thème"),
+    ("Export theme", "Exporter le thème"),
+    ("Not a theme file: {error}", "Pas un fichier de thème : {error}"),
+    ("Could not save the theme: {error}", "Impossible d'enregistrer le thème : {error}"),
+    ("Calendar", "Calendrier"),
+    ("Visible hours", "Heures visibles"),
+    ("Hours outside this range are hidden from the week grid", "Les heures hors de cette plage sont masquées dans la grille de la semaine"),
+    ("to", "à"),
+    ("Week starts on", "Début de la semaine"),
+    ("First column of the week grid", "Première colonne de la grille de la semaine"),
+    ("Default block", "Créneau par défaut"),
+    ("Length of a task the first time it is scheduled", "Durée d'une tâche la première fois qu'elle est planifiée"),
+    ("minutes", "minutes"),
+    ("Snap to", "Magnétisme"),
+    ("Resolution for dropping and resizing blocks", "Pas utilisé pour déposer et redimensionner les créneaux"),
+    ("Availability", "Disponibilités"),
+    ("Working hours", "Heures de travail"),
+    ("Shaded on the calendar; Plan my week and drops stay inside them", "Grisées dans le calendrier ; Planifier ma semaine et les dépôts restent à l'intérieur"),
+    ("Off-hours drops", "Dépôts hors horaires"),
+    ("What happens to a task dropped outside working hours or on protected time", "Ce qui arrive à une tâche déposée hors des heures de travail ou sur un temps protégé"),
+    ("Warn", "Avertir"),
+    ("Move to the next open slot", "Déplacer au prochain créneau libre"),
+    ("Protected blocks", "Temps protégés"),
+    ("Recurring focus time such as \"Deep work 9–11\" or \"No meetings\" all Friday", "Temps de concentration récurrent, comme « Travail de fond 9–11 » ou « Pas de réunion » tout le vendredi"),
+    ("Label", "Libellé"),
+    ("All day", "Toute la journée"),
+    ("Remove", "Retirer"),
+    ("Add protected block", "Ajouter un temps protégé"),
+    ("Calendar feed", "Flux de calendrier"),
+    ("Serve a calendar feed", "Publier un flux de calendrier"),
+    ("Calendar apps on this computer can subscribe to the export (same choices as Export Calendar)", "Les applications de calendrier de cet ordinateur peuvent s'abonner à l'export (mêmes choix que Exporter le calendrier)"),
+    ("Port", "Port"),
+    ("Only reachable from this computer (127.0.0.1)", "Accessible uniquement depuis cet ordinateur (127.0.0.1)"),
+    ("Feed URL", "URL du flux"),
+    ("Copy", "Copier"),
+    ("Existing subscriptions stop working", "Les abonnements existants cessent de fonctionner"),
+    ("New link", "Nouveau lien"),
+    ("Serving. Keep the link private: anyone with it can read your schedule.", "Publication en cours. Gardez le lien privé : quiconque l'a peut lire votre emploi du temps."),
+    ("CalDAV sync", "Synchronisation CalDAV"),
+    ("Collection URL", "URL de la collection"),
+    ("A calendar on Radicale, Nextcloud or similar (empty = off)", "Un calendrier sur Radicale, Nextcloud ou similaire (vide = désactivé)"),
+    ("This address is plain http://, so the username and password cross the network unencrypted. Use https:// unless the server runs on this computer.", "Cette adresse est en http:// simple : le nom d'utilisateur et le mot de passe traversent le réseau sans chiffrement. Utilisez https:// sauf si le serveur tourne sur cet ordinateur."),
+    ("Username", "Nom d'utilisateur"),
+    ("Leave empty if the server asks for none", "Laisser vide si le serveur n'en demande pas"),
+    ("Password", "Mot de passe"),
+    ("Saved in the system keyring; prefer an app password", "Enregistré dans le trousseau du système ; préférez un mot de passe d'application"),
+    ("Saved in keyring", "Enregistré dans le trousseau"),
+    ("No system keyring is available, so the password is stored unencrypted in settings.json.", "Aucun trousseau système n'est disponible : le mot de passe est enregistré en clair dans settings.json."),
+    ("Sync now", "Synchroniser maintenant"),
+    ("Sends the tasks Export Calendar covers and takes back times moved on the server", "Envoie les tâches couvertes par Exporter le calendrier et reprend les horaires déplacés sur le serveur"),
+    ("Sync", "Synchroniser"),
+    ("Workflow", "Flux de travail"),
+    ("{quadrant} limit", "Limite {quadrant}"),
+    ("Open tasks allowed before the matrix asks to demote (empty = no limit)", "Tâches ouvertes permises avant que la matrice propose d'en rétrograder (vide = sans limite)"),
+    ("Stale after", "Inactive après"),
+    ("Untouched open tasks show up in the Stale filter", "Les tâches ouvertes sans activité apparaissent dans le filtre Inactives"),
+    ("days", "jours"),
+    ("Archive done after", "Archiver les tâches faites après"),
+    ("Finished tasks move to the Archive (0 = right away)", "Les tâches terminées passent dans l'archive (0 = immédiatement)"),
+    ("Window", "Fenêtre"),
+    ("Window size", "Taille de la fenêtre"),
+    ("Applied the next time Errday starts", "Appliquée au prochain démarrage d'Errday"),
+    ("Keyboard shortcuts", "Raccourcis clavier"),
+    ("Rebind them under \"keymap\" in settings.json", "Modifiez-les sous « keymap » dans settings.json"),
+    ("Show shortcuts", "Afficher les raccourcis"),
+    // CalDAV sync
+    ("Syncing…", "Synchronisation…"),
+    ("Could not read the password from the system keyring: {error}", "Impossible de lire le mot de passe dans le trousseau du système : {error}"),
+    ("{time}: {sent} sent, {pulled} updated here, {removed} removed, {conflicts} conflicts", "{time} : {sent} envoyées, {pulled} mises à jour ici, {removed} retirées, {conflicts} conflits"),
+    ("{summary}. Stopped: {error}", "{summary}. Arrêt : {error}"),
+    // Matrix
+    ("Backlog", "En attente"),
+    ("UNSORTED MINDS", "IDÉES NON TRIÉES"),
+    ("Triage", "Tri"),
+    ("Grid", "Grille"),
+    ("Plot", "Nuage"),
+    ("Eisenhower matrix", "Matrice d'Eisenhower"),
+    ("DO FIRST", "À FAIRE"),
+    ("SCHEDULE", "PLANIFIER"),
+    ("DELEGATE", "DÉLÉGUER"),
+    ("DELETE", "SUPPRIMER"),
+    ("URGENT & IMPORTANT", "URGENT ET IMPORTANT"),
+    ("IMPORTANT, NOT URGENT", "IMPORTANT, PAS URGENT"),
+    ("URGENT, NOT IMPORTANT", "URGENT, PAS IMPORTANT"),
+    ("NEITHER", "NI L'UN NI L'AUTRE"),
+    ("{quadrant}: {count} tasks", "{quadrant} : {count} tâches"),
+    ("Click to set the WIP limit", "Cliquer pour fixer la limite de tâches en cours"),
+    ("Growing {weeks} weeks running ({counts}). Time to purge?", "En hausse depuis {weeks} semaines ({counts}). Temps de faire le ménage ?"),
+    ("Purge all", "Tout purger"),
+    ("Later", "Plus tard"),
+    ("Empty Sector", "Secteur vide"),
+    ("MORE IMPORTANT →", "PLUS IMPORTANT →"),
+    ("← MORE URGENT", "← PLUS URGENT"),
+    ("DUE {date}", "ÉCHÉANCE {date}"),
+    // Demote prompt
+    ("WIP LIMIT REACHED", "LIMITE ATTEINTE"),
+    ("{quadrant} is full", "{quadrant} est plein"),
+    ("Pick {count} task(s) to move down to {quadrant} to make room.", "Choisissez {count} tâche(s) à descendre vers {quadrant} pour faire de la place."),
+    ("Pick {count} task(s) to delete for good to make room.", "Choisissez {count} tâche(s) à supprimer définitivement pour faire de la place."),
+    ("This move stays {short} over the limit of {limit} even with every task there moved out.", "Ce déplacement dépasse encore la limite de {limit} de {short}, même en sortant toutes les tâches présentes."),
+    ("{count} more move(s) waiting after this one.", "{count} autre(s) déplacement(s) en attente après celui-ci."),
+    ("Move anyway", "Déplacer quand même"),
+    ("Demote", "Rétrograder"),
+    ("Purge", "Purger"),
+    ("{verb} {count} & move", "{verb} {count} et déplacer"),
+    // Triage
+    ("TRIAGE", "TRI"),
+    ("ONE TASK // ONE DECISION", "UNE TÂCHE // UNE DÉCISION"),
+    ("{done} / {total} PROCESSED", "{done} / {total} TRAITÉES"),
+    ("Skip", "Passer"),
+    ("Edit", "Modifier"),
+    ("Triage Remaining", "Trier le reste"),
+    ("Back to Matrix", "Retour à la matrice"),
+    ("Skipped", "Passées"),
+    ("Backlog Cleared", "File vidée"),
+    ("{count} TASKS PROCESSED", "{count} TÂCHES TRAITÉES"),
+    // Archive
+    ("ARCHIVE", "ARCHIVE"),
+    ("MISSION LOG // COMPLETED", "JOURNAL DE MISSION // TERMINÉES"),
+    ("Archive done tasks after", "Archiver les tâches faites après"),
+    ("Search titles, notes and #tags", "Rechercher dans les titres, notes et #tags"),
+    ("// ARCHIVE EMPTY", "// ARCHIVE VIDE"),
+    ("// NO MATCHING RECORDS", "// AUCUN RÉSULTAT"),
+    ("Task", "Tâche"),
+    ("Sector", "Secteur"),
+    ("Completed", "Terminée"),
+    ("Archived while still open", "Archivée encore ouverte"),
+    ("Reopen", "Rouvrir"),
+    // Command palette
+    ("Search tasks or type a command…", "Rechercher une tâche ou taper une commande…"),
+    ("// NO MATCHES", "// AUCUN RÉSULTAT"),
+    ("Navigate", "Naviguer"),
+    ("Open", "Ouvrir"),
+    ("Close", "Fermer"),
+    ("Command", "Commande"),
+    ("New task: {query}", "Nouvelle tâche : {query}"),
+    ("Archive", "Archive"),
+    ("Go to Brainstorm", "Aller au remue-méninges"),
+    ("Go to Matrix", "Aller à la matrice"),
+    ("Go to Triage", "Aller au tri"),
+    ("Go to Calendar", "Aller au calendrier"),
+    ("Go to Today (day view)", "Aller à aujourd'hui (vue jour)"),
+    ("Go to Month view", "Aller à la vue mois"),
+    ("Go to Agenda", "Aller à l'agenda"),
+    ("Go to Archive", "Aller à l'archive"),
+    ("Go to Settings", "Aller aux réglages"),
+    ("Go to Tutorial", "Aller au tutoriel"),
+    ("Export calendar (ICS)", "Exporter le calendrier (ICS)"),
+    ("Sync with CalDAV server", "Synchroniser avec le serveur CalDAV"),
+    // Cheat sheet
+    ("KEYBOARD", "CLAVIER"),
+    ("Shortcuts", "Raccourcis"),
+    ("Remap in settings.json → \"keymap\"", "À modifier dans settings.json → « keymap »"),
+    ("Navigation", "Navigation"),
+    ("Tasks", "Tâches"),
+    ("Matrix", "Matrice"),
+    ("New task", "Nouvelle tâche"),
+    ("Delete selected tasks", "Supprimer les tâches sélectionnées"),
+    ("Move selection to Do First", "Déplacer la sélection vers À faire"),
+    ("Move selection to Schedule", "Déplacer la sélection vers Planifier"),
+    ("Move selection to Delegate", "Déplacer la sélection vers Déléguer"),
+    ("Move selection to Delete", "Déplacer la sélection vers Supprimer"),
+    ("Move selection back to Brainstorm", "Renvoyer la sélection au remue-méninges"),
+    ("Calendar: previous week / day / month", "Calendrier : semaine / jour / mois précédent"),
+    ("Calendar: next week / day / month", "Calendrier : semaine / jour / mois suivant"),
+    ("Calendar: back to today", "Calendrier : retour à aujourd'hui"),
+    ("Search & commands", "Recherche et commandes"),
+    ("Show this cheat sheet", "Afficher cet aide-mémoire"),
+    // Sidebar
+    ("Mission Control", "Centre de contrôle"),
+    ("Matrix Protocol", "Protocole matrice"),
+    ("Perspectives", "Perspectives"),
+    ("New filter", "Nouveau filtre"),
+    ("+ New filter", "+ Nouveau filtre"),
+    ("Shortcuts (?)", "Raccourcis (?)"),
+    ("Settings", "Réglages"),
+    ("Tutorial", "Tutoriel"),
+    ("Flight Manual", "Manuel de vol"),
+    ("Credits", "Crédits"),
+    // Bulk action bar
+    ("{count} SELECTED", "{count} SÉLECTIONNÉE(S)"),
+    ("MOVE", "DÉPLACER"),
+    ("DEL", "DÉL."),
+    ("BIN", "CORB."),
+    ("INBOX", "ENTRÉE"),
+    ("#tag ↵", "#étiquette ↵"),
+    ("project ↵", "projet ↵"),
+    ("DUE", "ÉCHÉANCE"),
+    ("EST", "EST."),
+    ("min", "min"),
+    ("DONE", "TERMINÉ"),
+    ("Clear selection", "Vider la sélection"),
+    // Score assistant
+    ("PRIORITY ASSESSMENT", "ÉVALUATION DE PRIORITÉ"),
+    ("IMPORTANCE", "IMPORTANCE"),
+    ("URGENCY", "URGENCE"),
+    ("Does this move a key metric or goal?", "Est-ce que cela fait avancer un indicateur ou un objectif clé ?"),
+    ("No", "Non"),
+    ("A little", "Un peu"),
+    ("Clearly", "Clairement"),
+    ("It's the main lever", "C'est le levier principal"),
+    ("Who would notice if it never got done?", "Qui remarquerait si ce n'était jamais fait ?"),
+    ("Nobody", "Personne"),
+    ("Just me", "Moi seul"),
+    ("My team", "Mon équipe"),
+    ("Customers or investors", "Clients ou investisseurs"),
+    ("What happens if it slips a week?", "Que se passe-t-il si cela glisse d'une semaine ?"),
+    ("Nothing", "Rien"),
+    ("Minor annoyance", "Une petite gêne"),
+    ("Someone is blocked", "Quelqu'un est bloqué"),
+    ("A deadline is missed", "Une échéance est manquée"),
+    ("When is it actually due?", "Pour quand est-ce vraiment ?"),
+    ("No date", "Pas de date"),
+    ("This month", "Ce mois-ci"),
+    ("This week", "Cette semaine"),
+    ("Today or tomorrow", "Aujourd'hui ou demain"),
+    ("SUGGESTED SECTOR", "SECTEUR SUGGÉRÉ"),
+    ("URGENCY {urgency} // IMPORTANCE {importance}", "URGENCE {urgency} // IMPORTANCE {importance}"),
+    ("Answer every question to get a suggestion.", "Répondez à chaque question pour obtenir une suggestion."),
+    ("Save Scores", "Enregistrer les scores"),
+    ("Move to {quadrant}", "Déplacer vers {quadrant}"),
+    // Perspectives
+    ("// FILTER NOT FOUND", "// FILTRE INTROUVABLE"),
+    ("PERSPECTIVE // {count} TASKS", "PERSPECTIVE // {count} TÂCHE(S)"),
+    ("Done", "Terminé"),
+    ("Edit Filter", "Modifier le filtre"),
+    ("// NO TASKS MATCH THIS FILTER", "// AUCUNE TÂCHE NE CORRESPOND À CE FILTRE"),
+    ("NAME", "NOM"),
+    ("TEXT", "TEXTE"),
+    ("Words in the title or notes", "Mots du titre ou des notes"),
+    ("QUADRANTS", "QUADRANTS"),
+    ("(none picked = all)", "(aucun choisi = tous)"),
+    ("STATUS", "STATUT"),
+    ("Not done", "Non terminées"),
+    ("Any", "Toutes"),
+    ("TAGS", "ÉTIQUETTES"),
+    ("fundraising, hiring", "levée de fonds, recrutement"),
+    ("PROJECT", "PROJET"),
+    ("Any project", "Tous les projets"),
+    ("DATE", "DATE"),
+    ("Due date", "Date d'échéance"),
+    ("Scheduled date", "Date planifiée"),
+    ("Any time", "N'importe quand"),
+    ("Overdue", "En retard"),
+    ("Next N days", "N prochains jours"),
+    ("Between…", "Entre…"),
+];
+
+const EN_WEEKDAYS: [&str; 7] = ["Monday", "Tuesday", "Wednesday", "Thursday", "Friday", "Saturday", "Sunday"];
+const FR_WEEKDAYS: [&str; 7] = ["lundi", "mardi", "mercredi", "jeudi", "vendredi", "samedi", "dimanche"];
+const EN_WEEKDAYS_SHORT: [&str; 7] = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
+const FR_WEEKDAYS_SHORT: [&str; 7] = ["lun.", "mar.", "mer.", "jeu.", "ven.", "sam.", "dim."];
+const EN_MONTHS: [&str; 12] = ["January", "February", "March", "April", "May", "June", "July", "August", "September", "October", "November", "December"];
+const FR_MONTHS: [&str; 12] = ["janvier", "février", "mars", "avril", "mai", "juin", "juillet", "août", "septembre", "octobre", "novembre", "décembre"];
+const EN_MONTHS_SHORT: [&str; 12] = ["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec"];
+const FR_MONTHS_SHORT: [&str; 12] = ["janv.", "févr.", "mars", "avr.", "mai", "juin", "juil.", "août", "sept.", "oct.", "nov.", "déc."];
+
+/// I18N: The active language and clock, read from the settings (see SettingsState::i18n)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct I18n {
+    pub locale: Locale,
+    pub clock: Clock,
+}
+
+impl I18n {
+    /// TRANSLATE: The active language's text for an English message
+    pub fn t(&self, key: &'static str) -> &'static str {
+        let catalog = match self.locale {
+            Locale::En => return key,
+            Locale::Fr => FR,
+        };
+        catalog.iter().find(|(en, _)| *en == key).map(|(_, text)| *text).unwrap_or(key)
+    }
+
+    /// TRANSLATE + FILL: Like `t`, then replaces each {name} with its value
+    pub fn tf(&self, key: &'static str, values: &[(&str, &dyn std::fmt::Display)]) -> String {
+        values
+            .iter()
+            .fold(self.t(key).to_string(), |text, (name, value)| text.replace(&format!("{{{name}}}"), &value.to_string()))
+    }
+
+    /// "Monday" / "lundi"
+    pub fn weekday(&self, day: Weekday) -> &'static str {
+        let names = match self.locale {
+            Locale::En => &EN_WEEKDAYS,
+            Locale::Fr => &FR_WEEKDAYS,
+        };
+        names[day.num_days_from_monday() as usize]
+    }
+
+    /// "Mon" / "lun."
+    pub fn weekday_short(&self, day: Weekday) -> &'static str {
+        let names = match self.locale {
+            Locale::En => &EN_WEEKDAYS_SHORT,
+            Locale::Fr => &FR_WEEKDAYS_SHORT,
+        };
+        names[day.num_days_from_monday() as usize]
+    }
+
+    fn month_short(&self, month: u32) -> &'static str {
+        let names = match self.locale {
+            Locale::En => &EN_MONTHS_SHORT,
+            Locale::Fr => &FR_MONTHS_SHORT,
+        };
+        names[(month as usize + 11) % 12]
+    }
+
+    /// "October 2026" / "octobre 2026"
+    pub fn month_year(&self, date: NaiveDate) -> String {
+        let names = match self.locale {
+            Locale::En => &EN_MONTHS,
+            Locale::Fr => &FR_MONTHS,
+        };
+        format!("{} {}", names[date.month0() as usize], date.year())
+    }
+
+    /// "Mar 5" / "5 mars"
+    pub fn day_month(&self, date: NaiveDate) -> String {
+        match self.locale {
+            Locale::En => format!("{} {}", self.month_short(date.month()), date.day()),
+            Locale::Fr => format!("{} {}", date.day(), self.month_short(date.month())),
+        }
+    }
+
+    /// "Mar 5, 2026" / "5 mars 2026"
+    pub fn date(&self, date: NaiveDate) -> String {
+        match self.locale {
+            Locale::En => format!("{}, {}", self.day_month(date), date.year()),
+            Locale::Fr => format!("{} {}", self.day_month(date), date.year()),
+        }
+    }
+
+    /// "Mar 2 – Mar 8, 2026" / "2 mars – 8 mars 2026"
+    pub fn date_range(&self, start: NaiveDate, end: NaiveDate) -> String {
+        if start.year() == end.year() {
+            format!("{} – {}", self.day_month(start), self.date(end))
+        } else {
+            format!("{} – {}", self.date(start), self.date(end))
+        }
+    }
+
+    /// "2:30 PM" / "14:30" (French writes "14 h 30")
+    pub fn time(&self, time: &impl Timelike) -> String {
+        match (self.clock, self.locale) {
+            (Clock::H12, _) => format!("{}:{:02} {}", hour12(time.hour()), time.minute(), meridiem(time.hour())),
+            (Clock::H24, Locale::En) => format!("{:02}:{:02}", time.hour(), time.minute()),
+            (Clock::H24, Locale::Fr) => format!("{} h {:02}", time.hour(), time.minute()),
+        }
+    }
+
+    /// A block's span: "2:30 – 3:30 PM" (the AM/PM is only written twice when it changes)
+    pub fn time_range(&self, start: &impl Timelike, end: &impl Timelike) -> String {
+        if self.clock == Clock::H12 && meridiem(start.hour()) == meridiem(end.hour()) {
+            format!("{}:{:02} – {}", hour12(start.hour()), start.minute(), self.time(end))
+        } else {
+            format!("{} – {}", self.time(start), self.time(end))
+        }
+    }
+
+    /// Hour label for the calendar gutter: "2 PM" / "14:00" / "14 h"
+    pub fn hour(&self, hour: u32) -> String {
+        match (self.clock, self.locale) {
+            (Clock::H12, _) => format!("{} {}", hour12(hour), meridiem(hour)),
+            (Clock::H24, Locale::En) => format!("{hour:02}:00"),
+            (Clock::H24, Locale::Fr) => format!("{hour} h"),
+        }
+    }
+
+    /// "Mar 5, 2026 2:30 PM" / "5 mars 2026 14 h 30"
+    pub fn date_time(&self, at: &DateTime<Local>) -> String {
+        format!("{} {}", self.date(at.date_naive()), self.time(at))
+    }
+}
+
+fn hour12(hour: u32) -> u32 {
+    match hour % 12 {
+        0 => 12,
+        h => h,
+    }
+}
+
+fn meridiem(hour: u32) -> &'static str {
+    if hour % 24 < 12 { "AM" } else { "PM" }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveTime;
+
+    const EN_12: I18n = I18n { locale: Locale::En, clock: Clock::H12 };
+    const EN_24: I18n = I18n { locale: Locale::En, clock: Clock::H24 };
+    const FR_24: I18n = I18n { locale: Locale::Fr, clock: Clock::H24 };
+
+    fn at(hour: u32, minute: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(hour, minute, 0).unwrap()
+    }
+
+    fn day(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn messages_come_from_the_active_catalog() {
+        assert_eq!(EN_12.t("Today"), "Today");
+        assert_eq!(FR_24.t("Today"), "Aujourd'hui");
+        // Untranslated messages fall back to the English key
+        assert_eq!(FR_24.t("Not in any catalog"), "Not in any catalog");
+    }
+
+    #[test]
+    fn placeholders_are_filled_after_translating() {
+        let values: &[(&str, &dyn std::fmt::Display)] = &[("status", &"Planifier"), ("notes", &"Rien")];
+        assert_eq!(
+            FR_24.tf("Eisenhower Status: {status}\n\nNotes:\n{notes}", values),
+            "Statut Eisenhower : Planifier\n\nNotes :\nRien"
+        );
+        // Unknown names are left as they are
+        assert_eq!(EN_12.tf("{count} left", &[("other", &3)]), "{count} left");
+    }
+
+    #[test]
+    fn times_follow_the_clock_and_language() {
+        assert_eq!(EN_12.time(&at(14, 30)), "2:30 PM");
+        assert_eq!(EN_12.time(&at(0, 5)), "12:05 AM");
+        assert_eq!(EN_24.time(&at(9, 5)), "09:05");
+        assert_eq!(FR_24.time(&at(14, 30)), "14 h 30");
+
+        assert_eq!(EN_12.hour(12), "12 PM");
+        assert_eq!(EN_24.hour(7), "07:00");
+        assert_eq!(FR_24.hour(7), "7 h");
+    }
+
+    #[test]
+    fn time_ranges_only_repeat_the_meridiem_when_it_changes() {
+        assert_eq!(EN_12.time_range(&at(14, 30), &at(15, 30)), "2:30 – 3:30 PM");
+        assert_eq!(EN_12.time_range(&at(11, 0), &at(13, 0)), "11:00 AM – 1:00 PM");
+        assert_eq!(EN_24.time_range(&at(11, 0), &at(13, 0)), "11:00 – 13:00");
+        assert_eq!(FR_24.time_range(&at(11, 0), &at(13, 0)), "11 h 00 – 13 h 00");
+    }
+
+    #[test]
+    fn date_ranges_write_the_year_once_when_they_share_it() {
+        assert_eq!(EN_12.date_range(day(2026, 3, 2), day(2026, 3, 8)), "Mar 2 – Mar 8, 2026");
+        assert_eq!(FR_24.date_range(day(2026, 3, 2), day(2026, 3, 8)), "2 mars – 8 mars 2026");
+        assert_eq!(EN_12.date_range(day(2026, 12, 28), day(2027, 1, 3)), "Dec 28, 2026 – Jan 3, 2027");
+    }
+
+    /// Every English key handed to `t`/`tf` as a literal somewhere under src/
+    fn literal_keys() -> Vec<(String, String)> {
+        let mut files = vec![std::path::PathBuf::from(concat!(env!("CARGO_MANIFEST_DIR"), "/src"))];
+        let mut keys = Vec::new();
+        while let Some(path) = files.pop() {
+            if path.is_dir() {
+                files.extend(std::fs::read_dir(&path).unwrap().map(|e| e.unwrap().path()));
+                continue;
+            }
+            // This file's own tests use keys that are meant to be missing
+            if path.extension().is_none_or(|e| e != "rs") || path.ends_with("i18n.rs") {
+                continue;
+            }
+            let source = std::fs::read_to_string(&path).unwrap();
+            for call in [".t(", ".tf("] {
+                for (at, _) in source.match_indices(call) {
+                    let line_start = source[..at].rfind('\n').map_or(0, |i| i + 1);
+                    if source[line_start..at].trim_start().starts_with("//") {
+                        continue;
+                    }
+                    if let Some(key) = string_argument(source[at + call.len()..].trim_start()) {
+                        keys.push((key, path.strip_prefix(env!("CARGO_MANIFEST_DIR")).unwrap().display().to_string()));
+                    }
+                }
+            }
+        }
+        keys
+    }
+
+    /// Reads a string literal at the start of `rest`, plain (`"..."`) or escaped inside an rsx format string (`\"...\"`)
+    fn string_argument(rest: &str) -> Option<String> {
+        let (body, close) = if let Some(body) = rest.strip_prefix("\\\"") {
+            (body, "\\\"")
+        } else {
+            (rest.strip_prefix('"')?, "\"")
+        };
+        let mut key = String::new();
+        let mut chars = body.char_indices();
+        while let Some((i, c)) = chars.next() {
+            if body[i..].starts_with(close) {
+                return Some(key);
+            }
+            if c == '\\' {
+                match chars.next()?.1 {
+                    'n' => key.push('\n'),
+                    't' => key.push('\t'),
+                    other => key.push(other),
+                }
+            } else {
+                key.push(c);
+            }
+        }
+        None
+    }
+
+    #[test]
+    fn every_message_has_a_french_translation() {
+        let mut keys = literal_keys();
+        // Keys that reach `t` through a variable rather than a literal
+        let indirect = crate::models::Quadrant::ALL.iter().map(|q| q.label())
+            .chain(crate::keymap::Action::ALL.iter().flat_map(|a| [a.label(), a.group()]))
+            .chain([crate::views::calendar::PICKED_UP])
+            // Command palette
+            .chain(["Go to Brainstorm", "Go to Matrix", "Go to Triage", "Go to Calendar", "Go to Today (day view)", "Go to Month view", "Go to Agenda", "Go to Archive", "Go to Settings", "Go to Tutorial"])
+            // Matrix headers and view switcher, triage summary, WIP prompt
+            .chain(["DO FIRST", "URGENT & IMPORTANT", "SCHEDULE", "IMPORTANT, NOT URGENT", "DELEGATE", "URGENT, NOT IMPORTANT", "DELETE", "NEITHER"])
+            .chain(["Grid", "Plot", "Skipped", "Demote", "Purge", "Inbox"])
+            // Bulk action bar move buttons
+            .chain(["DO", "SCH", "DEL", "BIN", "INBOX"])
+            // Score assistant
+            .chain(["IMPORTANCE", "URGENCY"])
+            .chain(["Does this move a key metric or goal?", "No", "A little", "Clearly", "It's the main lever"])
+            .chain(["Who would notice if it never got done?", "Nobody", "Just me", "My team", "Customers or investors"])
+            .chain(["What happens if it slips a week?", "Nothing", "Minor annoyance", "Someone is blocked", "A deadline is missed"])
+            .chain(["When is it actually due?", "No date", "This month", "This week", "Today or tomorrow"])
+            // ICS export descriptions
+            .chain(["Do First (Urgent & Important)", "Schedule (Important, Not Urgent)", "Delegate (Urgent, Not Important)", "Delete (Neither)"]);
+        keys.extend(indirect.map(|key| (key.to_string(), "indirect".to_string())));
+
+        let missing: Vec<String> = keys
+            .into_iter()
+            .filter(|(key, _)| !key.is_empty() && !FR.iter().any(|(en, _)| en == key))
+            .map(|(key, file)| format!("{file}: {key:?}"))
+            .collect();
+        assert!(missing.is_empty(), "untranslated messages:\n{}", missing.join("\n"));
+    }
+}
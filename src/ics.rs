@@ -0,0 +1,848 @@
+// ICS: Reading calendars exported from Google, Outlook or Apple Calendar.
+// Timed events become read-only busy blocks, or events and to-dos become tasks.
+// For busy time, recurring events (RRULE, RDATE, EXDATE and moved occurrences) are expanded in
+// the event's own wall-clock time and then converted with the VTIMEZONE definitions that ship
+// inside the file, so a 9:00 meeting stays at 9:00 across daylight-saving changes.
+// A TZID the file does not define is read as local time.
+
+use std::collections::{HashMap, HashSet};
+use chrono::{DateTime, Datelike, Duration, Local, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc, Weekday};
+use icalendar::parser::{read_calendar, unfold, Calendar, Component, Property};
+use crate::models::{BusyBlock, Quadrant, Task, TaskStatus};
+
+/// How far back and ahead events are expanded, in days
+const PAST_DAYS: i64 = 31;
+const AHEAD_DAYS: i64 = 366;
+/// Upper bound on the periods walked for one rule, so a broken rule cannot spin forever
+const MAX_PERIODS: i64 = 20_000;
+/// Largest INTERVAL taken as written (1000 years for a yearly rule); bigger ones are clamped
+const MAX_INTERVAL: i64 = 1_000;
+/// Name CATEGORIES is read under, so the parser leaves its escapes alone (see `keep_raw_categories`)
+const RAW_CATEGORIES: &str = "X-RAW-CATEGORIES";
+
+/// IMPORTED CALENDAR: The calendar's own name (X-WR-CALNAME, if any) and its busy blocks
+pub struct BusyImport {
+    pub name: Option<String>,
+    pub blocks: Vec<BusyBlock>,
+}
+
+/// READ BUSY TIME: Every timed event occurrence from PAST_DAYS before `now` to AHEAD_DAYS after it,
+/// labelled with `source`. All-day, free (TRANSP:TRANSPARENT) and cancelled events are left out.
+pub fn read_busy(content: &str, source: &str, now: DateTime<Local>) -> Result<BusyImport, String> {
+    let unfolded = unfold(content);
+    let calendar = read_calendar(&unfolded)?;
+    let window_start = now.with_timezone(&Utc) - Duration::days(PAST_DAYS);
+    let window_end = now.with_timezone(&Utc) + Duration::days(AHEAD_DAYS);
+
+    let name = calendar.properties.iter().find(|p| is(p, "X-WR-CALNAME")).map(text);
+    let zones = zones_of(&calendar);
+    let events: Vec<&Component> = calendar
+        .components
+        .iter()
+        .filter(|c| c.name.as_str().eq_ignore_ascii_case("VEVENT"))
+        .filter(|c| !value(c, "STATUS").is_some_and(|s| s.eq_ignore_ascii_case("CANCELLED")))
+        .collect();
+
+    // Occurrences moved or edited on their own (RECURRENCE-ID) replace the rule's instance
+    let mut moved: HashSet<(String, DateTime<Utc>)> = HashSet::new();
+    for event in &events {
+        if let (Some(uid), Some(prop)) = (value(event, "UID"), event.find_prop("RECURRENCE-ID")) {
+            for stamp in stamps(prop) {
+                if let Some(at) = stamp.to_utc(&zones) {
+                    moved.insert((uid.clone(), at));
+                }
+            }
+        }
+    }
+
+    let mut blocks = Vec::new();
+    for event in events {
+        // Free time does not block anything
+        if value(event, "TRANSP").is_some_and(|t| t.eq_ignore_ascii_case("TRANSPARENT")) {
+            continue;
+        }
+        let Some(start) = event.find_prop("DTSTART").and_then(|p| stamps(p).into_iter().next()) else { continue };
+        // All-day events (holidays, birthdays) are not meetings
+        if let Stamp::Date(_) = start {
+            continue;
+        }
+        let Some(first) = start.to_utc(&zones) else { continue };
+        let length = match (event.find_prop("DTEND"), value(event, "DURATION")) {
+            (Some(end), _) => stamps(end).into_iter().next().and_then(|e| e.to_utc(&zones)).map(|e| e - first),
+            (None, Some(duration)) => parse_duration(&duration),
+            (None, None) => None,
+        };
+        let Some(length) = length.filter(|l| *l > Duration::zero()) else { continue };
+
+        let uid = value(event, "UID").unwrap_or_else(|| format!("{first}"));
+        let title = event.find_prop("SUMMARY").map(text).unwrap_or_else(|| "Busy".to_string());
+        let is_override = event.find_prop("RECURRENCE-ID").is_some();
+        let excluded: HashSet<DateTime<Utc>> = event
+            .properties
+            .iter()
+            .filter(|p| is(p, "EXDATE"))
+            .flat_map(stamps)
+            .filter_map(|s| s.to_utc(&zones))
+            .collect();
+
+        // Instances in the event's wall-clock time: DTSTART, the rule's and any RDATEs
+        let wall_start = start.wall();
+        let mut walls = vec![wall_start];
+        if let Some(rule) = value(event, "RRULE").filter(|_| !is_override).and_then(|r| Rule::parse(&r)) {
+            let limit = wall_start.max((window_end + Duration::days(1)).naive_utc());
+            let until = rule.until.as_ref().map(|u| start.wall_of(u, &zones));
+            walls.extend(expand(&rule, wall_start, until.map_or(limit, |u| u.min(limit))));
+        }
+        walls.extend(event.properties.iter().filter(|p| is(p, "RDATE")).flat_map(stamps).map(|s| start.wall_of(&s, &zones)));
+        walls.sort();
+        walls.dedup();
+
+        for wall in walls {
+            let Some(at) = start.with_wall(wall).to_utc(&zones) else { continue };
+            let end = at + length;
+            if end <= window_start || at >= window_end || excluded.contains(&at) {
+                continue;
+            }
+            if !is_override && moved.contains(&(uid.clone(), at)) {
+                continue;
+            }
+            blocks.push(BusyBlock {
+                source: source.to_string(),
+                uid: uid.clone(),
+                title: title.clone(),
+                start: at.with_timezone(&Local),
+                end: end.with_timezone(&Local),
+            });
+        }
+    }
+    blocks.sort_by_key(|b| b.start);
+    Ok(BusyImport { name, blocks })
+}
+
+/// IMPORTED ITEM: A VEVENT or VTODO on its way to becoming a task
+#[derive(Debug, Clone, PartialEq)]
+pub struct TaskItem {
+    pub uid: String,
+    pub kind: ItemKind,
+    pub title: String,
+    pub description: Option<String>,
+    pub start: Option<DateTime<Local>>, // Timed events and to-dos only
+    pub end: Option<DateTime<Local>>,
+    pub due: Option<NaiveDate>,         // All-day events and to-do deadlines
+    pub done: bool,
+    pub tags: Vec<String>,              // From CATEGORIES
+    pub modified: Option<DateTime<Local>>, // LAST-MODIFIED, when the file has it
+}
+
+/// Which kind of component an item came from
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ItemKind {
+    Event,
+    Todo,
+}
+
+impl TaskItem {
+    /// The task this item becomes, placed in `quadrant`
+    pub fn to_task(&self, quadrant: Quadrant) -> Task {
+        let mut task = Task::new(self.title.clone());
+        task.description = self.description.clone();
+        task.quadrant = quadrant;
+        task.scheduled_start = self.start;
+        task.scheduled_end = self.end;
+        task.due = self.due;
+        task.tags = self.tags.clone();
+        task.ics_uid = Some(self.uid.clone());
+        if self.done {
+            task.set_status(TaskStatus::Done);
+        }
+        task
+    }
+}
+
+/// READ ITEMS: The events and to-dos of a calendar file, once each.
+/// Recurring items come in once (as their first instance); moved instances and cancelled items are left out.
+pub fn read_items(content: &str) -> Result<Vec<TaskItem>, String> {
+    let unfolded = keep_raw_categories(&unfold(content));
+    let calendar = read_calendar(&unfolded)?;
+    let zones = zones_of(&calendar);
+    let local = |stamp: &Stamp| stamp.to_utc(&zones).map(|at| at.with_timezone(&Local));
+
+    let mut items: Vec<TaskItem> = Vec::new();
+    for component in &calendar.components {
+        let kind = match component.name.as_str().to_ascii_uppercase().as_str() {
+            "VEVENT" => ItemKind::Event,
+            "VTODO" => ItemKind::Todo,
+            _ => continue,
+        };
+        let status = value(component, "STATUS").unwrap_or_default().to_ascii_uppercase();
+        if status == "CANCELLED" || component.find_prop("RECURRENCE-ID").is_some() {
+            continue;
+        }
+        let stamp = |name: &str| component.find_prop(name).and_then(|p| stamps(p).into_iter().next());
+        let title = component.find_prop("SUMMARY").map(text).filter(|t| !t.trim().is_empty()).unwrap_or_else(|| "Untitled".to_string());
+
+        let (mut start, mut end, mut due) = (None, None, None);
+        match stamp("DTSTART") {
+            Some(Stamp::Date(day)) => due = Some(day),
+            Some(timed) => start = local(&timed),
+            None => {}
+        }
+        match (kind, stamp("DTEND"), stamp("DUE")) {
+            (ItemKind::Event, Some(stop), _) if start.is_some() => end = local(&stop),
+            (ItemKind::Todo, _, Some(Stamp::Date(day))) => due = Some(day),
+            (ItemKind::Todo, _, Some(timed)) => {
+                let at = local(&timed);
+                due = at.map(|t| t.date_naive());
+                // A to-do with a start and a timed due date is a block of work
+                if start.is_some() {
+                    end = at;
+                }
+            }
+            _ => {}
+        }
+        if let (Some(from), None, Some(length)) = (start, end, value(component, "DURATION").and_then(|d| parse_duration(&d))) {
+            end = Some(from + length);
+        }
+        let uid = value(component, "UID").unwrap_or_else(|| format!("{title}@{}", start.map(|s| s.to_rfc3339()).unwrap_or_default()));
+        if items.iter().any(|i| i.uid == uid) {
+            continue;
+        }
+        items.push(TaskItem {
+            uid,
+            kind,
+            title,
+            description: component.find_prop("DESCRIPTION").map(text).filter(|d| !d.trim().is_empty()),
+            start,
+            end: end.filter(|e| start.is_some_and(|s| *e > s)),
+            due,
+            done: status == "COMPLETED" || component.find_prop("COMPLETED").is_some(),
+            // Our own exports keep the quadrant out of CATEGORIES (see export::QUADRANT_PROPERTY)
+            tags: component
+                .properties
+                .iter()
+                .filter(|p| is(p, RAW_CATEGORIES))
+                .flat_map(text_list)
+                .collect(),
+            modified: stamp("LAST-MODIFIED").and_then(|at| local(&at)),
+        });
+    }
+    Ok(items)
+}
+
+// ─── PROPERTY VALUES ─────────────────────────────────────────────────────────
+
+/// VTIMEZONE definitions by TZID
+fn zones_of(calendar: &Calendar) -> HashMap<String, Zone> {
+    calendar
+        .components
+        .iter()
+        .filter(|c| c.name.as_str().eq_ignore_ascii_case("VTIMEZONE"))
+        .filter_map(|c| Some((value(c, "TZID")?, Zone::read(c))))
+        .collect()
+}
+
+fn is(prop: &Property, name: &str) -> bool {
+    prop.name.as_str().eq_ignore_ascii_case(name)
+}
+
+/// Raw value of a component's first `name` property
+fn value(component: &Component, name: &str) -> Option<String> {
+    component.properties.iter().find(|p| is(p, name)).map(|p| p.val.as_str().trim().to_string())
+}
+
+/// A TEXT value (the parser resolves the escapes of standard properties, so only X- ones need it here)
+fn text(prop: &Property) -> String {
+    match prop.name.as_str().starts_with("X-") {
+        true => prop.val.clone().unescape_text().as_str().to_string(),
+        false => prop.val.as_str().to_string(),
+    }
+}
+
+/// A comma-separated TEXT list (CATEGORIES), split on the unescaped commas of the raw value so
+/// that "R\,D" stays one category; see `keep_raw_categories`
+fn text_list(prop: &Property) -> Vec<String> {
+    let mut parts = vec![String::new()];
+    let mut chars = prop.val.as_str().chars();
+    while let Some(c) = chars.next() {
+        match c {
+            ',' => parts.push(String::new()),
+            '\\' => match chars.next() {
+                Some('n' | 'N') => parts.last_mut().unwrap().push('\n'),
+                Some(escaped) => parts.last_mut().unwrap().push(escaped),
+                None => {}
+            },
+            c => parts.last_mut().unwrap().push(c),
+        }
+    }
+    parts.into_iter().map(|part| part.trim().to_string()).filter(|part| !part.is_empty()).collect()
+}
+
+/// Renames CATEGORIES lines to RAW_CATEGORIES before parsing. The parser unescapes the values of
+/// standard TEXT properties, after which an escaped comma can't be told from a separator.
+fn keep_raw_categories(unfolded: &str) -> String {
+    unfolded
+        .split_inclusive('\n')
+        .map(|line| {
+            let name = "CATEGORIES".len();
+            let categories = line.get(..name).is_some_and(|n| n.eq_ignore_ascii_case("CATEGORIES"))
+                && matches!(line.as_bytes().get(name), Some(b':' | b';'));
+            match categories {
+                true => format!("{RAW_CATEGORIES}{}", &line[name..]),
+                false => line.to_string(),
+            }
+        })
+        .collect()
+}
+
+fn param(prop: &Property, key: &str) -> Option<String> {
+    prop.params
+        .iter()
+        .find(|p| p.key.as_str().eq_ignore_ascii_case(key))
+        .and_then(|p| p.val.as_ref())
+        .map(|v| v.as_str().trim_matches('"').to_string())
+}
+
+/// A date or date-time as written in the file
+#[derive(Debug, Clone, PartialEq)]
+enum Stamp {
+    Utc(NaiveDateTime),              // 20260105T140000Z
+    Zoned(NaiveDateTime, String),    // TZID=Europe/Paris:20260105T150000
+    Floating(NaiveDateTime),         // 20260105T150000, the reader's local time
+    Date(NaiveDate),                 // VALUE=DATE:20260105
+}
+
+/// Every value of a date property (EXDATE and RDATE may list several, comma separated)
+fn stamps(prop: &Property) -> Vec<Stamp> {
+    let tzid = param(prop, "TZID");
+    prop.val
+        .as_str()
+        .split(',')
+        .filter_map(|raw| {
+            let raw = raw.trim();
+            if let Ok(date) = NaiveDate::parse_from_str(raw, "%Y%m%d") {
+                return Some(Stamp::Date(date));
+            }
+            let at = NaiveDateTime::parse_from_str(raw.trim_end_matches('Z'), "%Y%m%dT%H%M%S").ok()?;
+            Some(match (&tzid, raw.ends_with('Z')) {
+                (_, true) => Stamp::Utc(at),
+                (Some(tzid), false) => Stamp::Zoned(at, tzid.clone()),
+                (None, false) => Stamp::Floating(at),
+            })
+        })
+        .collect()
+}
+
+impl Stamp {
+    /// The wall-clock reading (midnight for a date)
+    fn wall(&self) -> NaiveDateTime {
+        match self {
+            Stamp::Utc(at) | Stamp::Zoned(at, _) | Stamp::Floating(at) => *at,
+            Stamp::Date(date) => date.and_time(NaiveTime::MIN),
+        }
+    }
+
+    /// Another wall-clock reading in the same time zone as this one
+    fn with_wall(&self, wall: NaiveDateTime) -> Stamp {
+        match self {
+            Stamp::Utc(_) => Stamp::Utc(wall),
+            Stamp::Zoned(_, tzid) => Stamp::Zoned(wall, tzid.clone()),
+            Stamp::Floating(_) | Stamp::Date(_) => Stamp::Floating(wall),
+        }
+    }
+
+    /// `other` read on this stamp's wall clock (e.g. a UTC UNTIL for an event in New York time)
+    fn wall_of(&self, other: &Stamp, zones: &HashMap<String, Zone>) -> NaiveDateTime {
+        if std::mem::discriminant(self) == std::mem::discriminant(other) {
+            return other.wall();
+        }
+        let Some(at) = other.to_utc(zones) else { return other.wall() };
+        match self {
+            Stamp::Utc(_) => at.naive_utc(),
+            Stamp::Zoned(_, tzid) => match zones.get(tzid) {
+                Some(zone) => at.naive_utc() + Duration::seconds(zone.offset_at(at.naive_utc()) as i64),
+                None if is_utc_name(tzid) => at.naive_utc(),
+                None => at.with_timezone(&Local).naive_local(),
+            },
+            Stamp::Floating(_) | Stamp::Date(_) => at.with_timezone(&Local).naive_local(),
+        }
+    }
+
+    /// The instant this stamp stands for
+    fn to_utc(&self, zones: &HashMap<String, Zone>) -> Option<DateTime<Utc>> {
+        let local = |at: NaiveDateTime| at.and_local_timezone(Local).earliest().map(|t| t.with_timezone(&Utc));
+        match self {
+            Stamp::Utc(at) => Some(Utc.from_utc_datetime(at)),
+            Stamp::Zoned(at, tzid) => match zones.get(tzid) {
+                Some(zone) => Some(Utc.from_utc_datetime(&(*at - Duration::seconds(zone.offset_at(*at) as i64)))),
+                None if is_utc_name(tzid) => Some(Utc.from_utc_datetime(at)),
+                None => local(*at),
+            },
+            Stamp::Floating(at) => local(*at),
+            Stamp::Date(date) => local(date.and_time(NaiveTime::MIN)),
+        }
+    }
+}
+
+fn is_utc_name(tzid: &str) -> bool {
+    matches!(tzid.to_ascii_uppercase().as_str(), "UTC" | "GMT" | "Z" | "ETC/UTC" | "ETC/GMT")
+}
+
+/// ISO 8601 duration such as PT1H30M, P1D or -PT15M
+fn parse_duration(value: &str) -> Option<Duration> {
+    let (sign, rest) = match value.strip_prefix('-') {
+        Some(rest) => (-1, rest),
+        None => (1, value.strip_prefix('+').unwrap_or(value)),
+    };
+    let mut seconds = 0i64;
+    let mut number = String::new();
+    let mut in_time = false;
+    for c in rest.strip_prefix('P')?.chars() {
+        match c {
+            'T' => in_time = true,
+            '0'..='9' => number.push(c),
+            unit => {
+                let n: i64 = number.parse().ok()?;
+                number.clear();
+                let unit_seconds = match (unit, in_time) {
+                    ('W', false) => 7 * 86_400,
+                    ('D', false) => 86_400,
+                    ('H', true) => 3_600,
+                    ('M', true) => 60,
+                    ('S', true) => 1,
+                    _ => return None,
+                };
+                // Absurd lengths (P999999999999D) are rejected rather than overflowing
+                seconds = seconds.checked_add(n.checked_mul(unit_seconds)?)?;
+            }
+        }
+    }
+    Duration::try_seconds(sign * seconds)
+}
+
+/// UTC offset such as +0100, -0500 or +053000, in seconds
+fn parse_offset(value: &str) -> Option<i32> {
+    let sign = if value.starts_with('-') { -1 } else { 1 };
+    let digits = value.trim_start_matches(['+', '-']);
+    let part = |range: std::ops::Range<usize>| digits.get(range).map_or(Some(0), |d| d.parse::<i32>().ok());
+    Some(sign * (part(0..2)? * 3_600 + part(2..4)? * 60 + part(4..6)?))
+}
+
+// ─── TIME ZONES ──────────────────────────────────────────────────────────────
+
+/// One STANDARD or DAYLIGHT part of a VTIMEZONE: from its onsets on, the offset is `offset_to`
+struct Observance {
+    start: NaiveDateTime,
+    offset_from: i32,
+    offset_to: i32,
+    rule: Option<Rule>,
+    rdates: Vec<NaiveDateTime>,
+}
+
+/// A VTIMEZONE definition
+struct Zone {
+    observances: Vec<Observance>,
+}
+
+impl Zone {
+    fn read(component: &Component) -> Zone {
+        let observances = component
+            .components
+            .iter()
+            .filter_map(|part| {
+                let start = part.find_prop("DTSTART").and_then(|p| stamps(p).into_iter().next())?.wall();
+                Some(Observance {
+                    start,
+                    offset_from: parse_offset(&value(part, "TZOFFSETFROM")?)?,
+                    offset_to: parse_offset(&value(part, "TZOFFSETTO")?)?,
+                    rule: value(part, "RRULE").and_then(|r| Rule::parse(&r)),
+                    rdates: part.properties.iter().filter(|p| is(p, "RDATE")).flat_map(stamps).map(|s| s.wall()).collect(),
+                })
+            })
+            .collect();
+        Zone { observances }
+    }
+
+    /// UTC offset in effect at a wall-clock time: that of the observance with the latest onset before it
+    fn offset_at(&self, wall: NaiveDateTime) -> i32 {
+        let latest = self
+            .observances
+            .iter()
+            .filter_map(|o| {
+                let mut onsets = vec![o.start];
+                onsets.extend(o.rdates.iter().copied());
+                if let Some(rule) = &o.rule {
+                    let limit = rule.until.as_ref().map_or(wall, |u| u.wall().min(wall));
+                    onsets.extend(expand(rule, o.start, limit));
+                }
+                onsets.into_iter().filter(|t| *t <= wall).max().map(|t| (t, o.offset_to))
+            })
+            .max_by_key(|(onset, _)| *onset);
+        match latest {
+            Some((_, offset)) => offset,
+            // Before the first onset: the offset that observance came from
+            None => self.observances.iter().min_by_key(|o| o.start).map_or(0, |o| o.offset_from),
+        }
+    }
+}
+
+// ─── RECURRENCE RULES ────────────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Freq {
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+/// The parts of an RRULE the expansion understands
+#[derive(Debug, Clone, PartialEq)]
+struct Rule {
+    freq: Freq,
+    interval: i64,
+    count: Option<usize>,
+    until: Option<Stamp>,
+    by_day: Vec<(i32, Weekday)>, // (ordinal, day): 0 = every such day, 2 = the second, -1 = the last
+    by_month_day: Vec<i32>,      // Negative counts from the end of the month
+    by_month: Vec<u32>,
+    week_start: Weekday,
+}
+
+impl Rule {
+    fn parse(value: &str) -> Option<Rule> {
+        let mut rule = Rule {
+            freq: Freq::Daily,
+            interval: 1,
+            count: None,
+            until: None,
+            by_day: Vec::new(),
+            by_month_day: Vec::new(),
+            by_month: Vec::new(),
+            week_start: Weekday::Mon,
+        };
+        let mut freq = None;
+        for part in value.split(';') {
+            let Some((key, val)) = part.split_once('=') else { continue };
+            let list = || val.split(',').map(str::trim);
+            match key.trim().to_ascii_uppercase().as_str() {
+                "FREQ" => {
+                    freq = match val.trim().to_ascii_uppercase().as_str() {
+                        "DAILY" => Some(Freq::Daily),
+                        "WEEKLY" => Some(Freq::Weekly),
+                        "MONTHLY" => Some(Freq::Monthly),
+                        "YEARLY" => Some(Freq::Yearly),
+                        _ => None, // HOURLY and finer are not meetings
+                    }
+                }
+                "INTERVAL" => rule.interval = val.trim().parse::<i64>().ok()?.clamp(1, MAX_INTERVAL),
+                "COUNT" => rule.count = val.trim().parse().ok(),
+                "UNTIL" => rule.until = stamps(&Property::new_ref("UNTIL", val)).into_iter().next(),
+                "BYDAY" => rule.by_day = list().filter_map(parse_by_day).collect(),
+                "BYMONTHDAY" => rule.by_month_day = list().filter_map(|d| d.parse().ok()).collect(),
+                "BYMONTH" => rule.by_month = list().filter_map(|m| m.parse().ok()).collect(),
+                "WKST" => rule.week_start = parse_weekday(val.trim())?,
+                _ => {}
+            }
+        }
+        rule.freq = freq?;
+        Some(rule)
+    }
+}
+
+/// "MO", "2TU", "-1FR"
+fn parse_by_day(value: &str) -> Option<(i32, Weekday)> {
+    let split = value.len().checked_sub(2)?;
+    // `get` rather than slicing: the split can land inside a multi-byte character ("1ÉX")
+    let ordinal = match value.get(..split)? {
+        "" => 0,
+        n => n.trim_start_matches('+').parse().ok()?,
+    };
+    Some((ordinal, parse_weekday(value.get(split..)?)?))
+}
+
+fn parse_weekday(value: &str) -> Option<Weekday> {
+    Some(match value.to_ascii_uppercase().as_str() {
+        "MO" => Weekday::Mon,
+        "TU" => Weekday::Tue,
+        "WE" => Weekday::Wed,
+        "TH" => Weekday::Thu,
+        "FR" => Weekday::Fri,
+        "SA" => Weekday::Sat,
+        "SU" => Weekday::Sun,
+        _ => return None,
+    })
+}
+
+/// Instances of `rule` from `start` up to `limit` (wall-clock times; `start` itself is included
+/// when it matches). COUNT is counted from `start`, so it holds even when `limit` cuts earlier.
+fn expand(rule: &Rule, start: NaiveDateTime, limit: NaiveDateTime) -> Vec<NaiveDateTime> {
+    let time = start.time();
+    let mut out = Vec::new();
+    let mut seen = 0;
+    for period in 0..MAX_PERIODS {
+        let Some(step) = period.checked_mul(rule.interval) else { break };
+        let Some((first_day, mut days)) = period_days(rule, start.date(), step) else { break };
+        if first_day > limit.date() {
+            break;
+        }
+        days.sort();
+        days.dedup();
+        for day in days {
+            let at = day.and_time(time);
+            if at < start {
+                continue;
+            }
+            if at > limit || rule.count.is_some_and(|c| seen >= c) {
+                return out;
+            }
+            seen += 1;
+            out.push(at);
+        }
+    }
+    out
+}
+
+/// First day of the `step`-th period after the one holding `start`, and the candidate days in it.
+/// None once the period falls outside the dates chrono can represent.
+fn period_days(rule: &Rule, start: NaiveDate, step: i64) -> Option<(NaiveDate, Vec<NaiveDate>)> {
+    let weekdays: Vec<Weekday> = rule.by_day.iter().map(|(_, d)| *d).collect();
+    match rule.freq {
+        Freq::Daily => {
+            let day = start.checked_add_signed(Duration::try_days(step)?)?;
+            let keep = (weekdays.is_empty() || weekdays.contains(&day.weekday()))
+                && (rule.by_month.is_empty() || rule.by_month.contains(&day.month()));
+            Some((day, if keep { vec![day] } else { Vec::new() }))
+        }
+        Freq::Weekly => {
+            let back = (7 + start.weekday().num_days_from_monday() - rule.week_start.num_days_from_monday()) % 7;
+            let week = (start - Duration::days(back as i64)).checked_add_signed(Duration::try_weeks(step)?)?;
+            let wanted = if weekdays.is_empty() { vec![start.weekday()] } else { weekdays };
+            let days = (0..7).map(|i| week + Duration::days(i)).filter(|d| wanted.contains(&d.weekday())).collect();
+            Some((week, days))
+        }
+        Freq::Monthly => {
+            let months = (start.year() as i64 * 12 + start.month0() as i64).checked_add(step)?;
+            let first = NaiveDate::from_ymd_opt(i32::try_from(months.div_euclid(12)).ok()?, months.rem_euclid(12) as u32 + 1, 1)?;
+            Some((first, month_days(rule, first, start.day())))
+        }
+        Freq::Yearly => {
+            let year = start.year().checked_add(i32::try_from(step).ok()?)?;
+            let first = NaiveDate::from_ymd_opt(year, 1, 1)?;
+            let months = if rule.by_month.is_empty() { vec![start.month()] } else { rule.by_month.clone() };
+            let days = months
+                .into_iter()
+                .filter_map(|m| NaiveDate::from_ymd_opt(year, m, 1))
+                .flat_map(|month| month_days(rule, month, start.day()))
+                .collect();
+            Some((first, days))
+        }
+    }
+}
+
+/// Candidate days in the month starting at `first`: BYMONTHDAY, else BYDAY, else `default_day`
+fn month_days(rule: &Rule, first: NaiveDate, default_day: u32) -> Vec<NaiveDate> {
+    let next = if first.month() == 12 {
+        NaiveDate::from_ymd_opt(first.year() + 1, 1, 1)
+    } else {
+        NaiveDate::from_ymd_opt(first.year(), first.month() + 1, 1)
+    };
+    let length = next.map_or(31, |n| (n - first).num_days()) as i32;
+    let all: Vec<NaiveDate> = (0..length).map(|i| first + Duration::days(i as i64)).collect();
+
+    if !rule.by_month_day.is_empty() {
+        let weekdays: Vec<Weekday> = rule.by_day.iter().map(|(_, d)| *d).collect();
+        return rule
+            .by_month_day
+            .iter()
+            .filter_map(|&d| {
+                let index = if d > 0 { d - 1 } else { length + d };
+                all.get(usize::try_from(index).ok()?).copied()
+            })
+            .filter(|d| weekdays.is_empty() || weekdays.contains(&d.weekday()))
+            .collect();
+    }
+    if !rule.by_day.is_empty() {
+        return rule
+            .by_day
+            .iter()
+            .flat_map(|&(ordinal, weekday)| {
+                let matching: Vec<NaiveDate> = all.iter().copied().filter(|d| d.weekday() == weekday).collect();
+                match ordinal {
+                    0 => matching,
+                    n if n > 0 => matching.get(n as usize - 1).copied().into_iter().collect(),
+                    n => matching.len().checked_sub(n.unsigned_abs() as usize).and_then(|i| matching.get(i).copied()).into_iter().collect(),
+                }
+            })
+            .collect();
+    }
+    all.get(default_day as usize - 1).copied().into_iter().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utc(y: i32, m: u32, d: u32, h: u32, min: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(y, m, d, h, min, 0).unwrap()
+    }
+
+    // 1 March 2026; the window runs a month back and a year ahead
+    fn now() -> DateTime<Local> {
+        utc(2026, 3, 1, 0, 0).with_timezone(&Local)
+    }
+
+    fn calendar(body: &str) -> String {
+        format!("BEGIN:VCALENDAR\r\nVERSION:2.0\r\nX-WR-CALNAME:Work\r\n{body}END:VCALENDAR\r\n")
+    }
+
+    fn starts(import: &BusyImport) -> Vec<DateTime<Utc>> {
+        import.blocks.iter().map(|b| b.start.with_timezone(&Utc)).collect()
+    }
+
+    const NEW_YORK: &str = "BEGIN:VTIMEZONE\r\nTZID:America/New_York\r\n\
+        BEGIN:DAYLIGHT\r\nTZOFFSETFROM:-0500\r\nTZOFFSETTO:-0400\r\nDTSTART:19700308T020000\r\nRRULE:FREQ=YEARLY;BYMONTH=3;BYDAY=2SU\r\nEND:DAYLIGHT\r\n\
+        BEGIN:STANDARD\r\nTZOFFSETFROM:-0400\r\nTZOFFSETTO:-0500\r\nDTSTART:19701101T020000\r\nRRULE:FREQ=YEARLY;BYMONTH=11;BYDAY=1SU\r\nEND:STANDARD\r\n\
+        END:VTIMEZONE\r\n";
+
+    #[test]
+    fn reads_name_title_and_times() {
+        let ics = calendar("BEGIN:VEVENT\r\nUID:a\r\nSUMMARY:Standup\\, daily\r\nDTSTART:20260302T140000Z\r\nDTEND:20260302T141500Z\r\nEND:VEVENT\r\n");
+        let import = read_busy(&ics, "work.ics", now()).unwrap();
+
+        assert_eq!(import.name.as_deref(), Some("Work"));
+        assert_eq!(import.blocks.len(), 1);
+        let block = &import.blocks[0];
+        assert_eq!((block.uid.as_str(), block.title.as_str(), block.source.as_str()), ("a", "Standup, daily", "work.ics"));
+        assert_eq!(block.start.with_timezone(&Utc), utc(2026, 3, 2, 14, 0));
+        assert_eq!(block.end.with_timezone(&Utc), utc(2026, 3, 2, 14, 15));
+    }
+
+    #[test]
+    fn skips_all_day_free_and_cancelled_events() {
+        let ics = calendar(
+            "BEGIN:VEVENT\r\nUID:holiday\r\nDTSTART;VALUE=DATE:20260302\r\nDTEND;VALUE=DATE:20260303\r\nEND:VEVENT\r\n\
+             BEGIN:VEVENT\r\nUID:free\r\nTRANSP:TRANSPARENT\r\nDTSTART:20260302T140000Z\r\nDURATION:PT1H\r\nEND:VEVENT\r\n\
+             BEGIN:VEVENT\r\nUID:off\r\nSTATUS:CANCELLED\r\nDTSTART:20260302T140000Z\r\nDURATION:PT1H\r\nEND:VEVENT\r\n",
+        );
+        assert!(read_busy(&ics, "x", now()).unwrap().blocks.is_empty());
+    }
+
+    #[test]
+    fn weekly_rule_with_days_and_count() {
+        let ics = calendar("BEGIN:VEVENT\r\nUID:w\r\nDTSTART:20260302T090000Z\r\nDURATION:PT30M\r\nRRULE:FREQ=WEEKLY;BYDAY=MO,WE;COUNT=4\r\nEND:VEVENT\r\n");
+        let import = read_busy(&ics, "x", now()).unwrap();
+
+        assert_eq!(starts(&import), vec![utc(2026, 3, 2, 9, 0), utc(2026, 3, 4, 9, 0), utc(2026, 3, 9, 9, 0), utc(2026, 3, 11, 9, 0)]);
+    }
+
+    #[test]
+    fn time_zone_rules_keep_the_wall_clock_across_daylight_saving() {
+        // New York moves to daylight time on Sunday 8 March 2026
+        let ics = calendar(&format!(
+            "{NEW_YORK}BEGIN:VEVENT\r\nUID:ny\r\nDTSTART;TZID=America/New_York:20260302T090000\r\nDTEND;TZID=America/New_York:20260302T100000\r\nRRULE:FREQ=WEEKLY;UNTIL=20260316T140000Z\r\nEND:VEVENT\r\n"
+        ));
+        let import = read_busy(&ics, "x", now()).unwrap();
+
+        assert_eq!(starts(&import), vec![utc(2026, 3, 2, 14, 0), utc(2026, 3, 9, 13, 0), utc(2026, 3, 16, 13, 0)]);
+        assert!(import.blocks.iter().all(|b| b.end - b.start == Duration::hours(1)));
+    }
+
+    #[test]
+    fn exdates_and_moved_occurrences() {
+        let ics = calendar(
+            "BEGIN:VEVENT\r\nUID:r\r\nSUMMARY:Sync\r\nDTSTART:20260302T150000Z\r\nDURATION:PT1H\r\nRRULE:FREQ=DAILY;COUNT=4\r\nEXDATE:20260303T150000Z\r\nEND:VEVENT\r\n\
+             BEGIN:VEVENT\r\nUID:r\r\nSUMMARY:Sync (moved)\r\nRECURRENCE-ID:20260304T150000Z\r\nDTSTART:20260304T170000Z\r\nDURATION:PT1H\r\nEND:VEVENT\r\n",
+        );
+        let import = read_busy(&ics, "x", now()).unwrap();
+
+        assert_eq!(starts(&import), vec![utc(2026, 3, 2, 15, 0), utc(2026, 3, 4, 17, 0), utc(2026, 3, 5, 15, 0)]);
+        assert_eq!(import.blocks[1].title, "Sync (moved)");
+    }
+
+    #[test]
+    fn monthly_by_ordinal_weekday_and_month_day() {
+        let ics = calendar(
+            "BEGIN:VEVENT\r\nUID:last-friday\r\nDTSTART:20260327T160000Z\r\nDURATION:PT1H\r\nRRULE:FREQ=MONTHLY;BYDAY=-1FR;COUNT=3\r\nEND:VEVENT\r\n\
+             BEGIN:VEVENT\r\nUID:the-31st\r\nDTSTART:20260331T080000Z\r\nDURATION:PT1H\r\nRRULE:FREQ=MONTHLY;COUNT=2\r\nEND:VEVENT\r\n",
+        );
+        let import = read_busy(&ics, "x", now()).unwrap();
+        let of = |uid: &str| import.blocks.iter().filter(|b| b.uid == uid).map(|b| b.start.with_timezone(&Utc)).collect::<Vec<_>>();
+
+        assert_eq!(of("last-friday"), vec![utc(2026, 3, 27, 16, 0), utc(2026, 4, 24, 16, 0), utc(2026, 5, 29, 16, 0)]);
+        // April has no 31st, so the second instance is in May
+        assert_eq!(of("the-31st"), vec![utc(2026, 3, 31, 8, 0), utc(2026, 5, 31, 8, 0)]);
+    }
+
+    #[test]
+    fn events_become_items_with_times_or_a_due_date() {
+        let ics = calendar(&format!(
+            "{NEW_YORK}BEGIN:VEVENT\r\nUID:call\r\nSUMMARY:Call\r\nDESCRIPTION:Bring notes\r\nDTSTART;TZID=America/New_York:20260310T090000\r\nDURATION:PT45M\r\nRRULE:FREQ=DAILY\r\nEND:VEVENT\r\n\
+             BEGIN:VEVENT\r\nUID:call\r\nRECURRENCE-ID;TZID=America/New_York:20260311T090000\r\nDTSTART;TZID=America/New_York:20260311T100000\r\nDURATION:PT45M\r\nEND:VEVENT\r\n\
+             BEGIN:VEVENT\r\nUID:launch\r\nSUMMARY:Launch day\r\nDTSTART;VALUE=DATE:20260320\r\nEND:VEVENT\r\n"
+        ));
+        let items = read_items(&ics).unwrap();
+
+        assert_eq!(items.len(), 2);
+        let call = &items[0];
+        assert_eq!((call.kind, call.title.as_str(), call.description.as_deref()), (ItemKind::Event, "Call", Some("Bring notes")));
+        assert_eq!(call.start.map(|s| s.with_timezone(&Utc)), Some(utc(2026, 3, 10, 13, 0)));
+        assert_eq!(call.end.map(|e| e.with_timezone(&Utc)), Some(utc(2026, 3, 10, 13, 45)));
+        let launch = &items[1];
+        assert_eq!((launch.start, launch.due), (None, NaiveDate::from_ymd_opt(2026, 3, 20)));
+    }
+
+    #[test]
+    fn escaped_commas_keep_a_category_together() {
+        let ics = calendar("BEGIN:VTODO\r\nUID:t\r\nSUMMARY:Budget\r\nCATEGORIES:R\\,D,ops\r\nCATEGORIES:C:\\\\,x\r\nEND:VTODO\r\n");
+        let items = read_items(&ics).unwrap();
+
+        assert_eq!(items[0].tags, vec!["R,D", "ops", "C:\\", "x"]);
+    }
+
+    #[test]
+    fn todos_keep_deadline_status_and_categories() {
+        let ics = calendar(
+            "BEGIN:VTODO\r\nUID:t1\r\nSUMMARY:File taxes\r\nDUE;VALUE=DATE:20260415\r\nCATEGORIES:money, admin\r\nX-ERRDAY-QUADRANT:Do First\r\nEND:VTODO\r\n\
+             BEGIN:VTODO\r\nUID:t2\r\nSUMMARY:Old chore\r\nSTATUS:COMPLETED\r\nEND:VTODO\r\n\
+             BEGIN:VTODO\r\nUID:t3\r\nSUMMARY:Dropped\r\nSTATUS:CANCELLED\r\nEND:VTODO\r\n",
+        );
+        let items = read_items(&ics).unwrap();
+
+        assert_eq!(items.iter().map(|i| i.uid.as_str()).collect::<Vec<_>>(), vec!["t1", "t2"]);
+        assert_eq!(items[0].due, NaiveDate::from_ymd_opt(2026, 4, 15));
+        assert_eq!(items[0].tags, vec!["money", "admin"]);
+        assert!(!items[0].done && items[1].done);
+
+        let task = items[1].to_task(Quadrant::Schedule);
+        assert_eq!((task.quadrant, task.status, task.ics_uid.as_deref()), (Quadrant::Schedule, TaskStatus::Done, Some("t2")));
+    }
+
+    #[test]
+    fn endless_rules_stop_at_the_window() {
+        let ics = calendar("BEGIN:VEVENT\r\nUID:d\r\nDTSTART:20200101T090000Z\r\nDURATION:PT1H\r\nRRULE:FREQ=DAILY\r\nEND:VEVENT\r\n");
+        let import = read_busy(&ics, "x", now()).unwrap();
+
+        assert_eq!(import.blocks.first().map(|b| b.start.with_timezone(&Utc)), Some(utc(2026, 1, 29, 9, 0)));
+        assert_eq!(import.blocks.len() as i64, PAST_DAYS + AHEAD_DAYS);
+    }
+
+    #[test]
+    fn malformed_rules_and_durations_do_not_panic() {
+        let ics = calendar(
+            "BEGIN:VEVENT\r\nUID:far\r\nDTSTART:20260302T090000Z\r\nDURATION:PT1H\r\nRRULE:FREQ=DAILY;INTERVAL=200000000\r\nEND:VEVENT\r\n\
+             BEGIN:VEVENT\r\nUID:years\r\nDTSTART:20260302T090000Z\r\nDURATION:PT1H\r\nRRULE:FREQ=YEARLY;INTERVAL=200000000\r\nEND:VEVENT\r\n\
+             BEGIN:VEVENT\r\nUID:long\r\nDTSTART:20260302T090000Z\r\nDURATION:P999999999999D\r\nEND:VEVENT\r\n\
+             BEGIN:VEVENT\r\nUID:accent\r\nDTSTART:20260302T090000Z\r\nDURATION:PT1H\r\nRRULE:FREQ=WEEKLY;BYDAY=1ÉX;COUNT=2\r\nEND:VEVENT\r\n",
+        );
+        let import = read_busy(&ics, "x", now()).unwrap();
+        let uids: Vec<&str> = import.blocks.iter().map(|b| b.uid.as_str()).collect();
+
+        // Huge intervals leave only the first instance; the endless duration is dropped;
+        // the unreadable BYDAY is ignored, so the weekly rule falls back to DTSTART's weekday
+        assert_eq!(uids, vec!["far", "years", "accent", "accent"]);
+        assert_eq!(parse_duration("P999999999999D"), None);
+        assert_eq!(parse_duration("PT9223372036854775807S"), None);
+        assert_eq!(parse_by_day("1ÉX"), None);
+        assert_eq!(Rule::parse("FREQ=DAILY;INTERVAL=200000000").map(|r| r.interval), Some(MAX_INTERVAL));
+    }
+}
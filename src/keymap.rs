@@ -0,0 +1,196 @@
+// KEYMAP: The app-wide keyboard shortcuts and their default bindings.
+// A binding is a combo string such as "mod+n", "alt+2" or "delete":
+// "mod" is Ctrl on Windows/Linux and Cmd on macOS, modifiers come first in the order
+// mod, alt, shift, and the key is the lowercase KeyboardEvent.key ("arrowleft", "?", "[").
+// Shift is only written for named keys; for printable keys it is already part of the character.
+// Users remap actions in settings.json under "keymap"; actions left out keep their defaults.
+// Since the keymap is edited by hand, unknown action names and malformed entries are skipped
+// (with a log line) instead of failing the whole settings file.
+
+use serde::{Deserialize, Deserializer, Serialize};
+use std::collections::BTreeMap;
+
+/// ACTION: Everything a shortcut can trigger
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum Action {
+    GoInbox,
+    GoMatrix,
+    GoCalendar,
+    NewTask,
+    DeleteSelected,
+    Undo,
+    MoveToDoFirst,
+    MoveToSchedule,
+    MoveToDelegate,
+    MoveToDelete,
+    MoveToInbox,
+    PreviousWeek,
+    NextWeek,
+    ThisWeek,
+    CommandPalette,
+    CheatSheet,
+}
+
+impl Action {
+    /// Every action, in the order the cheat sheet lists them
+    pub const ALL: [Action; 16] = [
+        Action::GoInbox,
+        Action::GoMatrix,
+        Action::GoCalendar,
+        Action::NewTask,
+        Action::CommandPalette,
+        Action::Undo,
+        Action::DeleteSelected,
+        Action::MoveToDoFirst,
+        Action::MoveToSchedule,
+        Action::MoveToDelegate,
+        Action::MoveToDelete,
+        Action::MoveToInbox,
+        Action::PreviousWeek,
+        Action::NextWeek,
+        Action::ThisWeek,
+        Action::CheatSheet,
+    ];
+
+    /// Human-readable name for the cheat sheet
+    pub fn label(&self) -> &'static str {
+        match self {
+            Action::GoInbox => "Go to Brainstorm",
+            Action::GoMatrix => "Go to Matrix",
+            Action::GoCalendar => "Go to Calendar",
+            Action::NewTask => "New task",
+            Action::DeleteSelected => "Delete selected tasks",
+            Action::Undo => "Undo",
+            Action::MoveToDoFirst => "Move selection to Do First",
+            Action::MoveToSchedule => "Move selection to Schedule",
+            Action::MoveToDelegate => "Move selection to Delegate",
+            Action::MoveToDelete => "Move selection to Delete",
+            Action::MoveToInbox => "Move selection back to Brainstorm",
+            Action::PreviousWeek => "Calendar: previous week / day / month",
+            Action::NextWeek => "Calendar: next week / day / month",
+            Action::ThisWeek => "Calendar: back to today",
+            Action::CommandPalette => "Search & commands",
+            Action::CheatSheet => "Show this cheat sheet",
+        }
+    }
+
+    /// Which part of the app the action belongs to (cheat sheet section)
+    pub fn group(&self) -> &'static str {
+        match self {
+            Action::GoInbox | Action::GoMatrix | Action::GoCalendar | Action::CommandPalette | Action::CheatSheet => "Navigation",
+            Action::NewTask | Action::DeleteSelected | Action::Undo => "Tasks",
+            Action::MoveToDoFirst | Action::MoveToSchedule | Action::MoveToDelegate | Action::MoveToDelete | Action::MoveToInbox => "Matrix",
+            Action::PreviousWeek | Action::NextWeek | Action::ThisWeek => "Calendar",
+        }
+    }
+
+    /// Built-in bindings
+    pub fn default_bindings(&self) -> Vec<String> {
+        let combos: &[&str] = match self {
+            Action::GoInbox => &["mod+1"],
+            Action::GoMatrix => &["mod+2"],
+            Action::GoCalendar => &["mod+3"],
+            Action::NewTask => &["mod+n"],
+            Action::DeleteSelected => &["delete", "mod+backspace"],
+            Action::Undo => &["mod+z"],
+            Action::MoveToDoFirst => &["alt+1"],
+            Action::MoveToSchedule => &["alt+2"],
+            Action::MoveToDelegate => &["alt+3"],
+            Action::MoveToDelete => &["alt+4"],
+            Action::MoveToInbox => &["alt+0"],
+            Action::PreviousWeek => &["["],
+            Action::NextWeek => &["]"],
+            Action::ThisWeek => &["t"],
+            Action::CommandPalette => &["mod+k"],
+            Action::CheatSheet => &["?"],
+        };
+        combos.iter().map(|c| c.to_string()).collect()
+    }
+}
+
+/// KEYMAP: User overrides on top of the defaults (stored in settings.json)
+#[derive(Debug, Clone, PartialEq, Default, Serialize)]
+#[serde(transparent)]
+pub struct Keymap(pub BTreeMap<Action, Vec<String>>);
+
+impl<'de> Deserialize<'de> for Keymap {
+    /// Reads the overrides one entry at a time, keeping the ones that name a real action
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let raw = BTreeMap::<String, serde_json::Value>::deserialize(deserializer)?;
+        let mut keymap = BTreeMap::new();
+        for (name, combos) in raw {
+            let Ok(action) = serde_json::from_value::<Action>(serde_json::Value::String(name.clone())) else {
+                tracing::warn!("keymap: ignoring unknown action \"{name}\"");
+                continue;
+            };
+            match serde_json::from_value::<Vec<String>>(combos) {
+                Ok(combos) => {
+                    keymap.insert(action, combos);
+                }
+                Err(e) => tracing::warn!("keymap: ignoring the bindings of \"{name}\": {e}"),
+            }
+        }
+        Ok(Keymap(keymap))
+    }
+}
+
+impl Keymap {
+    /// The combos bound to an action (the user's list, or the default one)
+    pub fn bindings(&self, action: Action) -> Vec<String> {
+        self.0
+            .get(&action)
+            .cloned()
+            .unwrap_or_else(|| action.default_bindings())
+    }
+
+    /// Finds the action bound to a combo sent by the key listener
+    pub fn action_for(&self, combo: &str) -> Option<Action> {
+        Action::ALL.into_iter().find(|a| self.bindings(*a).iter().any(|c| c == combo))
+    }
+
+    /// Every bound combo, so the key listener knows which keys to take over
+    pub fn all_combos(&self) -> Vec<String> {
+        Action::ALL.into_iter().flat_map(|a| self.bindings(a)).collect()
+    }
+}
+
+/// Turns "mod+shift+arrowleft" into "Ctrl + Shift + ←" (⌘ on macOS) for display
+pub fn display_combo(combo: &str) -> String {
+    combo
+        .split('+')
+        .map(|part| match part {
+            "mod" if cfg!(target_os = "macos") => "⌘".to_string(),
+            "mod" => "Ctrl".to_string(),
+            "alt" if cfg!(target_os = "macos") => "⌥".to_string(),
+            "alt" => "Alt".to_string(),
+            "shift" => "Shift".to_string(),
+            "arrowleft" => "←".to_string(),
+            "arrowright" => "→".to_string(),
+            "arrowup" => "↑".to_string(),
+            "arrowdown" => "↓".to_string(),
+            "backspace" => "⌫".to_string(),
+            "delete" => "Del".to_string(),
+            "escape" => "Esc".to_string(),
+            key => key.to_uppercase(),
+        })
+        .collect::<Vec<_>>()
+        .join(" + ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unknown_actions_and_bad_entries_are_skipped() {
+        let keymap: Keymap = serde_json::from_str(
+            r#"{ "NewTask": ["mod+t"], "NewTsak": ["mod+y"], "Undo": "mod+u", "CheatSheet": ["f1"] }"#,
+        )
+        .unwrap();
+        assert_eq!(keymap.bindings(Action::NewTask), vec!["mod+t"]);
+        assert_eq!(keymap.bindings(Action::CheatSheet), vec!["f1"]);
+        // The malformed entry keeps its default instead of breaking the rest
+        assert_eq!(keymap.bindings(Action::Undo), vec!["mod+z"]);
+        assert_eq!(keymap.0.len(), 2);
+    }
+}
@@ -3,12 +3,29 @@
 use dioxus::prelude::*;
 
 // Internal modules for organizing code
+mod caldav;
+mod capture;
+mod export;
+mod feed;
+mod filters;
+mod i18n;
+mod ics;
+mod keymap;
 mod components;
 mod models;
+mod planner;
+mod search;
+mod settings;
 mod store;
+mod theme;
 mod views;
 mod routes; 
 
+use caldav::CalDavState;
+use models::TaskStatus;
+use components::{Selection, Shortcuts};
+use feed::FeedState;
+use settings::SettingsState;
 use store::AppState;
 use routes::Route;
 
@@ -17,6 +34,9 @@ const FAVICON: Asset = asset!("/assets/favicon.ico");
 const MAIN_CSS: Asset = asset!("/assets/main.css");
 const TAILWIND_CSS: Asset = asset!("/assets/tailwind.css");
 
+// How often finished tasks are checked against the archive delay while the app is open
+const ARCHIVE_SWEEP_INTERVAL: std::time::Duration = std::time::Duration::from_secs(60 * 60);
+
 /// THE MISSION START: This is where the application launches
 fn main() {
     std::env::set_var("WEBKIT_DISABLE_COMPOSITING_MODE", "1");
@@ -24,16 +44,21 @@ fn main() {
     #[cfg(debug_assertions)]
     dioxus_logger::init(tracing::Level::INFO).expect("failed to init logger");
     
-    // Stage 2: Configure the desktop window (Title, size, and styling)
+    // Stage 2: Configure the desktop window (Title, size from settings.json, and styling)
+    let prefs = SettingsState::load();
     let config = dioxus::desktop::Config::new()
         .with_window(
             dioxus::desktop::WindowBuilder::new()
                 .with_title("Errday")
-                .with_inner_size(dioxus::desktop::LogicalSize::new(1280.0, 800.0))
+                .with_inner_size(dioxus::desktop::LogicalSize::new(prefs.window_width.max(1000.0), prefs.window_height.max(700.0)))
                 .with_min_inner_size(dioxus::desktop::LogicalSize::new(1000.0, 700.0))
         )
-        // Set a dark background immediately to avoid a white flash on startup
-        .with_custom_head(r#"<style>body { background-color: #0B0D17; }</style>"#.to_string());
+        // Paint the theme's background immediately to avoid a white flash on startup.
+        // color() only hands back validated #rrggbb values, so a theme file can't inject markup here.
+        .with_custom_head(format!(
+            "<style>body {{ background-color: {}; }}</style>",
+            theme::Theme::resolve(&prefs.theme, &prefs.custom_themes).color("space-900")
+        ));
 
     // Stage 3: Launch the app with our specific configuration
     LaunchBuilder::desktop().with_cfg(config).launch(App);
@@ -43,13 +68,64 @@ fn main() {
 #[component]
 fn App() -> Element {
     // Initialize the shared state (the "Brain") for the entire app
-    use_context_provider(|| AppState::new());
+    let app_state = use_context_provider(|| AppState::new());
+    // User preferences (settings.json), kept next to the task data
+    let settings_state = use_context_provider(SettingsState::new);
+    // Cards picked for bulk actions, shared by every view
+    use_context_provider(Selection::new);
+    // Overlays and view commands driven by the keyboard shortcut layer
+    use_context_provider(Shortcuts::new);
+    // Status of the CalDAV sync, started from the settings page or the palette
+    use_context_provider(CalDavState::new);
+
+    // Sweep long-finished tasks into the archive on launch, when the delay changes and whenever a task is
+    // marked Done (so "0 days" archives right away)...
+    let archive_after_days = use_memo(move || settings_state.settings.read().archive_after_days);
+    let done_count = use_memo(move || app_state.tasks.read().iter().filter(|t| t.status == TaskStatus::Done).count());
+    use_effect(move || {
+        done_count();
+        app_state.archive_finished(archive_after_days());
+    });
+    // ...and every hour, for tasks that cross the delay while the app stays open
+    use_future(move || async move {
+        loop {
+            tokio::time::sleep(ARCHIVE_SWEEP_INTERVAL).await;
+            app_state.archive_finished(settings_state.settings.peek().archive_after_days);
+        }
+    });
+
+    // Keep this week's Delete-quadrant size up to date for the purge nudge, whichever view is open
+    use_effect(move || settings_state.record_delete_count(app_state.delete_count()));
+
+    // The loopback calendar feed follows its settings (restarting only when they change)...
+    let feed = use_context_provider(FeedState::new);
+    let feed_prefs = use_memo(move || settings_state.settings.read().feed.clone());
+    use_effect(move || {
+        let prefs = feed_prefs();
+        feed.configure(prefs.enabled, prefs.port, &prefs.token);
+    });
+    // ...and serves the current export, rebuilt whenever tasks or the export choices change
+    use_effect(move || {
+        if !feed.is_running() {
+            return;
+        }
+        let prefs = settings_state.settings.read().export.clone();
+        let ics = export::build_ics(&export::selection(&app_state.tasks.read(), &prefs, None, None), &prefs, settings_state.i18n());
+        feed.publish(ics);
+    });
+
+    // The active theme's colors as CSS variables
+    let theme_css = {
+        let settings = settings_state.settings.read();
+        theme::stylesheet(&settings.theme, &settings.custom_themes)
+    };
 
     rsx! {
         // Link our global styles and favicon
         document::Link { rel: "icon", href: FAVICON }
         document::Link { rel: "stylesheet", href: MAIN_CSS }
         document::Link { rel: "stylesheet", href: TAILWIND_CSS }
+        style { "{theme_css}" }
         
         // Define the main container with the deep space theme
         div { class: "dark bg-space-900 text-gray-100 min-h-screen font-sans selection:bg-neon-cyan selection:text-space-900",
@@ -1,4 +1,4 @@
-use chrono::{DateTime, Local};
+use chrono::{DateTime, Local, NaiveDate};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
@@ -12,6 +12,43 @@ pub enum Quadrant {
     Unsorted, // The Inbox: Newly captured tasks waiting for sorting
 }
 
+impl Quadrant {
+    pub const ALL: [Quadrant; 5] = [Quadrant::DoFirst, Quadrant::Schedule, Quadrant::Delegate, Quadrant::Delete, Quadrant::Unsorted];
+
+    /// DISPLAY NAME: Human-readable label for summaries and menus
+    pub fn label(&self) -> &'static str {
+        match self {
+            Quadrant::DoFirst => "Do First",
+            Quadrant::Schedule => "Schedule",
+            Quadrant::Delegate => "Delegate",
+            Quadrant::Delete => "Delete",
+            Quadrant::Unsorted => "Unsorted",
+        }
+    }
+
+    /// DEMOTION: Where a task goes when its sector is over its limit.
+    /// Delete has nowhere lower to go, so demoting from it means purging the task (None).
+    pub fn demoted(&self) -> Option<Quadrant> {
+        match self {
+            Quadrant::DoFirst => Some(Quadrant::Schedule),
+            Quadrant::Schedule | Quadrant::Delegate => Some(Quadrant::Delete),
+            Quadrant::Delete => None,
+            Quadrant::Unsorted => Some(Quadrant::Unsorted),
+        }
+    }
+
+    /// SCORE MAPPING: Picks the matrix sector for urgency/importance scores (0.0 – 1.0).
+    /// Anything at or above the midline counts as urgent / important.
+    pub fn from_scores(urgency: f32, importance: f32) -> Self {
+        match (urgency >= 0.5, importance >= 0.5) {
+            (true, true) => Quadrant::DoFirst,
+            (false, true) => Quadrant::Schedule,
+            (true, false) => Quadrant::Delegate,
+            (false, false) => Quadrant::Delete,
+        }
+    }
+}
+
 /// OPERATIONAL STATUS: Is the task active or archived?
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum TaskStatus {
@@ -30,6 +67,26 @@ pub struct Task {
     pub created_at: DateTime<Local>,         // The exact moment the task was created
     pub scheduled_start: Option<DateTime<Local>>, // Planned start time for the calendar
     pub scheduled_end: Option<DateTime<Local>>,   // Planned completion time
+    #[serde(default)]
+    pub urgency: Option<f32>,                // How urgent it is, 0.0 – 1.0 (None = never scored)
+    #[serde(default)]
+    pub importance: Option<f32>,             // How important it is, 0.0 – 1.0 (None = never scored)
+    #[serde(default)]
+    pub tags: Vec<String>,                   // Free-form labels such as "fundraising"
+    #[serde(default)]
+    pub updated_at: Option<DateTime<Local>>, // Last time the task was changed (None = never since creation)
+    #[serde(default)]
+    pub completed_at: Option<DateTime<Local>>, // When it was marked Done (None while open)
+    #[serde(default)]
+    pub project: Option<String>,             // The project it belongs to, e.g. "Seed Round"
+    #[serde(default)]
+    pub due: Option<NaiveDate>,              // Deadline (None = no deadline)
+    #[serde(default)]
+    pub estimate_minutes: Option<u32>,       // Expected effort (None = the calendar's default block)
+    #[serde(default)]
+    pub depends_on: Vec<Uuid>,               // Tasks that have to be finished before this one starts
+    #[serde(default)]
+    pub ics_uid: Option<String>,             // UID of the calendar item it was imported from (None = made here)
 }
 
 impl Task {
@@ -44,6 +101,87 @@ impl Task {
             created_at: Local::now(),
             scheduled_start: None,
             scheduled_end: None,
+            urgency: None,
+            importance: None,
+            tags: Vec::new(),
+            updated_at: None,
+            completed_at: None,
+            project: None,
+            due: None,
+            estimate_minutes: None,
+            depends_on: Vec::new(),
+            ics_uid: None,
+        }
+    }
+
+    /// STATUS CHANGE: Sets Done/Todo and keeps the completion time in sync
+    pub fn set_status(&mut self, status: TaskStatus) {
+        self.completed_at = match status {
+            TaskStatus::Done => self.completed_at.or(Some(Local::now())),
+            TaskStatus::Todo => None,
+        };
+        self.status = status;
+    }
+
+    /// MOVE: Puts the task in `quadrant`. Scores pointing at another sector are pulled to the
+    /// nearest edge of the new one, so the plot keeps showing the dot where the task now sits.
+    pub fn set_quadrant(&mut self, quadrant: Quadrant) {
+        let sector = match quadrant {
+            Quadrant::DoFirst => Some((true, true)),
+            Quadrant::Schedule => Some((false, true)),
+            Quadrant::Delegate => Some((true, false)),
+            Quadrant::Delete => Some((false, false)),
+            Quadrant::Unsorted => None, // The backlog has no sector to match
+        };
+        let toward = |score: f32, high: bool| if high { score.max(0.5) } else { score.min(0.49) };
+        if let (Some((urgent, important)), Some(u), Some(i)) = (sector, self.urgency, self.importance) {
+            self.urgency = Some(toward(u, urgent));
+            self.importance = Some(toward(i, important));
         }
+        self.quadrant = quadrant;
     }
+
+    /// TOUCH: Records that the task was just changed
+    pub fn touch(&mut self) {
+        self.updated_at = Some(Local::now());
+    }
+
+    /// LAST TOUCH: When the task was last changed (creation counts as a change)
+    pub fn last_touched(&self) -> DateTime<Local> {
+        self.updated_at.unwrap_or(self.created_at)
+    }
+
+    /// AGE: Whole days since the task was captured
+    pub fn age_days(&self) -> i64 {
+        (Local::now() - self.created_at).num_days()
+    }
+
+    /// IDLE TIME: Whole days since the task was last changed
+    pub fn idle_days(&self) -> i64 {
+        (Local::now() - self.last_touched()).num_days()
+    }
+
+    /// SUGGESTED SECTOR: The quadrant the task's scores point to (None until it is scored)
+    pub fn suggested_quadrant(&self) -> Option<Quadrant> {
+        Some(Quadrant::from_scores(self.urgency?, self.importance?))
+    }
+
+    /// PRIORITY: Combined score used to order cards inside a quadrant (unscored = lowest)
+    pub fn priority_score(&self) -> f32 {
+        match (self.urgency, self.importance) {
+            (Some(u), Some(i)) => u + i,
+            _ => -1.0,
+        }
+    }
+}
+
+/// BUSY BLOCK: One occurrence of a meeting from an imported calendar.
+/// Read-only: tasks are scheduled around it, and re-importing its calendar replaces it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BusyBlock {
+    pub source: String,        // The calendar it came from (its name, or the file name)
+    pub uid: String,           // The event's UID in that calendar
+    pub title: String,         // The event's summary
+    pub start: DateTime<Local>,
+    pub end: DateTime<Local>,
 }
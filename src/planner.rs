@@ -0,0 +1,499 @@
+// PLANNER: The "Plan my week" auto-scheduler.
+// Takes the open, unscheduled Do First tasks, then the Schedule tasks, and proposes a block for
+// each inside the working hours of a date range. Blocks already on the calendar never move,
+// imported meetings and protected time stay clear, a task starts only after the tasks it depends on end, and it has
+// to finish by its deadline.
+// Nothing here touches the store: the calendar shows the proposal and writes it on Accept.
+// The availability helpers below (working hours, protected blocks, next free slot) are shared
+// with the calendar's drop handlers.
+
+use std::collections::HashMap;
+use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime, NaiveTime};
+use uuid::Uuid;
+use crate::models::{BusyBlock, Quadrant, Task, TaskStatus};
+use crate::settings::CalendarPrefs;
+
+/// PROPOSED BLOCK: Where the planner would put one task (local wall-clock times)
+#[derive(Debug, Clone, PartialEq)]
+pub struct Proposal {
+    pub task_id: Uuid,
+    pub start: NaiveDateTime,
+    pub end: NaiveDateTime,
+}
+
+/// LEFT OUT: Why a task got no block
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Skipped {
+    NoRoom,         // No free working time long enough before the end of the range
+    MissesDeadline, // The first free slot ends after its due date
+    Blocked(Uuid),  // Waits on an open task that has no block and did not get one
+}
+
+/// PLAN: Proposed blocks in the order they were placed, plus the tasks left out
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Plan {
+    pub blocks: Vec<Proposal>,
+    pub skipped: Vec<(Uuid, Skipped)>,
+}
+
+/// PLAN: Proposes blocks from `from` to the end of `until` (inclusive).
+/// Order: Do First before Schedule, then the earliest deadline, the highest priority, the oldest task.
+pub fn plan(tasks: &[Task], meetings: &[BusyBlock], from: NaiveDateTime, until: NaiveDate, prefs: &CalendarPrefs) -> Plan {
+    let by_id: HashMap<Uuid, &Task> = tasks.iter().map(|t| (t.id, t)).collect();
+    let open: Vec<&Task> = tasks.iter().filter(|t| t.status == TaskStatus::Todo).collect();
+
+    // Busy time (sorted by start) and the end of every open task that has a block
+    let mut busy = busy_time(open.iter().copied(), meetings, from.date(), until, prefs);
+    let mut ends: HashMap<Uuid, NaiveDateTime> = open.iter().filter_map(|t| Some((t.id, span(t, prefs)?.1))).collect();
+
+    let mut waiting: Vec<&Task> = open
+        .iter()
+        .copied()
+        .filter(|t| t.scheduled_start.is_none() && matches!(t.quadrant, Quadrant::DoFirst | Quadrant::Schedule))
+        .collect();
+    waiting.sort_by(|a, b| {
+        rank(a)
+            .cmp(&rank(b))
+            .then_with(|| (a.due.is_none(), a.due).cmp(&(b.due.is_none(), b.due)))
+            .then_with(|| b.priority_score().total_cmp(&a.priority_score()))
+            .then_with(|| a.created_at.cmp(&b.created_at))
+    });
+
+    // A dependency holds a task back until it is done or has a block
+    let pending = |dep: &Uuid, ends: &HashMap<Uuid, NaiveDateTime>| {
+        by_id.get(dep).is_some_and(|t| t.status == TaskStatus::Todo) && !ends.contains_key(dep)
+    };
+
+    let mut plan = Plan::default();
+    // Always take the first task in priority order whose dependencies are settled
+    while let Some(pos) = waiting.iter().position(|t| !t.depends_on.iter().any(|d| pending(d, &ends))) {
+        let task = waiting.remove(pos);
+        let after = task.depends_on.iter().filter_map(|d| ends.get(d)).fold(from, |a, &b| a.max(b));
+        let length = task
+            .estimate_minutes
+            .map(|m| Duration::minutes(m as i64))
+            .unwrap_or_else(|| prefs.default_block());
+
+        let Some(start) = find_slot(&busy, after, length, until, prefs) else {
+            plan.skipped.push((task.id, Skipped::NoRoom));
+            continue;
+        };
+        let end = start + length;
+        if task.due.and_then(|d| d.succ_opt()).is_some_and(|d| end > d.and_time(NaiveTime::MIN)) {
+            plan.skipped.push((task.id, Skipped::MissesDeadline));
+            continue;
+        }
+        busy.push((start, end));
+        busy.sort();
+        ends.insert(task.id, end);
+        plan.blocks.push(Proposal { task_id: task.id, start, end });
+    }
+
+    // Whatever is still waiting depends on a task that never got a block (or on a cycle)
+    for task in waiting {
+        if let Some(dep) = task.depends_on.iter().find(|d| pending(d, &ends)) {
+            plan.skipped.push((task.id, Skipped::Blocked(*dep)));
+        }
+    }
+    plan
+}
+
+/// NEXT FREE SLOT: First start at or after `after` (looking SEARCH_DAYS ahead) where `length`
+/// fits inside working hours, outside protected time and clear of the open blocks in `tasks`
+/// other than `skip`. Pass no tasks to look at availability alone.
+pub fn next_free_slot(tasks: &[Task], meetings: &[BusyBlock], skip: Option<Uuid>, after: NaiveDateTime, length: Duration, prefs: &CalendarPrefs) -> Option<NaiveDateTime> {
+    let until = after.date() + Duration::days(SEARCH_DAYS);
+    let others = tasks.iter().filter(|t| t.status == TaskStatus::Todo && Some(t.id) != skip);
+    find_slot(&busy_time(others, meetings, after.date(), until, prefs), after, length, until, prefs)
+}
+
+/// Whether start..end lies within one day's working hours
+pub fn in_work_hours(prefs: &CalendarPrefs, start: NaiveDateTime, end: NaiveDateTime) -> bool {
+    let Some((open, close)) = prefs.work_hours_on(start.weekday()) else { return false };
+    let midnight = start.date().and_time(NaiveTime::MIN);
+    start >= midnight + Duration::hours(open as i64) && end <= midnight + Duration::hours(close as i64)
+}
+
+/// PROTECTED TIME: The protected blocks falling on `day` as (label, start, end)
+pub fn protected_on(prefs: &CalendarPrefs, day: NaiveDate) -> Vec<(&str, NaiveDateTime, NaiveDateTime)> {
+    let midnight = day.and_time(NaiveTime::MIN);
+    prefs.protected_blocks
+        .iter()
+        .filter(|b| b.days.contains(&day.weekday()))
+        .map(|b| {
+            let start = midnight + Duration::minutes(b.start_minute as i64);
+            let end = midnight + Duration::minutes(b.end_minute as i64);
+            (b.label.as_str(), start, end)
+        })
+        .collect()
+}
+
+/// Labels of the protected blocks that start..end runs into
+pub fn protected_hits(prefs: &CalendarPrefs, start: NaiveDateTime, end: NaiveDateTime) -> Vec<&str> {
+    let mut day = start.date();
+    let mut hits = Vec::new();
+    while day <= end.date() {
+        for (label, s, e) in protected_on(prefs, day) {
+            if s < end && start < e && !hits.contains(&label) {
+                hits.push(label);
+            }
+        }
+        let Some(next) = day.succ_opt() else { break };
+        day = next;
+    }
+    hits
+}
+
+/// How far ahead next_free_slot looks, in days
+const SEARCH_DAYS: i64 = 14;
+
+/// Busy time from the first to the last day, sorted by start: the tasks' blocks, imported meetings and protected time
+fn busy_time<'a>(tasks: impl Iterator<Item = &'a Task>, meetings: &[BusyBlock], first: NaiveDate, last: NaiveDate, prefs: &CalendarPrefs) -> Vec<(NaiveDateTime, NaiveDateTime)> {
+    let mut busy: Vec<(NaiveDateTime, NaiveDateTime)> = tasks.filter_map(|t| span(t, prefs)).collect();
+    busy.extend(
+        meetings
+            .iter()
+            .map(|m| (m.start.naive_local(), m.end.naive_local()))
+            .filter(|(start, end)| end.date() >= first && start.date() <= last),
+    );
+    let mut day = first;
+    while day <= last {
+        busy.extend(protected_on(prefs, day).into_iter().map(|(_, s, e)| (s, e)));
+        let Some(next) = day.succ_opt() else { break };
+        day = next;
+    }
+    busy.sort();
+    busy
+}
+
+/// Do First goes before Schedule
+fn rank(task: &Task) -> u8 {
+    match task.quadrant {
+        Quadrant::DoFirst => 0,
+        _ => 1,
+    }
+}
+
+/// A task's block in local wall-clock time (a missing end counts as the default block)
+fn span(task: &Task, prefs: &CalendarPrefs) -> Option<(NaiveDateTime, NaiveDateTime)> {
+    let start = task.scheduled_start?.naive_local();
+    let end = task.scheduled_end.map(|e| e.naive_local()).unwrap_or(start + prefs.default_block());
+    Some((start, end))
+}
+
+/// First start at or after `after` where `length` fits inside one working day without touching a busy block
+fn find_slot(
+    busy: &[(NaiveDateTime, NaiveDateTime)],
+    after: NaiveDateTime,
+    length: Duration,
+    until: NaiveDate,
+    prefs: &CalendarPrefs,
+) -> Option<NaiveDateTime> {
+    let mut day = after.date();
+    while day <= until {
+        if let Some((open, close)) = prefs.work_hours_on(day.weekday()) {
+            let opens = day.and_time(NaiveTime::MIN) + Duration::hours(open as i64);
+            let closes = day.and_time(NaiveTime::MIN) + Duration::hours(close as i64);
+            let mut start = snap_up(opens.max(after), prefs.snap_minutes);
+            // Jump past every block that gets in the way, in start order
+            for &(s, e) in busy {
+                if e <= start {
+                    continue;
+                }
+                if s >= start + length {
+                    break;
+                }
+                start = snap_up(e, prefs.snap_minutes);
+            }
+            if start + length <= closes {
+                return Some(start);
+            }
+        }
+        day = day.succ_opt()?;
+    }
+    None
+}
+
+/// Rounds up to the next multiple of `snap` minutes after midnight
+fn snap_up(time: NaiveDateTime, snap: u32) -> NaiveDateTime {
+    let midnight = time.date().and_time(NaiveTime::MIN);
+    let step = snap.max(1) as i64 * 60;
+    let seconds = (time - midnight).num_seconds();
+    midnight + Duration::seconds((seconds + step - 1) / step * step)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Local, Weekday};
+    use crate::settings::{ProtectedBlock, WorkHours};
+
+    // Monday 5 January 2026
+    fn monday() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 1, 5).unwrap()
+    }
+
+    fn at(day: NaiveDate, hour: u32, minute: u32) -> NaiveDateTime {
+        day.and_hms_opt(hour, minute, 0).unwrap()
+    }
+
+    fn task(title: &str, quadrant: Quadrant) -> Task {
+        let mut task = Task::new(title.to_string());
+        task.quadrant = quadrant;
+        task
+    }
+
+    fn scheduled(title: &str, start: NaiveDateTime, end: NaiveDateTime) -> Task {
+        let mut task = task(title, Quadrant::Schedule);
+        task.scheduled_start = start.and_local_timezone(Local).single();
+        task.scheduled_end = end.and_local_timezone(Local).single();
+        task
+    }
+
+    fn prefs() -> CalendarPrefs {
+        CalendarPrefs::default()
+    }
+
+    // Default working hours plus "Deep work" 9:00 – 11:00 on weekdays
+    fn deep_work() -> CalendarPrefs {
+        CalendarPrefs {
+            protected_blocks: vec![ProtectedBlock::default()],
+            ..CalendarPrefs::default()
+        }
+    }
+
+    fn block_of(plan: &Plan, id: Uuid) -> (NaiveDateTime, NaiveDateTime) {
+        let block = plan.blocks.iter().find(|b| b.task_id == id).expect("task was not placed");
+        (block.start, block.end)
+    }
+
+    #[test]
+    fn do_first_goes_before_schedule_at_the_start_of_the_working_day() {
+        let later = task("Later", Quadrant::Schedule);
+        let urgent = task("Urgent", Quadrant::DoFirst);
+        let plan = plan(&[later.clone(), urgent.clone()], &[], at(monday(), 8, 0), monday(), &prefs());
+
+        assert_eq!(block_of(&plan, urgent.id), (at(monday(), 9, 0), at(monday(), 10, 0)));
+        assert_eq!(block_of(&plan, later.id), (at(monday(), 10, 0), at(monday(), 11, 0)));
+        assert!(plan.skipped.is_empty());
+    }
+
+    #[test]
+    fn ignores_scheduled_done_and_other_quadrant_tasks() {
+        let mut done = task("Done", Quadrant::DoFirst);
+        done.status = TaskStatus::Done;
+        let delegate = task("Delegate", Quadrant::Delegate);
+        let inbox = task("Inbox", Quadrant::Unsorted);
+        let booked = scheduled("Booked", at(monday(), 13, 0), at(monday(), 14, 0));
+        let plan = plan(&[done, delegate, inbox, booked], &[], at(monday(), 8, 0), monday(), &prefs());
+
+        assert!(plan.blocks.is_empty());
+        assert!(plan.skipped.is_empty());
+    }
+
+    #[test]
+    fn uses_estimates_and_steps_around_existing_blocks() {
+        let meeting = scheduled("Meeting", at(monday(), 9, 30), at(monday(), 10, 15));
+        let mut short = task("Short", Quadrant::DoFirst);
+        short.estimate_minutes = Some(30);
+        let mut long = task("Long", Quadrant::DoFirst);
+        long.estimate_minutes = Some(90);
+        long.created_at = short.created_at + Duration::seconds(1);
+        let plan = plan(&[meeting, short.clone(), long.clone()], &[], at(monday(), 8, 0), monday(), &prefs());
+
+        assert_eq!(block_of(&plan, short.id), (at(monday(), 9, 0), at(monday(), 9, 30)));
+        assert_eq!(block_of(&plan, long.id), (at(monday(), 10, 15), at(monday(), 11, 45)));
+    }
+
+    #[test]
+    fn starts_from_now_rounded_up_to_the_snap() {
+        let now = task("Now", Quadrant::DoFirst);
+        let id = now.id;
+        let plan = plan(&[now], &[], at(monday(), 11, 7), monday(), &prefs());
+
+        assert_eq!(block_of(&plan, id).0, at(monday(), 11, 15));
+    }
+
+    #[test]
+    fn rolls_over_to_the_next_working_day_and_skips_weekends() {
+        let friday = monday() + Duration::days(4);
+        let next_monday = monday() + Duration::days(7);
+        let mut big = task("Big", Quadrant::Schedule);
+        big.estimate_minutes = Some(120);
+        let plan = plan(&[big.clone()], &[], at(friday, 16, 0), next_monday, &prefs());
+
+        assert_eq!(block_of(&plan, big.id), (at(next_monday, 9, 0), at(next_monday, 11, 0)));
+    }
+
+    #[test]
+    fn reports_no_room_when_nothing_fits() {
+        let mut huge = task("Huge", Quadrant::DoFirst);
+        huge.estimate_minutes = Some(9 * 60);
+        let plan = plan(&[huge.clone()], &[], at(monday(), 8, 0), monday() + Duration::days(4), &prefs());
+
+        assert!(plan.blocks.is_empty());
+        assert_eq!(plan.skipped, vec![(huge.id, Skipped::NoRoom)]);
+    }
+
+    #[test]
+    fn earlier_deadlines_go_first_and_late_slots_are_refused() {
+        let mut soon = task("Soon", Quadrant::Schedule);
+        soon.due = Some(monday());
+        soon.estimate_minutes = Some(8 * 60);
+        let mut impossible = task("Impossible", Quadrant::Schedule);
+        impossible.due = Some(monday());
+        impossible.created_at = soon.created_at + Duration::seconds(1);
+        let whenever = task("Whenever", Quadrant::Schedule);
+        let plan = plan(&[whenever.clone(), impossible.clone(), soon.clone()], &[], at(monday(), 8, 0), monday() + Duration::days(1), &prefs());
+
+        assert_eq!(block_of(&plan, soon.id), (at(monday(), 9, 0), at(monday(), 17, 0)));
+        assert_eq!(block_of(&plan, whenever.id).0, at(monday() + Duration::days(1), 9, 0));
+        assert_eq!(plan.skipped, vec![(impossible.id, Skipped::MissesDeadline)]);
+    }
+
+    #[test]
+    fn waits_for_dependencies_even_across_quadrants() {
+        let first = task("First", Quadrant::Schedule);
+        let mut second = task("Second", Quadrant::DoFirst);
+        second.depends_on = vec![first.id];
+        let plan = plan(&[second.clone(), first.clone()], &[], at(monday(), 8, 0), monday(), &prefs());
+
+        assert_eq!(block_of(&plan, first.id), (at(monday(), 9, 0), at(monday(), 10, 0)));
+        assert_eq!(block_of(&plan, second.id), (at(monday(), 10, 0), at(monday(), 11, 0)));
+    }
+
+    #[test]
+    fn starts_after_a_scheduled_dependency_ends() {
+        let prep = scheduled("Prep", at(monday(), 14, 0), at(monday(), 15, 0));
+        let mut follow_up = task("Follow-up", Quadrant::DoFirst);
+        follow_up.depends_on = vec![prep.id];
+        let plan = plan(&[prep.clone(), follow_up.clone()], &[], at(monday(), 8, 0), monday(), &prefs());
+
+        assert_eq!(block_of(&plan, follow_up.id).0, at(monday(), 15, 0));
+    }
+
+    #[test]
+    fn finished_or_missing_dependencies_do_not_hold_a_task_back() {
+        let mut done = task("Done", Quadrant::Schedule);
+        done.status = TaskStatus::Done;
+        let mut free = task("Free", Quadrant::DoFirst);
+        free.depends_on = vec![done.id, Uuid::new_v4()];
+        let plan = plan(&[done, free.clone()], &[], at(monday(), 8, 0), monday(), &prefs());
+
+        assert_eq!(block_of(&plan, free.id).0, at(monday(), 9, 0));
+    }
+
+    #[test]
+    fn blocked_tasks_name_the_dependency_that_got_no_block() {
+        let delegated = task("Delegated", Quadrant::Delegate);
+        let mut waits = task("Waits", Quadrant::DoFirst);
+        waits.depends_on = vec![delegated.id];
+        let mut a = task("A", Quadrant::Schedule);
+        let mut b = task("B", Quadrant::Schedule);
+        a.depends_on = vec![b.id];
+        b.depends_on = vec![a.id];
+        let plan = plan(&[delegated.clone(), waits.clone(), a.clone(), b.clone()], &[], at(monday(), 8, 0), monday(), &prefs());
+
+        assert!(plan.blocks.is_empty());
+        assert!(plan.skipped.contains(&(waits.id, Skipped::Blocked(delegated.id))));
+        assert!(plan.skipped.contains(&(a.id, Skipped::Blocked(b.id))));
+        assert!(plan.skipped.contains(&(b.id, Skipped::Blocked(a.id))));
+    }
+
+    #[test]
+    fn follows_custom_working_hours_and_days() {
+        let prefs = CalendarPrefs {
+            work_hours: vec![WorkHours { day: Weekday::Wed, start_hour: 13, end_hour: 15 }],
+            ..CalendarPrefs::default()
+        };
+        let job = task("Job", Quadrant::DoFirst);
+        let id = job.id;
+        let plan = plan(&[job], &[], at(monday(), 8, 0), monday() + Duration::days(6), &prefs);
+
+        let wednesday = monday() + Duration::days(2);
+        assert_eq!(block_of(&plan, id), (at(wednesday, 13, 0), at(wednesday, 14, 0)));
+    }
+
+    #[test]
+    fn working_hours_can_differ_per_weekday() {
+        let prefs = CalendarPrefs {
+            work_hours: vec![
+                WorkHours { day: Weekday::Mon, start_hour: 9, end_hour: 10 },
+                WorkHours { day: Weekday::Tue, start_hour: 7, end_hour: 12 },
+            ],
+            ..CalendarPrefs::default()
+        };
+        let mut a = task("A", Quadrant::DoFirst);
+        a.estimate_minutes = Some(60);
+        let mut b = task("B", Quadrant::DoFirst);
+        b.created_at = a.created_at + Duration::seconds(1);
+        let plan = plan(&[a.clone(), b.clone()], &[], at(monday(), 8, 0), monday() + Duration::days(1), &prefs);
+
+        let tuesday = monday() + Duration::days(1);
+        assert_eq!(block_of(&plan, a.id).0, at(monday(), 9, 0));
+        assert_eq!(block_of(&plan, b.id).0, at(tuesday, 7, 0));
+    }
+
+    #[test]
+    fn imported_meetings_are_busy_time() {
+        let mut job = task("Job", Quadrant::DoFirst);
+        job.estimate_minutes = Some(60);
+        let id = job.id;
+        let standup = BusyBlock {
+            source: "Work".to_string(),
+            uid: "standup".to_string(),
+            title: "Standup".to_string(),
+            start: at(monday(), 9, 0).and_local_timezone(Local).unwrap(),
+            end: at(monday(), 9, 30).and_local_timezone(Local).unwrap(),
+        };
+        let plan = plan(&[job], &[standup], at(monday(), 8, 0), monday(), &prefs());
+
+        assert_eq!(block_of(&plan, id), (at(monday(), 9, 30), at(monday(), 10, 30)));
+    }
+
+    #[test]
+    fn protected_blocks_stay_clear() {
+        let mut job = task("Job", Quadrant::DoFirst);
+        job.estimate_minutes = Some(90);
+        let id = job.id;
+        let plan = plan(&[job], &[], at(monday(), 8, 0), monday(), &deep_work());
+
+        assert_eq!(block_of(&plan, id), (at(monday(), 11, 0), at(monday(), 12, 30)));
+    }
+
+    #[test]
+    fn protected_blocks_follow_their_days() {
+        let prefs = deep_work();
+        let saturday = monday() + Duration::days(5);
+        assert_eq!(protected_on(&prefs, monday()), vec![("Deep work", at(monday(), 9, 0), at(monday(), 11, 0))]);
+        assert!(protected_on(&prefs, saturday).is_empty());
+        assert_eq!(protected_hits(&prefs, at(monday(), 10, 30), at(monday(), 11, 30)), vec!["Deep work"]);
+        assert!(protected_hits(&prefs, at(monday(), 11, 0), at(monday(), 12, 0)).is_empty());
+    }
+
+    #[test]
+    fn work_hours_check_both_ends_and_days_off() {
+        let prefs = prefs();
+        let sunday = monday() - Duration::days(1);
+        assert!(in_work_hours(&prefs, at(monday(), 9, 0), at(monday(), 17, 0)));
+        assert!(!in_work_hours(&prefs, at(monday(), 8, 45), at(monday(), 9, 45)));
+        assert!(!in_work_hours(&prefs, at(monday(), 16, 30), at(monday(), 17, 30)));
+        assert!(!in_work_hours(&prefs, at(sunday, 10, 0), at(sunday, 11, 0)));
+    }
+
+    #[test]
+    fn next_free_slot_skips_off_hours_protected_time_and_other_blocks() {
+        let prefs = deep_work();
+        let lunch = scheduled("Lunch", at(monday(), 11, 0), at(monday(), 12, 0));
+        let moved = scheduled("Moved", at(monday(), 7, 0), at(monday(), 8, 0));
+        let hour = Duration::hours(1);
+
+        // Availability only: the first minute after deep work
+        assert_eq!(next_free_slot(&[], &[], None, at(monday(), 7, 0), hour, &prefs), Some(at(monday(), 11, 0)));
+        // With tasks: past lunch too, but never blocked by the task being moved
+        let tasks = [lunch, moved.clone()];
+        assert_eq!(next_free_slot(&tasks, &[], Some(moved.id), at(monday(), 7, 0), hour, &prefs), Some(at(monday(), 12, 0)));
+        // Late in the day rolls over to the next morning's free time
+        assert_eq!(next_free_slot(&[], &[], None, at(monday(), 16, 30), hour, &prefs), Some(at(monday() + Duration::days(1), 11, 0)));
+    }
+}
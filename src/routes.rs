@@ -1,6 +1,6 @@
 use dioxus::prelude::*;
 use crate::components::layout::{SidebarLayout, PageNotFound};
-use crate::views::{Inbox, Matrix, Calendar, Credits, Tutorial};
+use crate::views::{Inbox, Matrix, Triage, Calendar, CalendarDay, CalendarMonth, CalendarAgenda, Archive, Perspective, Settings, Credits, Tutorial};
 
 /// NAVIGATION MAP: This defines all the pages in our application and their web addresses
 #[derive(Clone, Routable, Debug, PartialEq)]
@@ -13,8 +13,29 @@ pub enum Route {
         #[route("/matrix")] // The Eisenhower Matrix priority view
         Matrix {},
         
+        #[route("/triage")] // One-task-at-a-time keyboard sorting of the backlog
+        Triage {},
+        
         #[route("/calendar")] // The weekly time-blocking view
         Calendar {},
+
+        #[route("/calendar/day/:date")] // One day at 5-minute resolution, e.g. /calendar/day/2026-10-18
+        CalendarDay { date: chrono::NaiveDate },
+
+        #[route("/calendar/month")] // Month grid with compact task chips
+        CalendarMonth {},
+
+        #[route("/calendar/agenda")] // Upcoming blocks as a list, grouped by day
+        CalendarAgenda {},
+        
+        #[route("/archive")] // Searchable history of finished and archived tasks
+        Archive {},
+        
+        #[route("/filters/:id")] // One saved filter (perspective) from the sidebar
+        Perspective { id: uuid::Uuid },
+        
+        #[route("/settings")] // Preferences stored in settings.json
+        Settings {},
         
         #[route("/credits")] // About the creators
         Credits {},
@@ -0,0 +1,51 @@
+// SEARCH: Small fuzzy matcher shared by the command palette.
+// The query's characters must appear in order in the text; matches that are
+// consecutive or start a word score higher, so "wrp" ranks "Write report" above "swap".
+
+use crate::models::Task;
+
+/// FUZZY SCORE: None if `query` is not a subsequence of `text`, otherwise a score (higher is better)
+pub fn fuzzy_score(query: &str, text: &str) -> Option<i32> {
+    let query: Vec<char> = query.to_lowercase().chars().filter(|c| !c.is_whitespace()).collect();
+    if query.is_empty() {
+        return Some(0);
+    }
+    let text: Vec<char> = text.to_lowercase().chars().collect();
+
+    let mut score = 0;
+    let mut qi = 0;
+    let mut last_match: Option<usize> = None;
+    for (ti, ch) in text.iter().enumerate() {
+        if qi == query.len() {
+            break;
+        }
+        if *ch != query[qi] {
+            continue;
+        }
+        score += 1;
+        // Bonus for runs of consecutive characters
+        if last_match.is_some_and(|last| last + 1 == ti) {
+            score += 4;
+        }
+        // Bonus for hitting the start of a word
+        if ti == 0 || !text[ti - 1].is_alphanumeric() {
+            score += 6;
+        }
+        last_match = Some(ti);
+        qi += 1;
+    }
+
+    if qi < query.len() {
+        return None;
+    }
+    // Prefer shorter texts when everything else is equal
+    Some(score * 10 - text.len().min(100) as i32 / 10)
+}
+
+/// TASK SCORE: Best match across the title, the notes and the tags (titles weigh the most)
+pub fn task_score(query: &str, task: &Task) -> Option<i32> {
+    let title = fuzzy_score(query, &task.title).map(|s| s + 20);
+    let notes = task.description.as_deref().and_then(|d| fuzzy_score(query, d));
+    let tags = task.tags.iter().filter_map(|tag| fuzzy_score(query.trim_start_matches('#'), tag).map(|s| s + 10)).max();
+    [title, notes, tags].into_iter().flatten().max()
+}
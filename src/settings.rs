@@ -0,0 +1,525 @@
+use crate::filters::SavedFilter;
+use crate::i18n::{Clock, I18n, Locale};
+use crate::keymap::Keymap;
+use crate::models::Quadrant;
+use crate::store::data_file_path;
+use crate::theme::Theme;
+use chrono::{Datelike, Local, NaiveDate, Weekday};
+use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::fs;
+
+// User preferences live next to tasks.json in the data folder
+const SETTINGS_FILE: &str = "settings.json";
+// Where a settings file that failed to parse is copied before the defaults can replace it
+const SETTINGS_BACKUP_FILE: &str = "settings.json.bak";
+// How many weekly Delete-quadrant samples we keep around
+const DELETE_SAMPLE_WEEKS: usize = 8;
+// Consecutive weeks of growth before we nudge the user to purge
+const DELETE_GROWTH_WEEKS: usize = 3;
+
+/// WIP LIMITS: Optional cap on the number of open tasks per quadrant (None = unlimited)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WipLimits {
+    pub do_first: Option<usize>,
+    pub schedule: Option<usize>,
+    pub delegate: Option<usize>,
+    pub delete: Option<usize>,
+}
+
+impl Default for WipLimits {
+    fn default() -> Self {
+        Self {
+            do_first: Some(5), // Do First is only useful when it stays short
+            schedule: None,
+            delegate: None,
+            delete: None,
+        }
+    }
+}
+
+impl WipLimits {
+    /// The cap for a quadrant (the backlog is never capped)
+    pub fn get(&self, q: &Quadrant) -> Option<usize> {
+        match q {
+            Quadrant::DoFirst => self.do_first,
+            Quadrant::Schedule => self.schedule,
+            Quadrant::Delegate => self.delegate,
+            Quadrant::Delete => self.delete,
+            Quadrant::Unsorted => None,
+        }
+    }
+
+    fn slot(&mut self, q: &Quadrant) -> Option<&mut Option<usize>> {
+        match q {
+            Quadrant::DoFirst => Some(&mut self.do_first),
+            Quadrant::Schedule => Some(&mut self.schedule),
+            Quadrant::Delegate => Some(&mut self.delegate),
+            Quadrant::Delete => Some(&mut self.delete),
+            Quadrant::Unsorted => None,
+        }
+    }
+}
+
+/// DELETE SAMPLE: How many tasks sat in the Delete quadrant during one week
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DeleteSample {
+    pub week_start: NaiveDate,
+    pub count: usize,
+}
+
+/// DELETE WATCH: Weekly history used to spot a Delete quadrant that never gets purged
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DeleteWatch {
+    pub samples: Vec<DeleteSample>,
+    pub snoozed_until: Option<NaiveDate>,
+}
+
+/// WORKING HOURS: The working time of one weekday (days without an entry are off)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WorkHours {
+    pub day: Weekday,
+    pub start_hour: u32, // 0 – 23
+    pub end_hour: u32,   // 1 – 24, after start_hour
+}
+
+/// PROTECTED BLOCK: Recurring time kept clear of tasks, e.g. "Deep work" 9:00 – 11:00 on
+/// weekdays or "No meetings" all day Friday
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProtectedBlock {
+    pub label: String,
+    pub days: Vec<Weekday>,
+    pub start_minute: u32, // Minutes after midnight
+    pub end_minute: u32,   // 1 – 1440, after start_minute
+}
+
+impl Default for ProtectedBlock {
+    fn default() -> Self {
+        Self {
+            label: "Deep work".to_string(),
+            days: vec![Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu, Weekday::Fri],
+            start_minute: 9 * 60,
+            end_minute: 11 * 60,
+        }
+    }
+}
+
+/// CALENDAR PREFERENCES: How the time grid is laid out and how drops behave
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CalendarPrefs {
+    pub day_start_hour: u32,        // First hour shown on the grid (0 – 23)
+    pub day_end_hour: u32,          // Hour the grid stops at (1 – 24, after day_start_hour)
+    pub week_starts_on: Weekday,    // First column of the week
+    pub default_block_minutes: i64, // Length of a task dropped on the grid for the first time
+    pub snap_minutes: u32,          // Drop / resize resolution; always divides 60
+    pub work_hours: Vec<WorkHours>, // Working time per weekday, shaded on the grid and filled by "Plan my week"
+    pub protected_blocks: Vec<ProtectedBlock>, // Recurring focus time that drops and the planner keep clear
+    pub snap_to_work_hours: bool,   // Drops outside working hours move to the next open slot (false = warn)
+    pub collapse_off_hours: bool,   // The grid shows only the working hours instead of the visible hours
+}
+
+impl Default for CalendarPrefs {
+    fn default() -> Self {
+        Self {
+            day_start_hour: 0,
+            day_end_hour: 24,
+            week_starts_on: Weekday::Mon,
+            default_block_minutes: 60,
+            snap_minutes: 15,
+            work_hours: [Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu, Weekday::Fri]
+                .into_iter()
+                .map(|day| WorkHours { day, start_hour: 9, end_hour: 17 })
+                .collect(),
+            protected_blocks: Vec::new(),
+            snap_to_work_hours: false,
+            collapse_off_hours: false,
+        }
+    }
+}
+
+impl CalendarPrefs {
+    /// The first day of the week containing `date`
+    pub fn week_start_for(&self, date: NaiveDate) -> NaiveDate {
+        let back = (7 + date.weekday().num_days_from_monday() - self.week_starts_on.num_days_from_monday()) % 7;
+        date - chrono::Duration::days(back as i64)
+    }
+
+    /// First and end hour of the grid: the visible hours, or the span of the working hours when collapsed
+    pub fn visible_range(&self) -> (u32, u32) {
+        let work_start = self.work_hours.iter().map(|w| w.start_hour).min();
+        let work_end = self.work_hours.iter().map(|w| w.end_hour).max();
+        match (self.collapse_off_hours, work_start, work_end) {
+            (true, Some(start), Some(end)) => (start, end),
+            _ => (self.day_start_hour, self.day_end_hour),
+        }
+    }
+
+    /// Number of hour rows on the grid
+    pub fn visible_hours(&self) -> u32 {
+        let (start, end) = self.visible_range();
+        end.saturating_sub(start).max(1)
+    }
+
+    /// Working time of a weekday as (start hour, end hour), None on a day off
+    pub fn work_hours_on(&self, day: Weekday) -> Option<(u32, u32)> {
+        self.work_hours.iter().find(|w| w.day == day).map(|w| (w.start_hour, w.end_hour))
+    }
+
+    /// The default block length as a chrono Duration
+    pub fn default_block(&self) -> chrono::Duration {
+        chrono::Duration::minutes(self.default_block_minutes)
+    }
+
+    /// Clamps values edited by hand in settings.json into a usable range
+    fn sanitize(&mut self) {
+        self.day_start_hour = self.day_start_hour.min(23);
+        self.day_end_hour = self.day_end_hour.clamp(self.day_start_hour + 1, 24);
+        self.default_block_minutes = self.default_block_minutes.clamp(5, 24 * 60);
+        if self.snap_minutes == 0 || 60 % self.snap_minutes != 0 {
+            self.snap_minutes = 15;
+        }
+        for hours in &mut self.work_hours {
+            hours.start_hour = hours.start_hour.min(23);
+            hours.end_hour = hours.end_hour.clamp(hours.start_hour + 1, 24);
+        }
+        for block in &mut self.protected_blocks {
+            block.start_minute = block.start_minute.min(24 * 60 - 1);
+            block.end_minute = block.end_minute.clamp(block.start_minute + 1, 24 * 60);
+        }
+    }
+}
+
+/// ICS EXPORT PREFERENCES: What the calendar export writes; the export dialog remembers the last choices
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExportPrefs {
+    pub quadrants: Vec<Quadrant>,      // Only tasks in these quadrants are exported
+    pub as_todos: bool,                // VTODO items instead of VEVENT
+    pub reminder_minutes: Option<u32>, // VALARM this long before the start (None = no reminder)
+}
+
+impl Default for ExportPrefs {
+    fn default() -> Self {
+        Self {
+            quadrants: vec![Quadrant::DoFirst, Quadrant::Schedule],
+            as_todos: false,
+            reminder_minutes: Some(15),
+        }
+    }
+}
+
+/// CALENDAR FEED: The live ICS subscription served on 127.0.0.1 (off by default)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FeedPrefs {
+    pub enabled: bool,
+    pub port: u16,     // Loopback port the feed listens on
+    pub token: String, // Secret part of the URL; empty until the feed is first switched on
+}
+
+impl Default for FeedPrefs {
+    fn default() -> Self {
+        Self { enabled: false, port: 8466, token: String::new() }
+    }
+}
+
+/// CALDAV SYNC: The self-hosted calendar collection tasks are synced with (empty URL = off)
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CalDavPrefs {
+    pub url: String,      // The collection, e.g. https://cloud.example.com/remote.php/dav/calendars/me/tasks/
+    pub username: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub password: String, // Only used (and written here) when the OS keyring isn't available
+    pub in_keyring: bool, // The password is in the OS keyring, see caldav::store_password
+}
+
+/// SETTINGS: Everything the user can configure, saved as settings.json
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    pub wip_limits: WipLimits,
+    pub delete_watch: DeleteWatch,
+    pub stale_after_days: i64, // Tasks untouched this long count as stale
+    pub archive_after_days: i64, // Done tasks move to the archive after this many days
+    pub saved_filters: Vec<SavedFilter>, // Named perspectives listed in the sidebar
+    pub keymap: Keymap, // Shortcut overrides, see keymap.rs for the combo format
+    pub calendar: CalendarPrefs,
+    pub export: ExportPrefs,
+    pub feed: FeedPrefs,
+    pub caldav: CalDavPrefs,
+    pub window_width: f64,  // Initial window size, applied on the next launch
+    pub window_height: f64,
+    pub theme: String,             // Name of the active theme, or "system" to follow the OS
+    pub custom_themes: Vec<Theme>, // Themes imported from files
+    pub locale: Locale,            // Interface language
+    pub clock: Clock,              // 12- or 24-hour times
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        // First launch follows the OS language, with that language's week and clock
+        let locale = Locale::from_system();
+        Self {
+            wip_limits: WipLimits::default(),
+            delete_watch: DeleteWatch::default(),
+            stale_after_days: 14,
+            archive_after_days: 7,
+            saved_filters: Vec::new(),
+            keymap: Keymap::default(),
+            calendar: CalendarPrefs {
+                week_starts_on: locale.default_week_start(),
+                ..CalendarPrefs::default()
+            },
+            export: ExportPrefs::default(),
+            feed: FeedPrefs::default(),
+            caldav: CalDavPrefs::default(),
+            window_width: 1280.0,
+            window_height: 800.0,
+            theme: "Deep Space".to_string(),
+            custom_themes: Vec::new(),
+            locale,
+            clock: locale.default_clock(),
+        }
+    }
+}
+
+/// LOAD PROBLEM: settings.json existed but couldn't be parsed
+#[derive(Debug, Clone, PartialEq)]
+pub struct LoadProblem {
+    pub message: String,
+    pub backed_up: bool, // Copied to settings.json.bak; when false the file is never written over
+}
+
+/// SETTINGS STATE: Shared, reactive access to the user's settings (provided next to AppState)
+#[derive(Clone, Copy)]
+pub struct SettingsState {
+    pub settings: Signal<Settings>,
+    pub load_problem: Signal<Option<LoadProblem>>, // Why settings.json couldn't be read, shown on the settings page
+}
+
+impl SettingsState {
+    /// INITIALIZATION: Loads settings.json, falling back to defaults
+    pub fn new() -> Self {
+        let (settings, load_problem) = Self::read();
+        let state = Self {
+            settings: Signal::new(settings),
+            load_problem: Signal::new(load_problem),
+        };
+        // A password typed before the keyring was used (or while it was unavailable) moves there now
+        let password = state.settings.peek().caldav.password.clone();
+        if !password.is_empty() {
+            let _ = state.set_caldav_password(password);
+        }
+        state
+    }
+
+    /// LOADING: Reads the settings file (missing or broken files give the defaults)
+    pub fn load() -> Settings {
+        Self::read().0
+    }
+
+    /// Reads the settings file. One that exists but doesn't parse is copied to settings.json.bak
+    /// before anything can save the defaults over it, and the parse error is handed back.
+    fn read() -> (Settings, Option<LoadProblem>) {
+        let path = data_file_path(SETTINGS_FILE);
+        let Ok(content) = fs::read_to_string(&path) else {
+            return (Settings::default(), None);
+        };
+        let (mut settings, problem) = match serde_json::from_str::<Settings>(&content) {
+            Ok(settings) => (settings, None),
+            Err(e) => {
+                let backup = data_file_path(SETTINGS_BACKUP_FILE);
+                let problem = match fs::copy(&path, &backup) {
+                    Ok(_) => LoadProblem {
+                        message: format!("{e}. The file was copied to {} and defaults are in use.", backup.display()),
+                        backed_up: true,
+                    },
+                    Err(copy_error) => LoadProblem {
+                        message: format!("{e}. It could not be backed up ({copy_error}), so changes won't be saved."),
+                        backed_up: false,
+                    },
+                };
+                tracing::warn!("settings.json: {}", problem.message);
+                (Settings::default(), Some(problem))
+            }
+        };
+        settings.calendar.sanitize();
+        (settings, problem)
+    }
+
+    /// SAVING: Writes the current settings to disk. A file that failed to load and couldn't be
+    /// backed up is left alone, so the user's hand edits (and credentials) are never lost.
+    pub fn save(&self) {
+        if self.load_problem.peek().as_ref().is_some_and(|p| !p.backed_up) {
+            return;
+        }
+        let settings = self.settings.peek(); // peek: saving from an effect must not subscribe it to the settings
+        if let Ok(content) = serde_json::to_string_pretty(&*settings) {
+            let _ = fs::write(data_file_path(SETTINGS_FILE), content);
+        }
+    }
+
+    /// The active language and clock (subscribes the caller to changes)
+    pub fn i18n(&self) -> I18n {
+        let settings = self.settings.read();
+        I18n { locale: settings.locale, clock: settings.clock }
+    }
+
+    /// ACTION - LANGUAGE: Switches language and adopts its usual clock and first day of the week
+    pub fn set_locale(&self, locale: Locale) {
+        self.update(|s| {
+            s.locale = locale;
+            s.clock = locale.default_clock();
+            s.calendar.week_starts_on = locale.default_week_start();
+        });
+    }
+
+    /// ACTION - UPDATE: Applies any change from the settings page and saves it
+    pub fn update(&self, change: impl FnOnce(&mut Settings)) {
+        let mut settings_sig = self.settings;
+        let mut settings = settings_sig.write();
+        change(&mut settings);
+        settings.calendar.sanitize();
+        drop(settings);
+        self.save();
+    }
+
+    /// ACTION - CALDAV PASSWORD: Saves the password in the OS keyring. Without a keyring it is kept in
+    /// settings.json instead, and the error says why so the settings page can warn about it.
+    pub fn set_caldav_password(&self, password: String) -> Result<(), String> {
+        match crate::caldav::store_password(&password) {
+            Ok(()) => {
+                self.update(|s| {
+                    s.caldav.in_keyring = !password.is_empty();
+                    s.caldav.password.clear();
+                });
+                Ok(())
+            }
+            Err(e) => {
+                self.update(|s| {
+                    s.caldav.in_keyring = false;
+                    s.caldav.password = password;
+                });
+                Err(e)
+            }
+        }
+    }
+
+    /// ACTION - IMPORT THEME: Adds (or replaces) a theme read from a file and switches to it.
+    /// A theme with a color that isn't "#rrggbb" is refused and never saved.
+    pub fn import_theme(&self, mut theme: Theme) -> Result<(), String> {
+        theme.validate()?;
+        if Theme::built_in().iter().any(|t| t.name == theme.name) {
+            theme.name = format!("{} (custom)", theme.name);
+        }
+        self.update(|s| {
+            s.custom_themes.retain(|t| t.name != theme.name);
+            s.theme = theme.name.clone();
+            s.custom_themes.push(theme);
+        });
+        Ok(())
+    }
+
+    /// ACTION - WIP LIMIT: Sets (or removes, with None) the cap for a quadrant
+    pub fn set_wip_limit(&self, quadrant: &Quadrant, limit: Option<usize>) {
+        let mut settings_sig = self.settings;
+        let mut settings = settings_sig.write();
+        if let Some(slot) = settings.wip_limits.slot(quadrant) {
+            *slot = limit;
+        }
+        drop(settings);
+        self.save();
+    }
+
+    /// ACTION - STALE THRESHOLD: Sets how many idle days make a task stale
+    pub fn set_stale_after_days(&self, days: i64) {
+        let mut settings_sig = self.settings;
+        settings_sig.write().stale_after_days = days.max(1);
+        self.save();
+    }
+
+    /// ACTION - ARCHIVE DELAY: Sets how long Done tasks linger before being archived (0 = right away)
+    pub fn set_archive_after_days(&self, days: i64) {
+        let mut settings_sig = self.settings;
+        settings_sig.write().archive_after_days = days.max(0);
+        self.save();
+    }
+
+    /// ACTION - SAVE FILTER: Stores a perspective, replacing the one with the same id
+    pub fn save_filter(&self, filter: SavedFilter) {
+        let mut settings_sig = self.settings;
+        let mut settings = settings_sig.write();
+        match settings.saved_filters.iter_mut().find(|f| f.id == filter.id) {
+            Some(existing) => *existing = filter,
+            None => settings.saved_filters.push(filter),
+        }
+        drop(settings);
+        self.save();
+    }
+
+    /// ACTION - DELETE FILTER: Removes a perspective from the sidebar
+    pub fn delete_filter(&self, id: uuid::Uuid) {
+        let mut settings_sig = self.settings;
+        settings_sig.write().saved_filters.retain(|f| f.id != id);
+        self.save();
+    }
+
+    /// ACTION - DELETE SAMPLE: Records this week's Delete-quadrant size.
+    /// The current week's sample is overwritten, so each week keeps its latest count.
+    pub fn record_delete_count(&self, count: usize) {
+        let week_start = current_week_start();
+        // Compare before writing, so an unchanged count doesn't wake every settings reader
+        let unchanged = self.settings.peek().delete_watch.samples.last().is_some_and(|last| last.week_start == week_start && last.count == count);
+        if unchanged {
+            return;
+        }
+        let mut settings_sig = self.settings;
+        let mut settings = settings_sig.write();
+        let samples = &mut settings.delete_watch.samples;
+        match samples.last_mut() {
+            Some(last) if last.week_start == week_start => last.count = count,
+            _ => samples.push(DeleteSample { week_start, count }),
+        }
+        if samples.len() > DELETE_SAMPLE_WEEKS {
+            let excess = samples.len() - DELETE_SAMPLE_WEEKS;
+            samples.drain(..excess);
+        }
+        drop(settings);
+        self.save();
+    }
+
+    /// NUDGE CHECK: The last few weekly counts if the Delete quadrant grew every week
+    /// (and the user hasn't snoozed the warning this week). Weeks without a sample break the run.
+    pub fn delete_growth(&self) -> Option<Vec<usize>> {
+        let settings = self.settings.read();
+        let watch = &settings.delete_watch;
+        if watch.snoozed_until.is_some_and(|until| Local::now().date_naive() < until) {
+            return None;
+        }
+        if watch.samples.len() < DELETE_GROWTH_WEEKS {
+            return None;
+        }
+        let recent = &watch.samples[watch.samples.len() - DELETE_GROWTH_WEEKS..];
+        let growing = recent
+            .windows(2)
+            .all(|w| w[1].week_start == w[0].week_start + chrono::Duration::weeks(1) && w[1].count > w[0].count);
+        growing.then(|| recent.iter().map(|s| s.count).collect())
+    }
+
+    /// ACTION - SNOOZE: Hides the Delete nudge until next week
+    pub fn snooze_delete_nudge(&self) {
+        let mut settings_sig = self.settings;
+        settings_sig.write().delete_watch.snoozed_until = Some(current_week_start() + chrono::Duration::weeks(1));
+        self.save();
+    }
+}
+
+/// Monday of the current week
+fn current_week_start() -> NaiveDate {
+    let today = Local::now().date_naive();
+    today - chrono::Duration::days(today.weekday().num_days_from_monday() as i64)
+}
@@ -1,6 +1,8 @@
-use crate::models::{Task, Quadrant, TaskStatus};
+use crate::models::{BusyBlock, Task, Quadrant, TaskStatus};
+use crate::settings::WipLimits;
 use dioxus::prelude::*;
 use directories::ProjectDirs;
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::fs;
 use std::path::PathBuf;
 
@@ -9,34 +11,139 @@ const APP_QUALIFIER: &str = "ca";
 const APP_ORG: &str = "mutabie";
 const APP_NAME: &str = "errday";
 const DATA_FILE: &str = "tasks.json";
+const ARCHIVE_FILE: &str = "archive.json";
+const BUSY_FILE: &str = "busy.json"; // Meetings imported from other calendars
+const UNDO_DEPTH: usize = 50; // How many steps back Undo can go
+
+/// DATA LOCATION: Finds or creates the folder where we save our mission data and
+/// returns the path of `file_name` inside it
+pub fn data_file_path(file_name: &str) -> PathBuf {
+    // We use standard platform-specific directories (e.g., Application Support on macOS)
+    if let Some(proj_dirs) = ProjectDirs::from(APP_QUALIFIER, APP_ORG, APP_NAME) {
+        let data_dir = proj_dirs.data_dir();
+        // Ensure the directory exists before we try to write to it
+        if !data_dir.exists() {
+            let _ = fs::create_dir_all(data_dir);
+        }
+        return data_dir.join(file_name);
+    }
+    PathBuf::from(file_name) // Fallback to the current directory if we can't find the home folder
+}
+
+/// PENDING MOVE: Tasks headed for a quadrant with no room left, waiting for the demote prompt
+#[derive(Clone, PartialEq)]
+pub struct PendingMove {
+    pub ids: HashSet<uuid::Uuid>,
+    pub quadrant: Quadrant,
+    pub limit: Option<usize>,       // The target's WIP limit when the move was asked for
+    pub overflow: usize,            // How many residents must leave to stay within the limit
+    pub short: usize,               // How far over the limit the quadrant stays even with every resident gone
+    pub scores: Option<(f32, f32)>, // New (urgency, importance) when a task was placed on the plot
+    pub slots: HashMap<uuid::Uuid, (chrono::DateTime<chrono::Local>, chrono::DateTime<chrono::Local>)>, // Calendar blocks set along with the move (bulk schedule)
+}
+
+impl PendingMove {
+    /// A plain move of `ids` to `quadrant` (the WIP fields are filled in by the store)
+    pub fn new(ids: HashSet<uuid::Uuid>, quadrant: Quadrant) -> Self {
+        Self { ids, quadrant, limit: None, overflow: 0, short: 0, scores: None, slots: HashMap::new() }
+    }
+}
+
+/// WIP CHECK: For moving `ids` into `quadrant` under `limit`, returns (residents to demote, how far over
+/// the limit it stays even after demoting every one of them). (0, 0) = fits. Only open tasks count:
+/// Done tasks don't use the cap, and tasks already in the quadrant don't count twice.
+fn wip_overflow(tasks: &[Task], ids: &HashSet<uuid::Uuid>, quadrant: &Quadrant, limit: Option<usize>) -> (usize, usize) {
+    let Some(limit) = limit else { return (0, 0) };
+    let open = |t: &&Task| t.status == TaskStatus::Todo;
+    let incoming = tasks.iter().filter(open).filter(|t| ids.contains(&t.id) && t.quadrant != *quadrant).count();
+    if incoming == 0 {
+        return (0, 0);
+    }
+    let resident = tasks.iter().filter(open).filter(|t| t.quadrant == *quadrant).count();
+    // Residents that are part of the move stay, so they can't make room
+    let demotable = tasks.iter().filter(open).filter(|t| t.quadrant == *quadrant && !ids.contains(&t.id)).count();
+    let excess = (resident + incoming).saturating_sub(limit);
+    let overflow = excess.min(demotable);
+    (overflow, excess - overflow)
+}
+
+/// UNDO SNAPSHOT: (checkpoint number, tasks, archive) as they were before a change
+type Snapshot = (u64, Vec<Task>, Vec<Task>);
 
 /// APP STATE: The central hub for all application data
 #[derive(Clone, Copy)]
 pub struct AppState {
-    pub tasks: Signal<Vec<Task>>, // A reactive list of tasks that updates the UI automatically
+    pub tasks: Signal<Vec<Task>>,   // A reactive list of tasks that updates the UI automatically
+    pub archive: Signal<Vec<Task>>, // Tasks put away for good, kept out of the everyday views
+    pub busy: Signal<Vec<BusyBlock>>, // Read-only meetings from imported calendars (not part of undo)
+    pub pending_move: Signal<Option<PendingMove>>, // A quadrant change held back by a WIP limit
+    pub queued_moves: Signal<VecDeque<PendingMove>>, // Moves asked for while the prompt was open, handled next
+    undo_stack: Signal<Vec<Snapshot>>, // Taken before each change, newest last
+    checkpoints_taken: Signal<u64>, // Numbers the snapshots so a caller can tell whether its own is still the latest
 }
 
 impl AppState {
     /// INITIALIZATION: Starts the state by loading saved tasks from the disk
     pub fn new() -> Self {
         let tasks = Self::load_tasks();
+        let archive = Self::load_archive();
+        let busy = Self::load_busy();
         Self {
             tasks: Signal::new(tasks),
+            archive: Signal::new(archive),
+            busy: Signal::new(busy),
+            pending_move: Signal::new(None),
+            queued_moves: Signal::new(VecDeque::new()),
+            undo_stack: Signal::new(Vec::new()),
+            checkpoints_taken: Signal::new(0),
         }
     }
 
-    /// DATA LOCATION: Finds or creates the folder where we save our mission data
-    fn get_data_path() -> PathBuf {
-        // We use standard platform-specific directories (e.g., Application Support on macOS)
-        if let Some(proj_dirs) = ProjectDirs::from(APP_QUALIFIER, APP_ORG, APP_NAME) {
-            let data_dir = proj_dirs.data_dir();
-            // Ensure the directory exists before we try to write to it
-            if !data_dir.exists() {
-                let _ = fs::create_dir_all(data_dir);
-            }
-            return data_dir.join(DATA_FILE);
+    /// CHECKPOINT: Remembers the current data so the next change can be undone.
+    /// Public for gestures made of many small updates (e.g. stretching a block), which take one checkpoint up front.
+    pub fn checkpoint(&self) {
+        let mut taken_sig = self.checkpoints_taken;
+        let serial = *taken_sig.peek() + 1;
+        taken_sig.set(serial);
+        let snapshot = (serial, self.tasks.peek().clone(), self.archive.peek().clone());
+        let mut undo_sig = self.undo_stack;
+        let mut stack = undo_sig.write();
+        stack.push(snapshot);
+        if stack.len() > UNDO_DEPTH {
+            stack.remove(0);
+        }
+    }
+
+    /// The number of the newest checkpoint, i.e. the change the next undo would revert
+    pub fn last_checkpoint(&self) -> Option<u64> {
+        self.undo_stack.peek().last().map(|(serial, _, _)| *serial)
+    }
+
+    /// ACTION - UNDO ONE CHANGE: Undoes the change behind checkpoint `serial`, but only while nothing
+    /// was changed after it. Returns false (and changes nothing) otherwise.
+    pub fn undo_if_last(&self, serial: u64) -> bool {
+        if self.last_checkpoint() != Some(serial) {
+            return false;
         }
-        PathBuf::from(DATA_FILE) // Fallback to the current directory if we can't find the home folder
+        self.undo();
+        true
+    }
+
+    /// ACTION - UNDO: Restores the data as it was before the last change
+    pub fn undo(&self) {
+        let mut undo_sig = self.undo_stack;
+        let Some((_, tasks, archive)) = undo_sig.write().pop() else { return };
+        let mut tasks_sig = self.tasks;
+        tasks_sig.set(tasks);
+        let mut archive_sig = self.archive;
+        archive_sig.set(archive);
+        self.save_tasks();
+        self.save_archive();
+    }
+
+    /// DATA LOCATION: Where the task list is saved
+    fn get_data_path() -> PathBuf {
+        data_file_path(DATA_FILE)
     }
 
     /// LOADING DATA: Reads the tasks from the JSON file on startup
@@ -56,15 +163,91 @@ impl AppState {
     /// SAVING DATA: Writes the current list of tasks to the disk
     pub fn save_tasks(&self) {
         let path = Self::get_data_path();
-        let tasks = self.tasks.read();
+        let tasks = self.tasks.peek(); // peek: saving from an effect must not subscribe it to the data
         // Convert our list of tasks into formatted JSON text
         if let Ok(content) = serde_json::to_string_pretty(&*tasks) {
             let _ = fs::write(path, content);
         }
     }
 
+    /// LOADING ARCHIVE: Reads archived tasks from their own file
+    fn load_archive() -> Vec<Task> {
+        fs::read_to_string(data_file_path(ARCHIVE_FILE))
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    /// SAVING ARCHIVE: Writes the archived tasks to disk
+    pub fn save_archive(&self) {
+        let archive = self.archive.peek();
+        if let Ok(content) = serde_json::to_string_pretty(&*archive) {
+            let _ = fs::write(data_file_path(ARCHIVE_FILE), content);
+        }
+    }
+
+    /// LOADING BUSY TIME: Reads the imported calendar blocks
+    fn load_busy() -> Vec<BusyBlock> {
+        fs::read_to_string(data_file_path(BUSY_FILE))
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    /// SAVING BUSY TIME: Writes the imported calendar blocks to disk
+    fn save_busy(&self) {
+        let busy = self.busy.peek();
+        if let Ok(content) = serde_json::to_string_pretty(&*busy) {
+            let _ = fs::write(data_file_path(BUSY_FILE), content);
+        }
+    }
+
+    /// ACTION - IMPORT BUSY TIME: Replaces every block from `source` with `blocks`,
+    /// so importing the same calendar again updates it in place
+    pub fn replace_busy(&self, source: &str, blocks: Vec<BusyBlock>) {
+        let mut busy_sig = self.busy;
+        let mut busy = busy_sig.write();
+        busy.retain(|b| b.source != source);
+        busy.extend(blocks);
+        busy.sort_by_key(|b| b.start);
+        drop(busy);
+        self.save_busy();
+    }
+
+    /// ACTION - REMOVE CALENDAR: Drops every block imported from `source`
+    pub fn remove_busy_source(&self, source: &str) {
+        let mut busy_sig = self.busy;
+        busy_sig.write().retain(|b| b.source != source);
+        self.save_busy();
+    }
+
+    /// ACTION - REMOTE TIMES: Takes slots changed on the CalDAV server, except on tasks edited here since
+    /// the sync read them (not part of undo, and not a local change, so the next sync does not send them straight back)
+    pub fn apply_remote_times(&self, pulled: &[crate::caldav::Pulled]) {
+        if pulled.is_empty() {
+            return;
+        }
+        let mut tasks_sig = self.tasks;
+        let mut tasks = tasks_sig.write();
+        for change in pulled {
+            // A task changed here while the sync ran keeps the user's edit; the next sync sends it
+            if let Some(task) = tasks.iter_mut().find(|t| t.id == change.id && t.last_touched() == change.touched) {
+                task.scheduled_start = change.start;
+                task.scheduled_end = change.end;
+            }
+        }
+        drop(tasks);
+        self.save_tasks();
+    }
+
+    /// DELETE COUNT: Open tasks in the Delete quadrant (sampled weekly for the purge nudge)
+    pub fn delete_count(&self) -> usize {
+        self.tasks.read().iter().filter(|t| t.quadrant == Quadrant::Delete && t.status == TaskStatus::Todo).count()
+    }
+
     /// ACTION - ADD TASK: Adds a new task to the inbox
     pub fn add_task(&self, title: String) {
+        self.checkpoint();
         let mut tasks_sig = self.tasks;
         let mut tasks = tasks_sig.write();
         tasks.push(Task::new(title));
@@ -72,27 +255,181 @@ impl AppState {
         self.save_tasks(); // Persist changes immediately
     }
 
-    /// ACTION - SORT TASK: Moves a task to a different matrix quadrant
-    pub fn update_task_quadrant(&self, id: uuid::Uuid, quadrant: Quadrant) {
+    /// ACTION - ADD MANY: Adds a batch of tasks (e.g. a pasted list) with a single write
+    pub fn add_tasks(&self, new_tasks: Vec<Task>) {
+        self.checkpoint();
+        let mut tasks_sig = self.tasks;
+        let mut tasks = tasks_sig.write();
+        tasks.extend(new_tasks);
+        drop(tasks);
+        self.save_tasks();
+    }
+
+    /// ACTION - MOVE TO QUADRANT: The one way tasks change quadrant (drops, menus, shortcuts, triage, bulk bar).
+    /// When the move would break the target's WIP limit it waits in `pending_move` for the demote prompt.
+    pub fn move_to_quadrant(&self, ids: &HashSet<uuid::Uuid>, quadrant: Quadrant, limits: &WipLimits) {
+        self.request_move(PendingMove::new(ids.clone(), quadrant), limits);
+    }
+
+    /// ACTION - PLACE BY SCORES: Stores new scores and moves the task to the sector they point to,
+    /// as one undo step and under the same WIP check as `move_to_quadrant`
+    pub fn place_task(&self, id: uuid::Uuid, urgency: f32, importance: f32, limits: &WipLimits) {
+        let scores = (urgency.clamp(0.0, 1.0), importance.clamp(0.0, 1.0));
+        let quadrant = Quadrant::from_scores(scores.0, scores.1);
+        self.request_move(PendingMove { scores: Some(scores), ..PendingMove::new(HashSet::from([id]), quadrant) }, limits);
+    }
+
+    /// Carries out a move right away, or holds it for the prompt when it does not fit.
+    /// While another move waits for the prompt it is queued behind it (the prompt says so), never dropped.
+    fn request_move(&self, mut request: PendingMove, limits: &WipLimits) {
+        request.limit = limits.get(&request.quadrant);
+        if self.pending_move.peek().is_some() {
+            let mut queued_sig = self.queued_moves;
+            queued_sig.write().push_back(request);
+            return;
+        }
+        self.submit_move(request);
+    }
+
+    /// Checks a move against the limit it was asked under, as the tasks are now
+    fn submit_move(&self, mut request: PendingMove) {
+        (request.overflow, request.short) = wip_overflow(&self.tasks.peek(), &request.ids, &request.quadrant, request.limit);
+        if request.overflow > 0 || request.short > 0 {
+            let mut pending_sig = self.pending_move;
+            pending_sig.set(Some(request));
+            return;
+        }
+        self.checkpoint();
+        self.apply_move(&request);
+        self.save_tasks();
+    }
+
+    /// Once the prompt closes, the next queued move (if any) gets its turn
+    fn next_queued_move(&self) {
+        let mut queued_sig = self.queued_moves;
+        while self.pending_move.peek().is_none() {
+            let Some(request) = queued_sig.write().pop_front() else { return };
+            self.submit_move(request);
+        }
+    }
+
+    /// ACTION - FINISH PENDING MOVE: Carries out the held-back move as one undo step, first demoting
+    /// `demoted` one quadrant down (purging them when the target is Delete). An empty set moves anyway.
+    pub fn finish_pending_move(&self, demoted: &HashSet<uuid::Uuid>) {
+        let mut pending_sig = self.pending_move;
+        let Some(request) = pending_sig.write().take() else { return };
+        self.checkpoint();
+        let mut tasks_sig = self.tasks;
+        let mut tasks = tasks_sig.write();
+        match request.quadrant.demoted() {
+            Some(lower) => {
+                for task in tasks.iter_mut().filter(|t| demoted.contains(&t.id)) {
+                    task.set_quadrant(lower.clone());
+                    task.touch();
+                }
+            }
+            None => tasks.retain(|t| !demoted.contains(&t.id)),
+        }
+        drop(tasks);
+        self.apply_move(&request);
+        self.save_tasks();
+        self.next_queued_move();
+    }
+
+    /// ACTION - BACK TO BACKLOG: Returns one task to Unsorted with the scores it had before it was sorted,
+    /// leaving every other task (and any later edit of this one) as it is
+    pub fn unsort_task(&self, id: uuid::Uuid, urgency: Option<f32>, importance: Option<f32>) {
+        self.checkpoint();
         let mut tasks_sig = self.tasks;
         let mut tasks = tasks_sig.write();
         if let Some(task) = tasks.iter_mut().find(|t| t.id == id) {
-            task.quadrant = quadrant;
+            task.quadrant = Quadrant::Unsorted;
+            task.urgency = urgency;
+            task.importance = importance;
+            task.touch();
+        }
+        drop(tasks);
+        self.save_tasks();
+    }
+
+    /// ACTION - CANCEL PENDING MOVE: Drops the held-back move; nothing changes
+    pub fn cancel_pending_move(&self) {
+        let mut pending_sig = self.pending_move;
+        pending_sig.set(None);
+        self.next_queued_move();
+    }
+
+    /// Moves the tasks of `request` and sets its slots (no checkpoint or save of its own)
+    fn apply_move(&self, request: &PendingMove) {
+        let mut tasks_sig = self.tasks;
+        let mut tasks = tasks_sig.write();
+        for task in tasks.iter_mut() {
+            if let Some((start, end)) = request.slots.get(&task.id) {
+                task.scheduled_start = Some(*start);
+                task.scheduled_end = Some(*end);
+                task.touch();
+            }
+        }
+        for task in tasks.iter_mut().filter(|t| request.ids.contains(&t.id)) {
+            match request.scores {
+                Some((urgency, importance)) => {
+                    task.urgency = Some(urgency);
+                    task.importance = Some(importance);
+                    task.quadrant = request.quadrant.clone();
+                }
+                None => task.set_quadrant(request.quadrant.clone()),
+            }
+            task.touch();
+        }
+    }
+
+    /// ACTION - SCORE: Stores the urgency and importance scores from the questionnaire.
+    /// A sorted task stays put, so its scores are pulled into its current sector (see Task::set_quadrant).
+    pub fn update_task_scores(&self, id: uuid::Uuid, urgency: f32, importance: f32) {
+        self.checkpoint();
+        let mut tasks_sig = self.tasks;
+        let mut tasks = tasks_sig.write();
+        if let Some(task) = tasks.iter_mut().find(|t| t.id == id) {
+            task.urgency = Some(urgency.clamp(0.0, 1.0));
+            task.importance = Some(importance.clamp(0.0, 1.0));
+            task.set_quadrant(task.quadrant.clone());
+            task.touch();
+        }
+        drop(tasks);
+        self.save_tasks();
+    }
+
+    /// ACTION - RENAME: Replaces the title of a task
+    pub fn update_task_title(&self, id: uuid::Uuid, title: String) {
+        self.checkpoint();
+        self.write_task_title(id, title);
+    }
+
+    /// ACTION - RENAME (LIVE): Same as update_task_title without the undo step, for rename boxes that
+    /// save on every keystroke after taking one checkpoint when the edit starts
+    pub fn write_task_title(&self, id: uuid::Uuid, title: String) {
+        let mut tasks_sig = self.tasks;
+        let mut tasks = tasks_sig.write();
+        if let Some(task) = tasks.iter_mut().find(|t| t.id == id) {
+            task.title = title;
+            task.touch();
         }
         drop(tasks);
         self.save_tasks();
     }
 
     /// ACTION - TOGGLE STATUS: Marks a task as Done or Todo
-    #[allow(dead_code)]
     pub fn toggle_task_status(&self, id: uuid::Uuid) {
+        self.checkpoint();
         let mut tasks_sig = self.tasks;
         let mut tasks = tasks_sig.write();
         if let Some(task) = tasks.iter_mut().find(|t| t.id == id) {
-            task.status = match task.status {
+            let status = match task.status {
                 TaskStatus::Todo => TaskStatus::Done,
                 TaskStatus::Done => TaskStatus::Todo,
             };
+            task.set_status(status);
+            task.touch();
         }
         drop(tasks);
         self.save_tasks();
@@ -100,6 +437,7 @@ impl AppState {
     
     /// ACTION - DELETE: Permanently removes a task
     pub fn delete_task(&self, id: uuid::Uuid) {
+        self.checkpoint();
         let mut tasks_sig = self.tasks;
         let mut tasks = tasks_sig.write();
         tasks.retain(|t| t.id != id);
@@ -109,13 +447,267 @@ impl AppState {
     
     /// ACTION - SCHEDULE: Sets the time block for a task on the calendar
     pub fn update_task_schedule(&self, id: uuid::Uuid, start: Option<chrono::DateTime<chrono::Local>>, end: Option<chrono::DateTime<chrono::Local>>) {
+        self.checkpoint();
         let mut tasks_sig = self.tasks;
         let mut tasks = tasks_sig.write();
         if let Some(task) = tasks.iter_mut().find(|t| t.id == id) {
             task.scheduled_start = start;
             task.scheduled_end = end;
+            task.touch();
+        }
+        drop(tasks);
+        self.save_tasks();
+    }
+
+    /// ACTION - STRETCH: Moves the end of a block while it is being resized (no undo step of its own)
+    pub fn stretch_task_end(&self, id: uuid::Uuid, end: chrono::DateTime<chrono::Local>) {
+        let mut tasks_sig = self.tasks;
+        let mut tasks = tasks_sig.write();
+        if let Some(task) = tasks.iter_mut().find(|t| t.id == id) {
+            task.scheduled_end = Some(end);
+            task.touch();
+        }
+        drop(tasks);
+        self.save_tasks();
+    }
+
+    /// BULK HELPER: Applies one change to every selected task inside a single write and save
+    fn update_many(&self, ids: &HashSet<uuid::Uuid>, mut change: impl FnMut(&mut Task)) {
+        self.checkpoint();
+        let mut tasks_sig = self.tasks;
+        let mut tasks = tasks_sig.write();
+        for task in tasks.iter_mut().filter(|t| ids.contains(&t.id)) {
+            change(task);
+            task.touch();
+        }
+        drop(tasks);
+        self.save_tasks();
+    }
+
+    /// BULK - TAG: Adds a tag to every selected task (ignored if already present)
+    pub fn tag_tasks(&self, ids: &HashSet<uuid::Uuid>, tag: &str) {
+        let tag = tag.trim().trim_start_matches('#').trim().to_string();
+        if tag.is_empty() {
+            return;
+        }
+        self.update_many(ids, |task| {
+            if !task.tags.iter().any(|t| t.eq_ignore_ascii_case(&tag)) {
+                task.tags.push(tag.clone());
+            }
+        });
+    }
+
+    /// BULK - SCHEDULE: Places the selected tasks back to back starting at `start`.
+    /// Tasks keep their existing block length (`default_length` if never scheduled), and unsorted
+    /// tasks move to Schedule so they show up on the calendar (under Schedule's WIP limit, as one move).
+    pub fn schedule_tasks(&self, ids: &HashSet<uuid::Uuid>, start: chrono::DateTime<chrono::Local>, default_length: chrono::Duration, limits: &WipLimits) {
+        let mut next_start = start;
+        let mut slots = HashMap::new();
+        let mut unsorted = HashSet::new();
+        for task in self.tasks.peek().iter().filter(|t| ids.contains(&t.id)) {
+            let length = match (task.scheduled_start, task.scheduled_end) {
+                (Some(s), Some(e)) if e > s => e - s,
+                _ => default_length,
+            };
+            slots.insert(task.id, (next_start, next_start + length));
+            if task.quadrant == Quadrant::Unsorted {
+                unsorted.insert(task.id);
+            }
+            next_start += length;
+        }
+        self.request_move(PendingMove { slots, ..PendingMove::new(unsorted, Quadrant::Schedule) }, limits);
+    }
+
+    /// PLAN - ACCEPT: Writes the accepted "Plan my week" blocks as a single undo step
+    pub fn apply_plan(&self, blocks: &[(uuid::Uuid, chrono::DateTime<chrono::Local>, chrono::DateTime<chrono::Local>)]) {
+        self.checkpoint();
+        let mut tasks_sig = self.tasks;
+        let mut tasks = tasks_sig.write();
+        for (id, start, end) in blocks {
+            if let Some(task) = tasks.iter_mut().find(|t| t.id == *id) {
+                task.scheduled_start = Some(*start);
+                task.scheduled_end = Some(*end);
+                task.touch();
+            }
+        }
+        drop(tasks);
+        self.save_tasks();
+    }
+
+    /// ACTION - DEPENDENCY: Adds `dep` to the tasks `id` waits on, or removes it if already there
+    pub fn toggle_dependency(&self, id: uuid::Uuid, dep: uuid::Uuid) {
+        self.checkpoint();
+        let mut tasks_sig = self.tasks;
+        let mut tasks = tasks_sig.write();
+        if let Some(task) = tasks.iter_mut().find(|t| t.id == id) {
+            if let Some(pos) = task.depends_on.iter().position(|d| *d == dep) {
+                task.depends_on.remove(pos);
+            } else if dep != id {
+                task.depends_on.push(dep);
+            }
+            task.touch();
         }
         drop(tasks);
         self.save_tasks();
     }
+
+    /// BULK - ESTIMATE: Sets (or clears) the expected effort of every selected task, in minutes
+    pub fn set_estimate(&self, ids: &HashSet<uuid::Uuid>, minutes: Option<u32>) {
+        self.update_many(ids, |task| task.estimate_minutes = minutes);
+    }
+
+    /// BULK - PROJECT: Files every selected task under a project (None or blank clears it)
+    pub fn set_project(&self, ids: &HashSet<uuid::Uuid>, project: Option<String>) {
+        let project = project.map(|p| p.trim().to_string()).filter(|p| !p.is_empty());
+        self.update_many(ids, |task| task.project = project.clone());
+    }
+
+    /// BULK - DUE DATE: Sets (or clears) the deadline of every selected task
+    pub fn set_due(&self, ids: &HashSet<uuid::Uuid>, due: Option<chrono::NaiveDate>) {
+        self.update_many(ids, |task| task.due = due);
+    }
+
+    /// BULK - COMPLETE: Marks every selected task as Done
+    pub fn complete_tasks(&self, ids: &HashSet<uuid::Uuid>) {
+        self.update_many(ids, |task| task.set_status(TaskStatus::Done));
+    }
+
+    /// BULK - DELETE: Permanently removes every selected task
+    pub fn delete_tasks(&self, ids: &HashSet<uuid::Uuid>) {
+        self.checkpoint();
+        let mut tasks_sig = self.tasks;
+        let mut tasks = tasks_sig.write();
+        tasks.retain(|t| !ids.contains(&t.id));
+        drop(tasks);
+        self.save_tasks();
+    }
+
+    /// BULK - ARCHIVE: Moves tasks out of the active list into archive.json
+    pub fn archive_tasks(&self, ids: &HashSet<uuid::Uuid>) {
+        self.checkpoint();
+        self.move_to_archive(ids);
+    }
+
+    /// Moves tasks into the archive without recording an undo step
+    fn move_to_archive(&self, ids: &HashSet<uuid::Uuid>) {
+        let mut tasks_sig = self.tasks;
+        let mut tasks = tasks_sig.write();
+        let (archived, kept): (Vec<Task>, Vec<Task>) = tasks.drain(..).partition(|t| ids.contains(&t.id));
+        *tasks = kept;
+        drop(tasks);
+        if archived.is_empty() {
+            return;
+        }
+        let mut archive_sig = self.archive;
+        archive_sig.write().extend(archived);
+        self.save_tasks();
+        self.save_archive();
+    }
+
+    /// AUTO-ARCHIVE: Moves tasks that have been Done for at least `after_days` into the archive
+    pub fn archive_finished(&self, after_days: i64) {
+        let now = chrono::Local::now();
+        // peek: a sweep run from an effect must not re-run on every task edit
+        let ids: HashSet<uuid::Uuid> = self.tasks.peek()
+            .iter()
+            .filter(|t| t.status == TaskStatus::Done)
+            .filter(|t| (now - t.completed_at.unwrap_or(t.last_touched())).num_days() >= after_days)
+            .map(|t| t.id)
+            .collect();
+        if !ids.is_empty() {
+            self.move_to_archive(&ids);
+        }
+    }
+
+    /// ACTION - REOPEN: Brings an archived task back to the active list as Todo. Its quadrant is subject to
+    /// the WIP limit like any other move: when there is no room it waits in the backlog for the demote prompt.
+    pub fn reopen_archived(&self, id: uuid::Uuid, limits: &WipLimits) {
+        if !self.archive.peek().iter().any(|t| t.id == id) {
+            return;
+        }
+        self.checkpoint();
+        let mut archive_sig = self.archive;
+        let mut archive = archive_sig.write();
+        let Some(pos) = archive.iter().position(|t| t.id == id) else { return };
+        let mut task = archive.remove(pos);
+        drop(archive);
+        task.set_status(TaskStatus::Todo);
+        task.touch();
+        let target = std::mem::replace(&mut task.quadrant, Quadrant::Unsorted);
+        let mut tasks_sig = self.tasks;
+        tasks_sig.write().push(task);
+        if target != Quadrant::Unsorted {
+            let ids = HashSet::from([id]);
+            let request = PendingMove { limit: limits.get(&target), ..PendingMove::new(ids, target) };
+            if wip_overflow(&self.tasks.peek(), &request.ids, &request.quadrant, request.limit) == (0, 0) {
+                // Fits: part of the same undo step as the reopen
+                self.apply_move(&request);
+            } else {
+                self.request_move(request, limits);
+            }
+        }
+        self.save_tasks();
+        self.save_archive();
+    }
+
+    /// ACTION - PURGE ARCHIVED: Permanently removes a task from the archive
+    pub fn delete_archived(&self, id: uuid::Uuid) {
+        self.checkpoint();
+        let mut archive_sig = self.archive;
+        archive_sig.write().retain(|t| t.id != id);
+        self.save_archive();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tasks(quadrant: Quadrant, count: usize) -> Vec<Task> {
+        (0..count)
+            .map(|i| {
+                let mut task = Task::new(format!("Task {i}"));
+                task.quadrant = quadrant.clone();
+                task
+            })
+            .collect()
+    }
+
+    fn ids(tasks: &[Task]) -> HashSet<uuid::Uuid> {
+        tasks.iter().map(|t| t.id).collect()
+    }
+
+    #[test]
+    fn moves_within_the_limit_fit() {
+        let mut all = tasks(Quadrant::DoFirst, 2);
+        let incoming = tasks(Quadrant::Unsorted, 3);
+        all.extend(incoming.clone());
+        assert_eq!(wip_overflow(&all, &ids(&incoming), &Quadrant::DoFirst, Some(5)), (0, 0));
+        assert_eq!(wip_overflow(&all, &ids(&incoming), &Quadrant::DoFirst, None), (0, 0));
+    }
+
+    #[test]
+    fn an_empty_quadrant_still_reports_a_bulk_move_over_its_limit() {
+        let incoming = tasks(Quadrant::Unsorted, 8);
+        assert_eq!(wip_overflow(&incoming, &ids(&incoming), &Quadrant::DoFirst, Some(5)), (0, 3));
+    }
+
+    #[test]
+    fn demoting_every_resident_can_still_leave_the_quadrant_over() {
+        let mut all = tasks(Quadrant::DoFirst, 3);
+        let incoming = tasks(Quadrant::Unsorted, 10);
+        all.extend(incoming.clone());
+        assert_eq!(wip_overflow(&all, &ids(&incoming), &Quadrant::DoFirst, Some(5)), (3, 5));
+    }
+
+    #[test]
+    fn done_tasks_do_not_count_as_incoming() {
+        let mut all = tasks(Quadrant::DoFirst, 4);
+        let mut incoming = tasks(Quadrant::Unsorted, 3);
+        for task in incoming.iter_mut().skip(1) {
+            task.set_status(TaskStatus::Done);
+        }
+        all.extend(incoming.clone());
+        assert_eq!(wip_overflow(&all, &ids(&incoming), &Quadrant::DoFirst, Some(5)), (0, 0));
+    }
 }
@@ -0,0 +1,238 @@
+// THEMES: Every color the UI uses is a CSS variable (see tailwind.config.js), so a theme is
+// just a set of values for those variables. Values are hex colors; the stylesheet turns them
+// into "R G B" triplets so Tailwind's opacity modifiers (bg-neon-cyan/20) keep working.
+// Theme files are the same JSON as the `Theme` struct; missing or malformed colors fall back to Deep Space.
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Pseudo theme name that follows the operating system's light/dark preference
+pub const SYSTEM_THEME: &str = "system";
+
+/// THEME VARIABLES: Every variable a theme can set, with its Deep Space value
+const DEEP_SPACE: [(&str, &str); 24] = [
+    ("space-900", "#0B0D17"),
+    ("space-800", "#15192B"),
+    ("space-700", "#232942"),
+    ("neon-cyan", "#00F0FF"),
+    ("neon-amber", "#FFAA00"),
+    ("neon-green", "#00FF9D"),
+    ("neon-pink", "#FF00D4"),
+    ("neon-purple", "#A855F7"),
+    ("quadrant-do-first", "#FF00D4"),
+    ("quadrant-schedule", "#00F0FF"),
+    ("quadrant-delegate", "#FFAA00"),
+    ("quadrant-delete", "#6B7280"),
+    ("white", "#FFFFFF"),
+    ("gray-100", "#F3F4F6"),
+    ("gray-200", "#E5E7EB"),
+    ("gray-300", "#D1D5DB"),
+    ("gray-400", "#9CA3AF"),
+    ("gray-500", "#6B7280"),
+    ("gray-600", "#4B5563"),
+    ("gray-700", "#374151"),
+    ("gray-800", "#1F2937"),
+    ("gray-900", "#111827"),
+    ("red-400", "#F87171"),
+    ("red-500", "#EF4444"),
+];
+
+/// LIGHT: Paper background, ink-colored text, deeper accents that stay readable on white
+const LIGHT: [(&str, &str); 24] = [
+    ("space-900", "#F5F6FA"),
+    ("space-800", "#FFFFFF"),
+    ("space-700", "#D5D9E4"),
+    ("neon-cyan", "#0077B6"),
+    ("neon-amber", "#B86E00"),
+    ("neon-green", "#0A8F5A"),
+    ("neon-pink", "#C2188F"),
+    ("neon-purple", "#6D28D9"),
+    ("quadrant-do-first", "#C2188F"),
+    ("quadrant-schedule", "#0077B6"),
+    ("quadrant-delegate", "#B86E00"),
+    ("quadrant-delete", "#6B7280"),
+    ("white", "#111827"),
+    ("gray-100", "#111827"),
+    ("gray-200", "#1F2937"),
+    ("gray-300", "#374151"),
+    ("gray-400", "#4B5563"),
+    ("gray-500", "#6B7280"),
+    ("gray-600", "#9CA3AF"),
+    ("gray-700", "#D1D5DB"),
+    ("gray-800", "#E5E7EB"),
+    ("gray-900", "#F3F4F6"),
+    ("red-400", "#DC2626"),
+    ("red-500", "#B91C1C"),
+];
+
+/// HIGH CONTRAST: Pure black and white with saturated, distinct accents
+const HIGH_CONTRAST: [(&str, &str); 24] = [
+    ("space-900", "#000000"),
+    ("space-800", "#000000"),
+    ("space-700", "#FFFFFF"),
+    ("neon-cyan", "#00FFFF"),
+    ("neon-amber", "#FFD600"),
+    ("neon-green", "#00FF00"),
+    ("neon-pink", "#FF4FD8"),
+    ("neon-purple", "#C084FC"),
+    ("quadrant-do-first", "#FF4FD8"),
+    ("quadrant-schedule", "#00FFFF"),
+    ("quadrant-delegate", "#FFD600"),
+    ("quadrant-delete", "#FFFFFF"),
+    ("white", "#FFFFFF"),
+    ("gray-100", "#FFFFFF"),
+    ("gray-200", "#FFFFFF"),
+    ("gray-300", "#FFFFFF"),
+    ("gray-400", "#F0F0F0"),
+    ("gray-500", "#E0E0E0"),
+    ("gray-600", "#D0D0D0"),
+    ("gray-700", "#FFFFFF"),
+    ("gray-800", "#FFFFFF"),
+    ("gray-900", "#000000"),
+    ("red-400", "#FF5555"),
+    ("red-500", "#FF3333"),
+];
+
+/// THEME: A named set of colors, built in or imported from a file
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Theme {
+    pub name: String,
+    #[serde(default)]
+    pub colors: BTreeMap<String, String>, // Variable name (e.g. "neon-cyan") → hex color
+}
+
+impl Theme {
+    fn from_table(name: &str, table: &[(&str, &str)]) -> Self {
+        Self {
+            name: name.to_string(),
+            colors: table.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect(),
+        }
+    }
+
+    /// The original dark palette
+    pub fn deep_space() -> Self {
+        Self::from_table("Deep Space", &DEEP_SPACE)
+    }
+
+    /// The themes that ship with the app
+    pub fn built_in() -> Vec<Theme> {
+        vec![
+            Self::deep_space(),
+            Self::from_table("Light", &LIGHT),
+            Self::from_table("High Contrast", &HIGH_CONTRAST),
+        ]
+    }
+
+    /// Finds a theme by name among the built-in and imported ones
+    pub fn find(name: &str, custom: &[Theme]) -> Option<Theme> {
+        Self::built_in()
+            .into_iter()
+            .chain(custom.iter().cloned())
+            .find(|t| t.name == name)
+    }
+
+    /// The theme a setting resolves to right now ("system" = Deep Space unless the OS asks for light)
+    pub fn resolve(name: &str, custom: &[Theme]) -> Theme {
+        Self::find(name, custom).unwrap_or_else(Self::deep_space)
+    }
+
+    /// Checks that every color is a "#rrggbb" value before an imported theme is kept
+    pub fn validate(&self) -> Result<(), String> {
+        match self.colors.iter().find(|(_, value)| !is_hex_color(value)) {
+            Some((var, value)) => Err(format!("{var} is \"{value}\", not a #rrggbb color")),
+            None => Ok(()),
+        }
+    }
+
+    /// A color of this theme, falling back to Deep Space for anything the theme leaves out
+    /// (or sets to something that isn't a hex color, so a bad value never reaches the page)
+    pub fn color(&self, var: &str) -> String {
+        self.colors
+            .get(var)
+            .filter(|value| is_hex_color(value))
+            .cloned()
+            .or_else(|| DEEP_SPACE.iter().find(|(k, _)| *k == var).map(|(_, v)| v.to_string()))
+            .unwrap_or_else(|| "#000000".to_string())
+    }
+
+    /// CSS declarations for every theme variable ("--neon-cyan: 0 240 255;")
+    fn declarations(&self) -> String {
+        DEEP_SPACE
+            .iter()
+            .filter_map(|(var, _)| Some(format!("--{var}: {};", hex_to_triplet(&self.color(var))?)))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+/// STYLESHEET: The :root block for the chosen theme. "system" emits Deep Space plus
+/// a prefers-color-scheme override with the Light theme.
+pub fn stylesheet(choice: &str, custom: &[Theme]) -> String {
+    if choice == SYSTEM_THEME {
+        let light = Theme::resolve("Light", custom);
+        format!(
+            ":root {{ {} }}\n@media (prefers-color-scheme: light) {{ :root {{ {} }} }}",
+            Theme::deep_space().declarations(),
+            light.declarations()
+        )
+    } else {
+        format!(":root {{ {} }}", Theme::resolve(choice, custom).declarations())
+    }
+}
+
+/// "#00F0FF" → true; anything else (short forms, names, stray characters) → false
+fn is_hex_color(value: &str) -> bool {
+    value
+        .strip_prefix('#')
+        .is_some_and(|hex| hex.len() == 6 && hex.bytes().all(|b| b.is_ascii_hexdigit()))
+}
+
+/// "#00F0FF" (or "#0FF") → "0 240 255"
+fn hex_to_triplet(hex: &str) -> Option<String> {
+    let hex = hex.trim().trim_start_matches('#');
+    // Checked up front so the two-character slices below always land on char boundaries
+    if !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+    let hex = match hex.len() {
+        3 => hex.chars().flat_map(|c| [c, c]).collect::<String>(),
+        6 => hex.to_string(),
+        _ => return None,
+    };
+    let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+    Some(format!("{} {} {}", channel(0)?, channel(2)?, channel(4)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn theme(colors: &[(&str, &str)]) -> Theme {
+        Theme {
+            name: "Mine".to_string(),
+            colors: colors.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect(),
+        }
+    }
+
+    #[test]
+    fn triplets_come_from_hex_and_reject_everything_else() {
+        assert_eq!(hex_to_triplet("#00F0FF").as_deref(), Some("0 240 255"));
+        assert_eq!(hex_to_triplet("#0FF").as_deref(), Some("0 255 255"));
+        assert_eq!(hex_to_triplet("1é234"), None);
+        assert_eq!(hex_to_triplet("#12345g"), None);
+    }
+
+    #[test]
+    fn import_validation_wants_rrggbb() {
+        assert!(theme(&[("neon-cyan", "#00f0ff")]).validate().is_ok());
+        assert!(theme(&[("neon-cyan", "#0FF")]).validate().is_err());
+        assert!(theme(&[("space-900", "red}</style><script>")]).validate().is_err());
+    }
+
+    #[test]
+    fn bad_colors_fall_back_to_deep_space() {
+        let bad = theme(&[("space-900", "red}</style><script>alert(1)</script>"), ("neon-cyan", "1é234")]);
+        assert_eq!(bad.color("space-900"), "#0B0D17");
+        assert!(stylesheet("Mine", &[bad]).contains("--neon-cyan: 0 240 255;"));
+    }
+}
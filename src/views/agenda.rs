@@ -0,0 +1,177 @@
+use dioxus::prelude::*;
+use std::collections::BTreeMap;
+use crate::components::{opens_task_menu, TaskMenu};
+use crate::models::{Task, TaskStatus};
+use crate::routes::Route;
+use crate::settings::SettingsState;
+use crate::store::AppState;
+use crate::views::calendar::{on_calendar, quadrant_badge_color, CalendarMode, CalendarModeSwitch, InlineTitleInput};
+use chrono::{Datelike, Local, NaiveDate};
+
+/// How far ahead the agenda can look, in days
+const RANGES: [i64; 4] = [7, 14, 30, 90];
+
+/// AGENDA VIEW: Upcoming scheduled tasks in time order, grouped by day.
+/// Same actions as the grid: double-click (or F2) renames, × unschedules, the menu reschedules.
+#[component]
+pub fn CalendarAgenda() -> Element {
+    let app_state = use_context::<AppState>();
+    let settings_state = use_context::<SettingsState>();
+    let navigator = use_navigator();
+    let i18n = settings_state.i18n();
+    let mut days_ahead = use_signal(|| 14i64);
+    let mut menu_task = use_signal(|| None::<uuid::Uuid>);
+
+    let today = Local::now().date_naive();
+    let until = today + chrono::Duration::days(days_ahead());
+
+    let mut by_day: BTreeMap<NaiveDate, Vec<Task>> = BTreeMap::new();
+    for task in app_state.tasks.read().iter().filter(|t| on_calendar(t)) {
+        if let Some(start) = task.scheduled_start {
+            let day = start.date_naive();
+            if day >= today && day < until {
+                by_day.entry(day).or_default().push(task.clone());
+            }
+        }
+    }
+    for tasks in by_day.values_mut() {
+        tasks.sort_by_key(|t| t.scheduled_start);
+    }
+
+    rsx! {
+        div { class: "flex flex-col h-full bg-space-900 text-gray-100 font-sans overflow-hidden",
+            if let Some(task_id) = menu_task() {
+                TaskMenu { task_id, onclose: move |_| menu_task.set(None) }
+            }
+
+            // ── Top Bar: Range ──────────────────────────────────────────
+            div { class: "h-14 border-b border-space-700/60 bg-space-800/50 flex items-center px-4 gap-4 shrink-0",
+                span { class: "text-base font-semibold text-white ml-2", "{i18n.t(\"Agenda\")}" }
+                select {
+                    class: "bg-space-900 border border-space-700 rounded px-2 py-1 text-sm text-gray-300 focus:border-neon-cyan focus:outline-none",
+                    value: "{days_ahead}",
+                    onchange: move |evt| {
+                        if let Ok(days) = evt.value().parse::<i64>() {
+                            days_ahead.set(days);
+                        }
+                    },
+                    for days in RANGES {
+                        option { value: "{days}", {i18n.tf("Next {days} days", &[("days", &days)])} }
+                    }
+                }
+                CalendarModeSwitch { active: CalendarMode::Agenda, day_target: today }
+            }
+
+            // ── Day Groups ──────────────────────────────────────────────
+            div { class: "flex-1 overflow-y-auto p-8",
+                div { class: "max-w-3xl mx-auto space-y-6",
+                    if by_day.is_empty() {
+                        div { class: "text-center py-20 text-gray-600 font-mono border border-dashed border-gray-800 rounded-xl bg-space-800/20",
+                            "{i18n.t(\"// NOTHING SCHEDULED\")}"
+                        }
+                    }
+                    for (day, tasks) in by_day {
+                        {
+                            let relative = if day == today {
+                                i18n.t("Today")
+                            } else if day == today + chrono::Duration::days(1) {
+                                i18n.t("Tomorrow")
+                            } else {
+                                ""
+                            };
+                            let heading = format!("{} {}", i18n.weekday(day.weekday()), i18n.date(day));
+                            rsx! {
+                                section { key: "{day}", aria_label: "{heading}",
+                                    // Day heading opens the day view
+                                    button {
+                                        class: "w-full flex items-baseline gap-3 border-b border-space-700/60 pb-2 mb-2 text-left group",
+                                        onclick: move |_| {
+                                            navigator.push(Route::CalendarDay { date: day });
+                                        },
+                                        h3 { class: "text-sm font-bold text-white capitalize group-hover:text-neon-cyan transition-colors", "{heading}" }
+                                        if !relative.is_empty() {
+                                            span { class: "text-[10px] font-mono tracking-widest uppercase text-neon-cyan", "{relative}" }
+                                        }
+                                    }
+                                    ul { class: "space-y-1",
+                                        for task in tasks {
+                                            AgendaRow { key: "{task.id}", task, menu_task }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// AGENDA ROW: Time range, quadrant badge and title of one scheduled block
+#[component]
+fn AgendaRow(task: Task, menu_task: Signal<Option<uuid::Uuid>>) -> Element {
+    let app_state = use_context::<AppState>();
+    let settings_state = use_context::<SettingsState>();
+    let i18n = settings_state.i18n();
+    let mut editing = use_signal(|| false);
+    let task_id = task.id;
+
+    let start = task.scheduled_start;
+    let end = task.scheduled_end.or_else(|| Some(start? + settings_state.settings.read().calendar.default_block()));
+    let time = match (start, end) {
+        (Some(start), Some(end)) => i18n.time_range(&start, &end),
+        _ => String::new(),
+    };
+    let done = if task.status == TaskStatus::Done { "opacity-50 line-through" } else { "" };
+
+    rsx! {
+        li {
+            id: "task-{task_id}",
+            tabindex: 0,
+            aria_label: "{task.title}, {time}",
+            aria_haspopup: "menu",
+            class: "group flex items-center gap-4 px-3 py-2 rounded-lg hover:bg-space-800/60 focus:outline-none focus-visible:ring-2 focus-visible:ring-neon-cyan {done}",
+            ondoubleclick: move |_| editing.set(true),
+            oncontextmenu: move |evt| {
+                evt.prevent_default();
+                menu_task.set(Some(task_id));
+            },
+            onkeydown: move |evt| {
+                if opens_task_menu(&evt) {
+                    evt.prevent_default();
+                    menu_task.set(Some(task_id));
+                } else if evt.key() == Key::F2 {
+                    editing.set(true);
+                }
+            },
+            span { class: "w-36 shrink-0 font-mono text-xs text-gray-400", "{time}" }
+            span { class: "shrink-0 text-[9px] font-bold px-1.5 py-0.5 rounded {quadrant_badge_color(&task.quadrant)}",
+                "{i18n.t(task.quadrant.label())}"
+            }
+            if editing() {
+                InlineTitleInput {
+                    task_id,
+                    title: task.title.clone(),
+                    class: "flex-1 min-w-0 bg-space-900 text-white text-sm px-2 py-0.5 rounded border border-white/30 focus:outline-none focus:border-neon-cyan",
+                    onclose: move |_| editing.set(false),
+                }
+            } else {
+                span { class: "flex-1 min-w-0 truncate text-sm text-gray-200", "{task.title}" }
+            }
+            div { class: "flex gap-2 opacity-0 group-hover:opacity-100 group-focus-within:opacity-100 transition-opacity",
+                button {
+                    class: "text-xs text-gray-500 hover:text-neon-cyan uppercase tracking-wider font-bold",
+                    onclick: move |_| menu_task.set(Some(task_id)),
+                    "{i18n.t(\"Reschedule…\")}"
+                }
+                button {
+                    class: "w-5 h-5 rounded-full text-gray-500 hover:text-white hover:bg-space-700",
+                    aria_label: i18n.t("Unschedule"),
+                    onclick: move |_| app_state.update_task_schedule(task_id, None, None),
+                    "×"
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,158 @@
+use dioxus::prelude::*;
+use chrono::NaiveDate;
+use crate::components::Selection;
+use crate::models::Task;
+use crate::settings::SettingsState;
+use crate::store::AppState;
+
+/// ARCHIVE VIEW: The history of finished tasks, searchable by text and completion date
+#[component]
+pub fn Archive() -> Element {
+    let app_state = use_context::<AppState>();
+    let settings_state = use_context::<SettingsState>();
+    let selection = use_context::<Selection>();
+    let i18n = settings_state.i18n();
+
+    // Filters: free text plus an optional completion date range (inclusive)
+    let mut query = use_signal(String::new);
+    let mut from = use_signal(|| None::<NaiveDate>);
+    let mut to = use_signal(|| None::<NaiveDate>);
+
+    let archive_after = settings_state.settings.read().archive_after_days;
+
+    // Stage 1: Match every archived task against the filters, newest first
+    let needle = query.read().trim().to_lowercase();
+    let mut matches: Vec<Task> = app_state.archive.read()
+        .iter()
+        .filter(|t| needle.is_empty() || matches_text(t, &needle))
+        .filter(|t| {
+            let day = finished_on(t);
+            from().is_none_or(|f| day >= f) && to().is_none_or(|end| day <= end)
+        })
+        .cloned()
+        .collect();
+    matches.sort_by_key(|t| std::cmp::Reverse(t.completed_at.unwrap_or(t.last_touched())));
+    let total = app_state.archive.read().len();
+
+    rsx! {
+        div { class: "flex-1 h-full p-8 overflow-y-auto",
+            div { class: "w-full max-w-6xl mx-auto space-y-8",
+                // PAGE HEADER: Title plus the auto-archive delay
+                div { class: "border-b border-gray-800 pb-6 flex justify-between items-end",
+                    div {
+                        h1 { class: "text-4xl font-bold mb-2 text-white font-sans tracking-tight", "{i18n.t(\"ARCHIVE\")}" }
+                        h2 { class: "text-xl font-mono text-neon-cyan/80 tracking-widest", "{i18n.t(\"MISSION LOG // COMPLETED\")}" }
+                    }
+                    div { class: "flex items-center gap-2 text-sm text-gray-400",
+                        span { "{i18n.t(\"Archive done tasks after\")}" }
+                        input {
+                            r#type: "number",
+                            min: "0",
+                            class: "w-16 bg-space-900 border border-space-700 rounded px-2 py-1 text-white font-mono focus:border-neon-cyan focus:outline-none",
+                            value: "{archive_after}",
+                            onchange: move |evt| {
+                                if let Ok(days) = evt.value().trim().parse::<i64>() {
+                                    settings_state.set_archive_after_days(days);
+                                }
+                            },
+                        }
+                        span { "{i18n.t(\"days\")}" }
+                    }
+                }
+
+                // FILTER BAR: Search text and completion date range
+                div { class: "glass-panel rounded-xl p-4 flex flex-wrap items-center gap-4 text-sm",
+                    input {
+                        class: "flex-1 min-w-[200px] bg-space-900 border border-space-700 rounded px-3 py-2 text-white placeholder-gray-600 focus:border-neon-cyan focus:outline-none",
+                        placeholder: i18n.t("Search titles, notes and #tags"),
+                        value: "{query}",
+                        oninput: move |evt| query.set(evt.value()),
+                    }
+                    div { class: "flex items-center gap-2 text-gray-400",
+                        span { "{i18n.t(\"From\")}" }
+                        input {
+                            r#type: "date",
+                            class: "bg-space-900 border border-space-700 rounded px-2 py-1 text-white font-mono focus:border-neon-cyan focus:outline-none",
+                            oninput: move |evt| from.set(NaiveDate::parse_from_str(&evt.value(), "%Y-%m-%d").ok()),
+                        }
+                        span { "{i18n.t(\"to\")}" }
+                        input {
+                            r#type: "date",
+                            class: "bg-space-900 border border-space-700 rounded px-2 py-1 text-white font-mono focus:border-neon-cyan focus:outline-none",
+                            oninput: move |evt| to.set(NaiveDate::parse_from_str(&evt.value(), "%Y-%m-%d").ok()),
+                        }
+                    }
+                    span { class: "hud-text", "{matches.len()} / {total}" }
+                }
+
+                // THE LOG: One row per archived task with the option to bring it back
+                if matches.is_empty() {
+                    div { class: "text-center py-20 text-gray-600 font-mono border border-dashed border-gray-800 rounded-xl bg-space-800/20",
+                        if total == 0 { "{i18n.t(\"// ARCHIVE EMPTY\")}" } else { "{i18n.t(\"// NO MATCHING RECORDS\")}" }
+                    }
+                } else {
+                    div { class: "glass-panel rounded-xl overflow-hidden",
+                        table { class: "data-table",
+                            thead {
+                                tr {
+                                    th { "{i18n.t(\"Task\")}" }
+                                    th { class: "w-32 text-right", "{i18n.t(\"Sector\")}" }
+                                    th { class: "w-40 text-right", "{i18n.t(\"Completed\")}" }
+                                    th { class: "w-44 text-right", "{i18n.t(\"Actions\")}" }
+                                }
+                            }
+                            tbody {
+                                for task in matches {
+                                    tr { key: "{task.id}",
+                                        class: "{selection.reveal_class(task.id)}",
+                                        onmounted: move |evt| selection.scroll_if_revealed(task.id, &evt.data()),
+                                        td {
+                                            p { class: "font-medium text-gray-200", "{task.title}"
+                                                for tag in task.tags.iter() {
+                                                    span { class: "ml-2 text-[10px] font-mono px-1.5 py-0.5 rounded bg-space-800 text-gray-400 align-middle", "#{tag}" }
+                                                }
+                                            }
+                                            if let Some(desc) = task.description.clone() {
+                                                p { class: "text-xs text-gray-500 truncate max-w-xl", "{desc}" }
+                                            }
+                                        }
+                                        td { class: "text-right font-mono text-xs text-gray-500", "{i18n.t(task.quadrant.label()).to_uppercase()}" }
+                                        td { class: "text-right font-mono text-xs text-gray-500",
+                                            match task.completed_at {
+                                                Some(done) => rsx! { "{i18n.date(done.date_naive())}" },
+                                                None => rsx! { span { title: i18n.t("Archived while still open"), "—" } },
+                                            }
+                                        }
+                                        td { class: "text-right space-x-4",
+                                            button { class: "text-xs hover:text-neon-cyan text-gray-600 transition-colors uppercase tracking-wider font-bold",
+                                                onclick: move |_| app_state.reopen_archived(task.id, &settings_state.settings.peek().wip_limits),
+                                                "{i18n.t(\"Reopen\")}"
+                                            }
+                                            button { class: "text-xs hover:text-red-500 text-gray-600 transition-colors uppercase tracking-wider font-bold",
+                                                onclick: move |_| app_state.delete_archived(task.id),
+                                                "{i18n.t(\"Delete\")}"
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Case-insensitive match on the title, notes and tags (`needle` is already lowercase)
+fn matches_text(task: &Task, needle: &str) -> bool {
+    let needle = needle.trim_start_matches('#');
+    task.title.to_lowercase().contains(needle)
+        || task.description.as_deref().is_some_and(|d| d.to_lowercase().contains(needle))
+        || task.tags.iter().any(|tag| tag.to_lowercase().contains(needle))
+}
+
+/// The day a task counts as finished (falls back to its last change for tasks archived while open)
+fn finished_on(task: &Task) -> NaiveDate {
+    task.completed_at.unwrap_or(task.last_touched()).date_naive()
+}
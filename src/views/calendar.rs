@@ -1,124 +1,197 @@
 use dioxus::prelude::*;
+use crate::components::{opens_task_menu, ExportDialog, PlanDialog, Selection, Shortcuts, TaskMenu};
+use crate::i18n::I18n;
+use crate::ics;
+use crate::keymap::Action;
+use crate::planner;
+use crate::settings::{CalendarPrefs, SettingsState};
 use crate::store::AppState;
-use crate::models::{Task, Quadrant};
-use chrono::{Datelike, Timelike, Local, NaiveDate};
-use icalendar::{Calendar as ICalendar, Component, Event, EventLike};
+use crate::models::{BusyBlock, Task, Quadrant, TaskStatus};
+use crate::routes::Route;
+use chrono::{DateTime, Datelike, Timelike, Local, NaiveDate, NaiveTime};
 
 // ─── CONSTANTS ───────────────────────────────────────────────────────────────
-/// Total height of the scrollable grid in pixels — 60px per hour × 24 hours
-const GRID_HEIGHT_PX: u32 = 1440;
-/// Height of a single hour row
-const HOUR_HEIGHT_PX: u32 = GRID_HEIGHT_PX / 24; // 60px
+/// Height of a single hour row in pixels (the grid is this × the visible hours from Settings)
+const HOUR_HEIGHT_PX: u32 = 60;
+/// Hour height in the day view, tall enough for 5-minute drop zones
+const DAY_HOUR_HEIGHT_PX: u32 = 144;
+/// Drop / resize resolution of the day view
+const DAY_SNAP_MINUTES: u32 = 5;
 
 // ─── MAIN CALENDAR COMPONENT ────────────────────────────────────────────────
 
 /// Weekly calendar view modeled after Apple Calendar / Google Calendar.
-/// X-axis = Days (Mon–Sun), Y-axis = Hours (00:00–23:00) in 24h clock.
 #[component]
 pub fn Calendar() -> Element {
+    rsx! { CalendarGrid { day: None } }
+}
+
+/// Day view (/calendar/day/2026-10-18): one tall column with 5-minute drop zones.
+#[component]
+pub fn CalendarDay(date: NaiveDate) -> Element {
+    rsx! { CalendarGrid { day: Some(date) } }
+}
+
+/// CALENDAR GRID: The week (day = None) or a single day, with the unscheduled sidebar.
+/// X-axis = Days (from the configured first weekday), Y-axis = the visible hours.
+/// Keyboard: Enter on a task picks it up, arrow keys move through the drop zones, Enter drops it.
+#[component]
+fn CalendarGrid(day: Option<NaiveDate>) -> Element {
     let app_state = use_context::<AppState>();
+    let navigator = use_navigator();
+    let settings_state = use_context::<SettingsState>();
+
+    // ── Preferences (Settings page) ─────────────────────────────────────────
+    let prefs = settings_state.settings.read().calendar.clone();
+    let i18n = settings_state.i18n();
+    // Collapsing off-hours narrows the grid to the working hours
+    let (day_start_hour, day_end_hour) = prefs.visible_range();
+    // The day view trades width for a finer timeline
+    let (hour_height, snap_minutes) = match day {
+        Some(_) => (DAY_HOUR_HEIGHT_PX, DAY_SNAP_MINUTES),
+        None => (HOUR_HEIGHT_PX, prefs.snap_minutes),
+    };
+    let grid_height_px = prefs.visible_hours() * hour_height;
 
     // ── Navigation State ────────────────────────────────────────────────────
-    let mut week_start = use_signal(|| {
-        let now = Local::now();
-        let offset = now.weekday().num_days_from_monday();
-        now.date_naive() - chrono::Duration::days(offset as i64)
+    let mut week_start = use_signal(|| settings_state.settings.peek().calendar.week_start_for(Local::now().date_naive()));
+
+    // Realign the visible week when the first day of the week changes
+    use_effect(move || {
+        let prefs = settings_state.settings.read().calendar.clone();
+        let current = *week_start.peek();
+        week_start.set(prefs.week_start_for(current));
+    });
+
+    // Jumping to a task from search opens the week it is scheduled in
+    let selection = use_context::<Selection>();
+    use_effect(move || {
+        let Some(id) = selection.revealed() else { return };
+        if day.is_some() {
+            return;
+        }
+        let start = app_state.tasks.peek().iter().find(|t| t.id == id).and_then(|t| t.scheduled_start);
+        if let Some(start) = start {
+            week_start.set(settings_state.settings.peek().calendar.week_start_for(start.date_naive()));
+        }
+    });
+
+    // Week navigation from the keyboard shortcut layer (the day view steps one day)
+    let shortcuts = use_context::<Shortcuts>();
+    use_effect(move || {
+        let Some(action) = shortcuts.take_calendar_command() else { return };
+        if let Some(date) = day {
+            let date = match action {
+                Action::PreviousWeek => date - chrono::Duration::days(1),
+                Action::NextWeek => date + chrono::Duration::days(1),
+                _ => Local::now().date_naive(),
+            };
+            navigator.push(Route::CalendarDay { date });
+            return;
+        }
+        let current = *week_start.peek();
+        match action {
+            Action::PreviousWeek => week_start.set(current - chrono::Duration::weeks(1)),
+            Action::NextWeek => week_start.set(current + chrono::Duration::weeks(1)),
+            _ => week_start.set(settings_state.settings.peek().calendar.week_start_for(Local::now().date_naive())),
+        }
     });
 
     // ── Interaction State ───────────────────────────────────────────────────
     let mut dragged_task_id = use_signal(|| None::<uuid::Uuid>);
     let mut stretching_task_id = use_signal(|| None::<uuid::Uuid>);
+    // Slot of the block being resized as it was before the resize (for the banner's Undo)
+    let mut stretch_origin = use_signal(|| None::<Conflict>);
 
-    // ── Derived Data ────────────────────────────────────────────────────────
-    let week_days: Vec<NaiveDate> = (0..7)
-        .map(|i| week_start.read().checked_add_signed(chrono::Duration::days(i)).unwrap())
-        .collect();
+    // ── Columns ─────────────────────────────────────────────────────────────
+    let week_days: Vec<NaiveDate> = match day {
+        Some(date) => vec![date],
+        None => (0..7)
+            .map(|i| week_start.read().checked_add_signed(chrono::Duration::days(i)).unwrap())
+            .collect(),
+    };
+    let column_count = week_days.len();
+    let grid_cols = if day.is_some() { "grid-cols-1" } else { "grid-cols-7" };
+
+    // ── Keyboard State ──────────────────────────────────────────────────────
+    // Focused drop zone as (day column, snap slot from the top of the visible hours)
+    let mut slot_cursor = use_signal(|| (0usize, 0u32));
+    let mut grid_focused = use_signal(|| false);
+    // Task whose move / schedule menu is open
+    let mut menu_task = use_signal(|| None::<uuid::Uuid>);
+    // Read out by screen readers after keyboard moves
+    let mut announcement = use_signal(String::new);
+    // Task just dropped or resized on top of others (see ConflictBanner)
+    let mut conflict = use_signal(|| None::<Conflict>);
+    // "Plan my week" proposal is open
+    let mut planning = use_signal(|| false);
+    let slots_per_day = prefs.visible_hours() * 60 / snap_minutes;
+    let cursor = {
+        let (day_idx, slot) = slot_cursor();
+        (day_idx.min(column_count - 1), slot.min(slots_per_day - 1))
+    };
+    // The day and time a slot stands for
+    let slot_time = move |day_idx: usize, slot: u32| {
+        let day = day.unwrap_or(*week_start.peek()) + chrono::Duration::days(day_idx as i64);
+        let minutes = day_start_hour * 60 + slot * snap_minutes;
+        (day, NaiveTime::from_hms_opt(minutes / 60, minutes % 60, 0).unwrap_or_default())
+    };
 
+    // ── Derived Data ────────────────────────────────────────────────────────
     let today = Local::now().date_naive();
     let now = Local::now();
-    let current_time_pct = ((now.hour() as f32 + now.minute() as f32 / 60.0) / 24.0) * 100.0;
+    // Position of the "now" line within the visible hours (None when outside them)
+    let now_minutes = (now.hour() * 60 + now.minute()) as f32 - (day_start_hour * 60) as f32;
+    let visible_minutes = (prefs.visible_hours() * 60) as f32;
+    let current_time_pct = (0.0..visible_minutes).contains(&now_minutes).then(|| now_minutes / visible_minutes * 100.0);
 
-    // Tasks eligible for the calendar (Do First + Schedule quadrants only)
-    let all_eligible: Vec<Task> = app_state
+    // Scheduled tasks eligible for the calendar
+    let scheduled: Vec<Task> = app_state
         .tasks
         .read()
         .iter()
-        .filter(|t| matches!(t.quadrant, Quadrant::DoFirst | Quadrant::Schedule))
+        .filter(|t| on_calendar(t) && t.scheduled_start.is_some())
         .cloned()
         .collect();
-    let (scheduled, unscheduled): (Vec<Task>, Vec<Task>) =
-        all_eligible.into_iter().partition(|t| t.scheduled_start.is_some());
 
-    // Week label for the header
-    let week_end = week_days.last().cloned().unwrap_or(today);
-    let week_label = format!(
-        "{} – {}",
-        week_start.read().format("%b %d"),
-        week_end.format("%b %d, %Y")
-    );
+    // Label for the header: the week's range, or the day spelled out
+    let week_label = match day {
+        Some(date) => format!("{} {}", i18n.weekday(date.weekday()), i18n.date(date)),
+        None => i18n.date_range(week_days[0], week_days[column_count - 1]),
+    };
+    // Where "Day" opens: today when it is on screen, otherwise the first column
+    let day_target = if week_days.contains(&today) { today } else { week_days[0] };
+    let (cursor_day, cursor_time) = slot_time(cursor.0, cursor.1);
+    let cursor_id = slot_id(cursor_day, cursor_time);
 
     rsx! {
         div {
             // ROOT: Apply ns-resize cursor globally during active stretch
             class: if stretching_task_id().is_some() { "flex h-full bg-space-900 text-gray-100 font-sans overflow-hidden select-none cursor-ns-resize" } else { "flex h-full bg-space-900 text-gray-100 font-sans overflow-hidden select-none" },
             // Release stretch on mouseup anywhere
-            onmouseup: move |_| stretching_task_id.set(None),
-
-            // ─── LEFT SIDEBAR: Unscheduled Tasks ────────────────────────
-            div { class: "w-64 bg-space-800/70 border-r border-space-700/60 flex flex-col shrink-0",
-                // Sidebar header
-                div { class: "p-4 border-b border-space-700/40 flex justify-between items-center",
-                    div {
-                        h2 { class: "text-lg font-bold text-white tracking-tight", "Unscheduled" }
-                        p { class: "text-xs font-mono text-neon-cyan/80 tracking-widest", "Drag to schedule" }
-                    }
-                    // ICS Export Button
-                    button {
-                        class: "p-2 mb-6 rounded-lg hover:bg-space-700/60 text-gray-400 hover:text-neon-cyan transition-all duration-200",
-                        title: "Export Calendar (.ics)",
-                        onclick: move |_| {
-                            let tasks = scheduled.clone();
-                            dioxus::prelude::spawn(async move {
-                                export_ics(&tasks).await;
-                            });
-                        },
-                        svg { class: "w-4 h-4", fill: "none", stroke: "currentColor", view_box: "0 0 24 24",
-                            path { stroke_linecap: "round", stroke_linejoin: "round", stroke_width: "2", d: "M4 16v1a3 3 0 003 3h10a3 3 0 003-3v-1m-4-4l-4 4m0 0l-4-4m4 4V4" }
-                        }
-                    }
+            onmouseup: move |_| {
+                if stretching_task_id().is_some() {
+                    conflict.set(stretch_origin.take());
+                    stretching_task_id.set(None);
                 }
+            },
 
-                // Task list
-                div { class: "flex-1 overflow-y-auto p-3 space-y-2",
-                    if unscheduled.is_empty() {
-                        div { class: "text-center py-12 text-gray-600 text-xs italic font-mono", "All tasks scheduled." }
-                    }
-                    for task in unscheduled {
-                        {
-                            let badge_color = quadrant_badge_color(&task.quadrant);
-                            rsx! {
-                                div {
-                                    key: "{task.id}",
-                                    draggable: true,
-                                    ondragstart: move |_| dragged_task_id.set(Some(task.id)),
-                                    class: "bg-space-900/60 py-5 px-5 rounded-lg border border-space-700/50 cursor-grab hover:border-white/15 transition-all duration-200 group active:cursor-grabbing",
-                                    div { class: "flex flex-col items-start m-auto w-fit gap-1",
-                                        span { class: "shrink-0 text-[9px] font-bold px-1.5 py-0.5 rounded {badge_color}",
-                                            match task.quadrant {
-                                                Quadrant::DoFirst => "DO",
-                                                Quadrant::Schedule => "SCH",
-                                                _ => ""
-                                            }
-                                        }
-                                        span { class: "text-[13px] font-medium pt-5 text-gray-200 leading-snug group-hover:text-white transition-colors", "{task.title}" }
-                                    }
-                                }
-                            }
-                        }
-                    }
+            div { class: "sr-only", role: "status", aria_live: "polite", "{announcement}" }
+            if let Some(task_id) = menu_task() {
+                TaskMenu { task_id, onclose: move |_| menu_task.set(None) }
+            }
+            // Plans the rest of the visible week (from now on when it is the current one)
+            if planning() {
+                PlanDialog {
+                    from: now.naive_local().max(week_days[0].and_time(NaiveTime::MIN)),
+                    until: week_days[column_count - 1],
+                    onclose: move |_| planning.set(false),
                 }
             }
 
+            // ─── LEFT SIDEBAR: Unscheduled Tasks ────────────────────────
+            UnscheduledSidebar { dragged_task_id, menu_task, announcement }
+
             // ─── MAIN CALENDAR AREA ─────────────────────────────────────
             div { class: "flex-1 flex flex-col min-w-0",
 
@@ -126,7 +199,12 @@ pub fn Calendar() -> Element {
                 div { class: "h-14 border-b border-space-700/60 bg-space-800/50 flex items-center px-4 gap-4 shrink-0",
                     button {
                         class: "w-12 h-12 rounded-full hover:bg-space-700 text-gray-400 hover:text-white flex items-center justify-center transition-colors text-2xl font-bold",
+                        aria_label: if day.is_some() { i18n.t("Previous day") } else { i18n.t("Previous week") },
                         onclick: move |_| {
+                            if let Some(date) = day {
+                                navigator.push(Route::CalendarDay { date: date - chrono::Duration::days(1) });
+                                return;
+                            }
                             let new_date = *week_start.read() - chrono::Duration::weeks(1);
                             week_start.set(new_date);
                         },
@@ -134,44 +212,74 @@ pub fn Calendar() -> Element {
                     }
                     button {
                         class: "w-12 h-12 rounded-full hover:bg-space-700 text-gray-400 hover:text-white flex items-center justify-center transition-colors text-2xl font-bold",
+                        aria_label: if day.is_some() { i18n.t("Next day") } else { i18n.t("Next week") },
                         onclick: move |_| {
+                            if let Some(date) = day {
+                                navigator.push(Route::CalendarDay { date: date + chrono::Duration::days(1) });
+                                return;
+                            }
                             let new_date = *week_start.read() + chrono::Duration::weeks(1);
                             week_start.set(new_date);
                         },
                         "›"
                     }
                     span { class: "text-base font-semibold text-white ml-2", "{week_label}" }
+                    CalendarModeSwitch { active: if day.is_some() { CalendarMode::Day } else { CalendarMode::Week }, day_target }
                     // Today button
                     button {
-                        class: "ml-auto text-sm font-semibold px-4 py-2 rounded-md border border-space-700/60 text-gray-300 hover:text-white hover:bg-space-700/40 transition-all",
+                        class: "text-sm font-semibold px-4 py-2 rounded-md border border-space-700/60 text-gray-300 hover:text-white hover:bg-space-700/40 transition-all",
                         onclick: move |_| {
-                            let now = Local::now();
-                            let offset = now.weekday().num_days_from_monday();
-                            week_start.set(now.date_naive() - chrono::Duration::days(offset as i64));
+                            if day.is_some() {
+                                navigator.push(Route::CalendarDay { date: Local::now().date_naive() });
+                            } else {
+                                week_start.set(settings_state.settings.peek().calendar.week_start_for(Local::now().date_naive()));
+                            }
                         },
-                        "Today"
+                        "{i18n.t(\"Today\")}"
+                    }
+                    // Hides the hours outside everyone's working time (saved with the calendar settings)
+                    button {
+                        class: if prefs.collapse_off_hours { "ml-auto text-sm font-semibold px-4 py-2 rounded-md border border-neon-cyan/40 text-neon-cyan bg-neon-cyan/10 transition-all" } else { "ml-auto text-sm font-semibold px-4 py-2 rounded-md border border-space-700/60 text-gray-300 hover:text-white hover:bg-space-700/40 transition-all" },
+                        aria_pressed: prefs.collapse_off_hours,
+                        onclick: move |_| settings_state.update(|s| s.calendar.collapse_off_hours = !s.calendar.collapse_off_hours),
+                        "{i18n.t(\"Collapse off-hours\")}"
+                    }
+                    if day.is_none() {
+                        button {
+                            class: "text-sm font-semibold px-4 py-2 rounded-md border border-neon-cyan/40 text-neon-cyan hover:bg-neon-cyan/10 transition-all disabled:opacity-40",
+                            disabled: week_days[column_count - 1] < today,
+                            onclick: move |_| planning.set(true),
+                            "{i18n.t(\"Plan my week\")}"
+                        }
                     }
                 }
 
+                ConflictBanner { conflict }
+
                 // ── Day Header Row (sticky) ─────────────────────────────
                 div { class: "flex border-b border-space-700/60 bg-space-900 shrink-0 z-20",
                     // Time gutter spacer
                     div { class: "w-14 shrink-0 border-r border-space-700/30" }
                     // Day columns
-                    div { class: "flex-1 grid grid-cols-7",
-                        for day in &week_days {
+                    div { class: "flex-1 grid {grid_cols}",
+                        for day in week_days.iter().copied() {
                             {
-                                let is_today = *day == today;
+                                let is_today = day == today;
                                 rsx! {
-                                    div {
-                                        class: "py-2 flex flex-col items-center justify-center border-r border-space-700/20 last:border-r-0",
+                                    // Each day header opens that day on its own
+                                    button {
+                                        class: "py-2 flex flex-col items-center justify-center border-r border-space-700/20 last:border-r-0 hover:bg-space-800/60 transition-colors",
+                                        title: "{i18n.weekday(day.weekday())} {i18n.date(day)}",
+                                        onclick: move |_| {
+                                            navigator.push(Route::CalendarDay { date: day });
+                                        },
                                         span {
                                             class: if is_today { "text-[11px] font-semibold text-neon-cyan uppercase tracking-widest mb-1" } else { "text-[11px] font-medium text-gray-500 uppercase tracking-widest mb-1" },
-                                            "{day.format(\"%a\")}"
+                                            "{i18n.weekday_short(day.weekday())}"
                                         }
                                         span {
                                             class: if is_today { "text-lg font-bold w-10 h-10 flex items-center justify-center rounded-full bg-neon-cyan text-space-900 shadow-md" } else { "text-lg font-medium text-gray-300 w-10 h-10 flex items-center justify-center" },
-                                            "{day.format(\"%e\")}"
+                                            "{day.day()}"
                                         }
                                     }
                                 }
@@ -184,30 +292,82 @@ pub fn Calendar() -> Element {
                 div { class: "flex-1 overflow-y-auto overflow-x-hidden relative",
                     div {
                         class: "flex relative",
-                        style: "height: {GRID_HEIGHT_PX}px; min-height: {GRID_HEIGHT_PX}px;",
+                        style: "height: {grid_height_px}px; min-height: {grid_height_px}px;",
 
                         // ── Time Gutter (Y-axis labels) ─────────────────
-                        div { class: "w-14 shrink-0 border-r border-space-700/30 relative bg-space-900/80 z-20",
-                            for hour in 0..24 {
+                        div { class: "w-14 shrink-0 border-r border-space-700/30 relative bg-space-900/80 z-20", aria_hidden: true,
+                            for hour in day_start_hour..day_end_hour {
                                 div {
                                     class: "absolute -right-1 z-30 text-[14px] font-mono font-bold text-white leading-none bg-space-900 px-1",
-                                    style: "top: {hour * HOUR_HEIGHT_PX}px; transform: translateY(-50%);",
-                                    if hour == 0 { "" } else {
-                                        "{hour:02}:00"
+                                    style: "top: {(hour - day_start_hour) * hour_height}px; transform: translateY(-50%);",
+                                    if hour == day_start_hour { "" } else {
+                                        "{i18n.hour(hour)}"
                                     }
                                 }
                             }
                         }
 
-                        // ── Grid Area (7 day columns) ───────────────────
-                        div { class: "flex-1 grid grid-cols-7 relative",
+                        // ── Grid Area (7 day columns, or 1 in the day view) ──
+                        div { class: "flex-1 grid {grid_cols} relative focus:outline-none",
+                            id: "calendar-grid",
+                            role: "grid",
+                            tabindex: 0,
+                            aria_label: "{week_label}",
+                            aria_activedescendant: "{cursor_id}",
+                            onfocus: move |_| grid_focused.set(true),
+                            onblur: move |_| grid_focused.set(false),
+                            onkeydown: move |evt| {
+                                let (day_idx, slot) = cursor;
+                                let hour_steps = 60 / snap_minutes;
+                                let last = slots_per_day - 1;
+                                let moved = match evt.key() {
+                                    Key::ArrowLeft => Some((day_idx.saturating_sub(1), slot)),
+                                    Key::ArrowRight => Some(((day_idx + 1).min(column_count - 1), slot)),
+                                    Key::ArrowUp => Some((day_idx, slot.saturating_sub(1))),
+                                    Key::ArrowDown => Some((day_idx, (slot + 1).min(last))),
+                                    Key::PageUp => Some((day_idx, slot.saturating_sub(hour_steps))),
+                                    Key::PageDown => Some((day_idx, (slot + hour_steps).min(last))),
+                                    Key::Home => Some((day_idx, 0)),
+                                    Key::End => Some((day_idx, last)),
+                                    _ => None,
+                                };
+                                if let Some((day_idx, slot)) = moved {
+                                    evt.prevent_default();
+                                    slot_cursor.set((day_idx, slot));
+                                    let (day, time) = slot_time(day_idx, slot);
+                                    document::eval(&format!("document.getElementById('{}')?.scrollIntoView({{ block: 'nearest' }});", slot_id(day, time)));
+                                } else if is_activation_key(&evt) {
+                                    evt.prevent_default();
+                                    // Drop the carried task on the focused slot
+                                    let Some(id) = dragged_task_id() else { return };
+                                    let (day, time) = slot_time(day_idx, slot);
+                                    let Some(start_dt) = day.and_time(time).and_local_timezone(Local).single() else { return };
+                                    let moved = Conflict::before_move(&app_state, id);
+                                    schedule_at(&app_state, id, start_dt, &settings_state.settings.peek().calendar);
+                                    dragged_task_id.set(None);
+                                    conflict.set(Some(moved));
+                                    let title = app_state.tasks.peek().iter().find(|t| t.id == id).map(|t| t.title.clone()).unwrap_or_default();
+                                    let when = slot_label(&i18n, day, time);
+                                    announcement.set(i18n.tf("Scheduled {title} on {when}.", &[("title", &title), ("when", &when)]));
+                                } else if evt.key() == Key::Escape && dragged_task_id().is_some() {
+                                    dragged_task_id.set(None);
+                                    announcement.set(i18n.t("Cancelled.").to_string());
+                                }
+                            },
 
                             // Horizontal hour lines (full-width background)
-                            div { class: "absolute inset-0 pointer-events-none z-0",
-                                for hour in 0..24 {
+                            div { class: "absolute inset-0 pointer-events-none z-0", aria_hidden: true,
+                                for hour in day_start_hour..day_end_hour {
                                     div {
                                         class: "absolute left-0 right-0 border-t border-space-700/25",
-                                        style: "top: {hour * HOUR_HEIGHT_PX}px;",
+                                        style: "top: {(hour - day_start_hour) * hour_height}px;",
+                                    }
+                                    // The day view also marks the half hours
+                                    if day.is_some() {
+                                        div {
+                                            class: "absolute left-0 right-0 border-t border-dashed border-space-700/15",
+                                            style: "top: {(hour - day_start_hour) * hour_height + hour_height / 2}px;",
+                                        }
                                     }
                                 }
                             }
@@ -216,76 +376,92 @@ pub fn Calendar() -> Element {
                             for (col_idx, &day) in week_days.iter().enumerate() {
                                 div {
                                     class: if col_idx > 0 { "relative border-l border-dashed border-space-700/40" } else { "relative" },
-                                    style: "height: {GRID_HEIGHT_PX}px;",
+                                    style: "height: {grid_height_px}px;",
+                                    role: "row",
+                                    aria_label: "{i18n.weekday(day.weekday())} {i18n.day_month(day)}",
 
-                                    // Current time indicator (red line) — only on today
-                                    if day == today {
+                                    // Current time indicator (red line) — only on today, within the visible hours
+                                    if let (true, Some(pct)) = (day == today, current_time_pct) {
                                         div {
+                                            aria_hidden: true,
                                             class: "absolute left-0 right-0 z-40 pointer-events-none flex items-center",
-                                            style: "top: {current_time_pct}%;",
+                                            style: "top: {pct}%;",
                                             div { class: "w-2.5 h-2.5 rounded-full bg-red-500 -ml-[5px] shadow-[0_0_6px_rgba(239,68,68,0.7)]" }
                                             div { class: "flex-1 border-t-2 border-red-500" }
                                         }
                                     }
 
-                                    // 15-minute interactive drop zones (96 per day)
-                                    for hour in 0..24i32 {
-                                        for quarter in 0..4i32 {
+                                    // Off-hours and protected time, shaded beneath everything else
+                                    for (top, height, label) in availability_bands(&prefs, day, (day_start_hour, day_end_hour), hour_height) {
+                                        if let Some(label) = label {
+                                            div {
+                                                aria_hidden: true,
+                                                class: "absolute left-0 right-0 z-0 pointer-events-none bg-neon-purple/10 border-y border-neon-purple/30 overflow-hidden",
+                                                style: "top: {top}px; height: {height}px;",
+                                                span { class: "block px-1 text-[10px] font-mono uppercase tracking-wider text-neon-purple/80 truncate", "{label}" }
+                                            }
+                                        } else {
+                                            div {
+                                                aria_hidden: true,
+                                                class: "absolute left-0 right-0 z-0 pointer-events-none bg-space-800/60",
+                                                style: "top: {top}px; height: {height}px;",
+                                            }
+                                        }
+                                    }
+
+                                    // Interactive drop zones, one per snap interval (15 minutes by default)
+                                    for hour in day_start_hour..day_end_hour {
+                                        for step in 0..(60 / snap_minutes) {
                                             {
-                                                let minute = quarter * 15;
-                                                let slot_top = hour as u32 * HOUR_HEIGHT_PX + quarter as u32 * (HOUR_HEIGHT_PX / 4);
-                                                let slot_height = HOUR_HEIGHT_PX / 4;
+                                                let minute = step * snap_minutes;
+                                                let slot_height = hour_height * snap_minutes / 60;
+                                                let slot_top = (hour - day_start_hour) * hour_height + step * slot_height;
+                                                let slot = (hour - day_start_hour) * (60 / snap_minutes) + step;
+                                                let is_cursor = cursor == (col_idx, slot);
+                                                let time = NaiveTime::from_hms_opt(hour, minute, 0).unwrap_or_default();
+                                                let cursor_ring = if is_cursor && grid_focused() { "ring-2 ring-inset ring-neon-cyan bg-neon-cyan/10" } else { "" };
                                                 // Raise z-index above blocks (z-20) when stretching or dragging
                                                 let zone_z = if stretching_task_id().is_some() || dragged_task_id().is_some() { "absolute left-0 right-0 z-30 hover:bg-white/[0.03] transition-colors" } else { "absolute left-0 right-0 z-10 hover:bg-white/[0.03] transition-colors cursor-pointer" };
                                                 rsx! {
                                                     div {
-                                                        class: "{zone_z}",
+                                                        class: "{zone_z} {cursor_ring}",
                                                         style: "top: {slot_top}px; height: {slot_height}px;",
+                                                        id: "{slot_id(day, time)}",
+                                                        role: "gridcell",
+                                                        aria_label: "{slot_label(&i18n, day, time)}",
+                                                        aria_selected: is_cursor,
                                                         ondragover: move |e| e.prevent_default(),
                                                         ondrop: move |_| {
                                                             if let Some(id) = dragged_task_id() {
-                                                                let start_dt = day
-                                                                    .and_hms_opt(hour as u32, minute as u32, 0)
-                                                                    .unwrap()
-                                                                    .and_local_timezone(Local)
-                                                                    .unwrap();
-                                                                
-                                                                let mut new_end = start_dt + chrono::Duration::hours(1);
-                                                                let tasks = app_state.tasks.read();
-                                                                if let Some(task) = tasks.iter().find(|t| t.id == id) {
-                                                                    if let (Some(old_start), Some(old_end)) = (task.scheduled_start, task.scheduled_end) {
-                                                                        let dur = old_end.signed_duration_since(old_start);
-                                                                        new_end = start_dt + dur;
-                                                                    }
-                                                                }
-                                                                drop(tasks);
-
-                                                                app_state.update_task_schedule(id, Some(start_dt), Some(new_end));
+                                                                // A slot skipped by a DST change has no local time; a repeated one takes its first
+                                                                let Some(start_dt) = day
+                                                                    .and_hms_opt(hour, minute, 0)
+                                                                    .and_then(|slot| slot.and_local_timezone(Local).earliest())
+                                                                else {
+                                                                    return;
+                                                                };
+                                                                let moved = Conflict::before_move(&app_state, id);
+                                                                schedule_at(&app_state, id, start_dt, &settings_state.settings.peek().calendar);
                                                                 dragged_task_id.set(None);
+                                                                conflict.set(Some(moved));
                                                             }
                                                         },
                                                         onmouseenter: move |_| {
                                                             if let Some(id) = stretching_task_id() {
                                                                 // Calculate the END time this slot represents
-                                                                let mut end_min = (minute + 15) as u32;
-                                                                let mut end_hour = hour as u32;
+                                                                let mut end_min = minute + snap_minutes;
+                                                                let mut end_hour = hour;
                                                                 if end_min >= 60 {
                                                                     end_min = 0;
                                                                     end_hour += 1;
                                                                 }
 
                                                                 let end_dt = if end_hour >= 24 {
-                                                                    day.and_hms_opt(0, 0, 0)
-                                                                        .unwrap()
-                                                                        .and_local_timezone(Local)
-                                                                        .unwrap()
-                                                                        + chrono::Duration::hours(24)
+                                                                    day.succ_opt().and_then(|next| next.and_hms_opt(0, 0, 0))
                                                                 } else {
                                                                     day.and_hms_opt(end_hour, end_min, 0)
-                                                                        .unwrap()
-                                                                        .and_local_timezone(Local)
-                                                                        .unwrap()
                                                                 };
+                                                                let Some(end_dt) = end_dt.and_then(|slot| slot.and_local_timezone(Local).earliest()) else { return };
 
                                                                 let tasks = app_state.tasks.read();
                                                                 if let Some(task) = tasks.iter().find(|t| t.id == id) {
@@ -298,7 +474,7 @@ pub fn Calendar() -> Element {
                                                                                 end_dt
                                                                             };
                                                                             drop(tasks);
-                                                                            app_state.update_task_schedule(id, Some(start), Some(final_end));
+                                                                            app_state.stretch_task_end(id, final_end);
                                                                         }
                                                                     }
                                                                 }
@@ -316,7 +492,11 @@ pub fn Calendar() -> Element {
                                         day: day,
                                         dragged_task_id: dragged_task_id,
                                         stretching_task_id: stretching_task_id,
+                                        stretch_origin: stretch_origin,
                                         is_dragging: dragged_task_id().is_some(),
+                                        menu_task: menu_task,
+                                        announcement: announcement,
+                                        hour_height: hour_height,
                                     }
                                 }
                             }
@@ -328,6 +508,344 @@ pub fn Calendar() -> Element {
     }
 }
 
+// ─── UNSCHEDULED SIDEBAR ─────────────────────────────────────────────────────
+
+/// Do First / Schedule tasks without a slot, ready to be dragged (or picked up with Enter)
+/// onto the week, day or month grid. Also holds the ICS export and import buttons and the list of
+/// imported calendars.
+#[component]
+pub(crate) fn UnscheduledSidebar(
+    dragged_task_id: Signal<Option<uuid::Uuid>>,
+    menu_task: Signal<Option<uuid::Uuid>>,
+    announcement: Signal<String>,
+) -> Element {
+    let app_state = use_context::<AppState>();
+    let i18n = use_context::<SettingsState>().i18n();
+    let mut import_status = use_signal(|| None::<String>);
+    let mut exporting = use_signal(|| false);
+
+    // Finished tasks stay on the grid as history but no longer wait to be scheduled
+    let unscheduled: Vec<Task> = app_state
+        .tasks
+        .read()
+        .iter()
+        .filter(|t| on_calendar(t) && t.scheduled_start.is_none() && t.status == TaskStatus::Todo)
+        .cloned()
+        .collect();
+
+    rsx! {
+        div { class: "w-64 bg-space-800/70 border-r border-space-700/60 flex flex-col shrink-0",
+            // Sidebar header
+            div { class: "p-4 border-b border-space-700/40 flex justify-between items-center",
+                div {
+                    h2 { class: "text-lg font-bold text-white tracking-tight", "{i18n.t(\"Unscheduled\")}" }
+                    p { class: "text-xs font-mono text-neon-cyan/80 tracking-widest", "{i18n.t(\"Drag to schedule\")}" }
+                }
+                // ICS Export Button: opens the export choices
+                button {
+                    class: "p-2 mb-6 rounded-lg hover:bg-space-700/60 text-gray-400 hover:text-neon-cyan transition-all duration-200",
+                    title: i18n.t("Export Calendar (.ics)"),
+                    aria_label: i18n.t("Export Calendar (.ics)"),
+                    onclick: move |_| exporting.set(true),
+                    svg { class: "w-4 h-4", fill: "none", stroke: "currentColor", view_box: "0 0 24 24",
+                        path { stroke_linecap: "round", stroke_linejoin: "round", stroke_width: "2", d: "M4 16v1a3 3 0 003 3h10a3 3 0 003-3v-1m-4-4l-4 4m0 0l-4-4m4 4V4" }
+                    }
+                }
+                // ICS Import Button: meetings become busy blocks
+                button {
+                    class: "p-2 mb-6 rounded-lg hover:bg-space-700/60 text-gray-400 hover:text-neon-cyan transition-all duration-200",
+                    title: i18n.t("Import busy time (.ics)"),
+                    aria_label: i18n.t("Import busy time (.ics)"),
+                    onclick: move |_| {
+                        dioxus::prelude::spawn(async move {
+                            if let Some(status) = import_busy(app_state, i18n).await {
+                                import_status.set(Some(status));
+                            }
+                        });
+                    },
+                    svg { class: "w-4 h-4", fill: "none", stroke: "currentColor", view_box: "0 0 24 24",
+                        path { stroke_linecap: "round", stroke_linejoin: "round", stroke_width: "2", d: "M4 16v1a3 3 0 003 3h10a3 3 0 003-3v-1m-4-8l-4-4m0 0L8 8m4-4v12" }
+                    }
+                }
+            }
+
+            // Task list
+            div { class: "flex-1 overflow-y-auto p-3 space-y-2", role: "list", aria_label: i18n.t("Unscheduled"),
+                if unscheduled.is_empty() {
+                    div { class: "text-center py-12 text-gray-600 text-xs italic font-mono", "{i18n.t(\"All tasks scheduled.\")}" }
+                }
+                for task in unscheduled {
+                    {
+                        let badge_color = quadrant_badge_color(&task.quadrant);
+                        rsx! {
+                            div {
+                                key: "{task.id}",
+                                id: "task-{task.id}",
+                                role: "listitem",
+                                tabindex: 0,
+                                aria_label: "{task.title}",
+                                aria_haspopup: "menu",
+                                draggable: true,
+                                ondragstart: move |_| dragged_task_id.set(Some(task.id)),
+                                onkeydown: {
+                                    let title = task.title.clone();
+                                    move |evt: KeyboardEvent| {
+                                        if opens_task_menu(&evt) {
+                                            evt.prevent_default();
+                                            menu_task.set(Some(task.id));
+                                        } else if is_activation_key(&evt) {
+                                            evt.prevent_default();
+                                            pick_up(dragged_task_id, announcement, task.id, i18n.tf(PICKED_UP, &[("title", &title)]));
+                                        }
+                                    }
+                                },
+                                oncontextmenu: move |evt| {
+                                    evt.prevent_default();
+                                    menu_task.set(Some(task.id));
+                                },
+                                class: "bg-space-900/60 py-5 px-5 rounded-lg border border-space-700/50 cursor-grab hover:border-white/15 transition-all duration-200 group active:cursor-grabbing focus:outline-none focus-visible:ring-2 focus-visible:ring-neon-cyan",
+                                div { class: "flex flex-col items-start m-auto w-fit gap-1",
+                                    span { class: "shrink-0 text-[9px] font-bold px-1.5 py-0.5 rounded {badge_color}",
+                                        match task.quadrant {
+                                            Quadrant::DoFirst => i18n.t("DO"),
+                                            Quadrant::Schedule => i18n.t("SCH"),
+                                            _ => ""
+                                        }
+                                    }
+                                    span { class: "text-[13px] font-medium pt-5 text-gray-200 leading-snug group-hover:text-white transition-colors", "{task.title}" }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            BusyCalendars { import_status }
+
+            if exporting() {
+                ExportDialog { onclose: move |_| exporting.set(false) }
+            }
+        }
+    }
+}
+
+/// Imported calendars with their block counts, each removable, plus the last import's outcome
+#[component]
+fn BusyCalendars(import_status: Signal<Option<String>>) -> Element {
+    let app_state = use_context::<AppState>();
+    let i18n = use_context::<SettingsState>().i18n();
+
+    let mut sources: Vec<(String, usize)> = Vec::new();
+    for block in app_state.busy.read().iter() {
+        match sources.iter_mut().find(|(name, _)| *name == block.source) {
+            Some((_, count)) => *count += 1,
+            None => sources.push((block.source.clone(), 1)),
+        }
+    }
+    if sources.is_empty() && import_status().is_none() {
+        return rsx! {};
+    }
+
+    rsx! {
+        div { class: "p-3 border-t border-space-700/40 space-y-1",
+            h3 { class: "text-[10px] font-mono tracking-widest text-gray-500 uppercase", "{i18n.t(\"Busy calendars\")}" }
+            if let Some(status) = import_status() {
+                p { class: "text-xs text-gray-400", role: "status", "{status}" }
+            }
+            for (source, count) in sources {
+                div { key: "{source}", class: "flex items-center gap-2 text-xs text-gray-300",
+                    span { class: "w-2 h-2 rounded-sm bg-gray-500 shrink-0" }
+                    span { class: "flex-1 truncate", title: "{source}", "{source}" }
+                    span { class: "font-mono text-gray-500", "{count}" }
+                    button {
+                        class: "text-gray-500 hover:text-white",
+                        aria_label: i18n.tf("Remove {name}", &[("name", &source)]),
+                        onclick: {
+                            let source = source.clone();
+                            move |_| {
+                                app_state.remove_busy_source(&source);
+                                import_status.set(None);
+                            }
+                        },
+                        "×"
+                    }
+                }
+            }
+        }
+    }
+}
+
+// ─── VIEW SWITCHER ───────────────────────────────────────────────────────────
+
+/// CALENDAR MODE: Which calendar layout is on screen
+#[derive(Clone, Copy, PartialEq)]
+pub(crate) enum CalendarMode {
+    Week,
+    Day,
+    Month,
+    Agenda,
+}
+
+/// Week / Day / Month / Agenda buttons shared by every calendar layout.
+/// `day_target` is the date the Day button opens.
+#[component]
+pub(crate) fn CalendarModeSwitch(active: CalendarMode, day_target: NaiveDate) -> Element {
+    let navigator = use_navigator();
+    let i18n = use_context::<SettingsState>().i18n();
+    let modes = [
+        (CalendarMode::Week, i18n.t("Week")),
+        (CalendarMode::Day, i18n.t("Day")),
+        (CalendarMode::Month, i18n.t("Month")),
+        (CalendarMode::Agenda, i18n.t("Agenda")),
+    ];
+
+    rsx! {
+        div { class: "ml-auto flex rounded-md border border-space-700/60 overflow-hidden text-sm font-semibold", role: "group",
+            for (mode, label) in modes {
+                button {
+                    class: if mode == active { "px-4 py-2 bg-neon-cyan/10 text-neon-cyan" } else { "px-4 py-2 text-gray-400 hover:text-white" },
+                    aria_pressed: mode == active,
+                    onclick: move |_| {
+                        navigator.push(match mode {
+                            CalendarMode::Week => Route::Calendar {},
+                            CalendarMode::Day => Route::CalendarDay { date: day_target },
+                            CalendarMode::Month => Route::CalendarMonth {},
+                            CalendarMode::Agenda => Route::CalendarAgenda {},
+                        });
+                    },
+                    "{label}"
+                }
+            }
+        }
+    }
+}
+
+// ─── CONFLICT BANNER ─────────────────────────────────────────────────────────
+
+/// A block just dropped or resized, with the slot it had before so the banner's Undo puts back
+/// only that block (a global undo could revert some later, unrelated change instead)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Conflict {
+    pub id: uuid::Uuid,
+    pub start: Option<DateTime<Local>>,
+    pub end: Option<DateTime<Local>>,
+}
+
+impl Conflict {
+    /// Remembers `id`'s current slot; call before moving or resizing it
+    pub(crate) fn before_move(app_state: &AppState, id: uuid::Uuid) -> Self {
+        let tasks = app_state.tasks.peek();
+        let task = tasks.iter().find(|t| t.id == id);
+        Self { id, start: task.and_then(|t| t.scheduled_start), end: task.and_then(|t| t.scheduled_end) }
+    }
+}
+
+/// Warns after a drop or resize that left `conflict` on top of other blocks or imported meetings, outside working
+/// hours or on protected time, and offers to shift it to the next free slot.
+/// Hides itself once nothing is wrong with the block any more.
+#[component]
+pub(crate) fn ConflictBanner(conflict: Signal<Option<Conflict>>) -> Element {
+    let app_state = use_context::<AppState>();
+    let settings_state = use_context::<SettingsState>();
+    let i18n = settings_state.i18n();
+    let prefs = settings_state.settings.read().calendar.clone();
+    let default_block = prefs.default_block();
+
+    let Some(moved) = conflict() else { return rsx! {} };
+    let id = moved.id;
+    let tasks = app_state.tasks.read();
+    let Some((task, (start, end))) = tasks.iter().find(|t| t.id == id).and_then(|t| Some((t, block_span(t, default_block)?))) else {
+        return rsx! {};
+    };
+    let title = format!("\"{}\"", task.title);
+    let mut others: Vec<String> = overlapping(&tasks, id, start, end, default_block).iter().map(|t| format!("\"{}\"", t.title)).collect();
+    drop(tasks);
+    others.extend(app_state.busy.read().iter().filter(|b| b.start < end && start < b.end).map(|b| format!("\"{}\"", b.title)));
+
+    let (from, to) = (start.naive_local(), end.naive_local());
+    let mut problems = Vec::new();
+    if !others.is_empty() {
+        problems.push(i18n.tf("{title} overlaps {others}.", &[("title", &title), ("others", &others.join(", "))]));
+    }
+    if !planner::in_work_hours(&prefs, from, to) {
+        problems.push(i18n.tf("{title} is outside working hours.", &[("title", &title)]));
+    }
+    for label in planner::protected_hits(&prefs, from, to) {
+        problems.push(i18n.tf("{title} is in protected time ({label}).", &[("title", &title), ("label", &label)]));
+    }
+    if problems.is_empty() {
+        return rsx! {};
+    }
+    let message = problems.join(" ");
+
+    rsx! {
+        div { class: "mx-4 mt-3 px-4 py-2 rounded-lg border border-neon-amber/40 bg-neon-amber/10 flex items-center gap-4 text-sm shrink-0",
+            role: "alert",
+            span { class: "flex-1 text-neon-amber", "{message}" }
+            button {
+                class: "text-xs font-bold uppercase tracking-wide text-white hover:text-neon-cyan",
+                onclick: move |_| {
+                    let prefs = settings_state.settings.peek().calendar.clone();
+                    let slot = planner::next_free_slot(&app_state.tasks.peek(), &app_state.busy.peek(), Some(id), from, end - start, &prefs)
+                        .and_then(|slot| slot.and_local_timezone(Local).single());
+                    if let Some(shifted) = slot {
+                        app_state.update_task_schedule(id, Some(shifted), Some(shifted + (end - start)));
+                    }
+                    conflict.set(None);
+                },
+                "{i18n.t(\"Move to next free slot\")}"
+            }
+            button {
+                class: "text-xs font-bold uppercase tracking-wide text-gray-400 hover:text-white",
+                onclick: move |_| conflict.set(None),
+                "{i18n.t(\"Keep it here\")}"
+            }
+            button {
+                class: "text-xs font-bold uppercase tracking-wide text-gray-400 hover:text-white",
+                onclick: move |_| {
+                    app_state.update_task_schedule(id, moved.start, moved.end);
+                    conflict.set(None);
+                },
+                "{i18n.t(\"Undo\")}"
+            }
+        }
+    }
+}
+
+// ─── INLINE TITLE EDIT ───────────────────────────────────────────────────────
+
+/// The rename box shown after a double-click (or F2) on a task in any calendar layout.
+/// Saves as you type (the whole rename is one undo step); Enter, Escape or leaving the field closes it.
+#[component]
+pub(crate) fn InlineTitleInput(task_id: uuid::Uuid, title: String, class: &'static str, onclose: EventHandler<()>) -> Element {
+    let app_state = use_context::<AppState>();
+    let mut edited = use_signal(|| false);
+
+    rsx! {
+        input {
+            class: "{class}",
+            value: "{title}",
+            autofocus: true,
+            onkeydown: move |e| {
+                // Keep Enter from reaching the task (it would pick it up)
+                e.stop_propagation();
+                if e.key() == Key::Enter || e.key() == Key::Escape {
+                    onclose.call(());
+                }
+            },
+            oninput: move |e| {
+                // Checkpoint on the first keystroke only
+                if !edited() {
+                    edited.set(true);
+                    app_state.checkpoint();
+                }
+                app_state.write_task_title(task_id, e.value());
+            },
+            onblur: move |_| onclose.call(()),
+        }
+    }
+}
+
 // ─── TASK BLOCK OVERLAY COMPONENT ────────────────────────────────────────────
 
 /// Renders the positioned task blocks for a single day column.
@@ -337,37 +855,100 @@ fn DayTaskBlocks(
     day: NaiveDate,
     dragged_task_id: Signal<Option<uuid::Uuid>>,
     stretching_task_id: Signal<Option<uuid::Uuid>>,
+    stretch_origin: Signal<Option<Conflict>>,
     is_dragging: bool,
+    menu_task: Signal<Option<uuid::Uuid>>,
+    announcement: Signal<String>,
+    hour_height: u32,
 ) -> Element {
     let app_state = use_context::<AppState>();
+    let selection = use_context::<Selection>();
+    let settings_state = use_context::<SettingsState>();
+    let prefs = settings_state.settings.read().calendar.clone();
+    let i18n = settings_state.i18n();
+    let (first_hour, end_hour) = prefs.visible_range();
+    let range_start = first_hour * 60;
+    let range_end = end_hour * 60;
     
     // State to track if a task is currently being edited
     let mut editing_task_id = use_signal(|| None::<uuid::Uuid>);
 
+    // Pixel offset and height of start..end in the visible hours (None when it falls outside them)
+    let place = |start: DateTime<Local>, end: DateTime<Local>| {
+        let start_mins = start.hour() * 60 + start.minute();
+        let mut end_mins = end.hour() * 60 + end.minute();
+        if end_mins <= start_mins {
+            end_mins = 24 * 60; // wraps to end of day
+        }
+        // Skip blocks entirely outside the visible hours and clip the rest
+        if end_mins <= range_start || start_mins >= range_end {
+            return None;
+        }
+        let start_mins = start_mins.max(range_start) - range_start;
+        let end_mins = end_mins.min(range_end) - range_start;
+
+        let top_px = start_mins * hour_height / 60;
+        let height_px = (end_mins - start_mins) * hour_height / 60;
+        Some((top_px, height_px.max(15))) // minimum visible height
+    };
+
     let blocks: Vec<(Task, u32, u32, String)> = tasks
         .iter()
         .filter(|t| t.scheduled_start.map(|dt| dt.date_naive() == day).unwrap_or(false))
         .filter_map(|t| {
             let start = t.scheduled_start?;
-            let end = t.scheduled_end.unwrap_or(start + chrono::Duration::hours(1));
-
-            let start_mins = start.hour() * 60 + start.minute();
-            let mut end_mins = end.hour() * 60 + end.minute();
-            if end_mins <= start_mins {
-                end_mins = 24 * 60; // wraps to end of day
-            }
-
-            let top_px = start_mins * (HOUR_HEIGHT_PX / 60);
-            let height_px = (end_mins - start_mins) * (HOUR_HEIGHT_PX / 60);
-            let height_px = height_px.max(15); // minimum visible height
-
-            let time_str = format!("{} – {}", start.format("%-I:%M"), end.format("%-I:%M %p"));
+            let end = t.scheduled_end.unwrap_or(start + prefs.default_block());
+            let (top_px, height_px) = place(start, end)?;
+            let time_str = i18n.time_range(&start, &end);
 
             Some((t.clone(), top_px, height_px, time_str))
         })
         .collect();
 
-    let elements = blocks.into_iter().map(|(task, top_px, height_px, time_str)| {
+    // Imported meetings on this day, drawn grey and read-only
+    let meetings: Vec<(BusyBlock, u32, u32, String)> = app_state.busy
+        .read()
+        .iter()
+        .filter(|b| b.start.date_naive() == day)
+        .filter_map(|b| {
+            let (top_px, height_px) = place(b.start, b.end)?;
+            Some((b.clone(), top_px, height_px, i18n.time_range(&b.start, &b.end)))
+        })
+        .collect();
+
+    // Concurrent blocks (meetings included) share the column width
+    let spans: Vec<(u32, u32)> = blocks
+        .iter()
+        .map(|(_, top_px, height_px, _)| (*top_px, top_px + height_px))
+        .chain(meetings.iter().map(|(_, top_px, height_px, _)| (*top_px, top_px + height_px)))
+        .collect();
+    let mut layout = column_layout(&spans);
+    let meeting_layout = layout.split_off(blocks.len());
+
+    let busy_label = i18n.t("Busy");
+    let busy_elements = meetings.into_iter().zip(meeting_layout).map(|((meeting, top_px, height_px, time_str), (column, columns))| {
+        let width_pct = 100.0 / columns as f32;
+        let left_pct = column as f32 * width_pct;
+        rsx! {
+            div {
+                key: "busy-{meeting.source}-{meeting.uid}-{meeting.start.timestamp()}",
+                role: "img",
+                aria_label: "{busy_label}: {meeting.title}, {time_str} ({meeting.source})",
+                // Below the task blocks and never in the way of drops
+                class: "absolute rounded-md z-[15] pointer-events-none border-l-[3px] border-gray-500 bg-gray-600/30 outline outline-1 outline-white/5",
+                style: "top: {top_px}px; height: {height_px}px; left: calc({left_pct}% + 4px); width: calc({width_pct}% - 8px);",
+                title: "{meeting.title} ({meeting.source})",
+                div { class: "px-2 py-1 h-full flex flex-col overflow-hidden min-w-0",
+                    div { class: "text-[11px] font-semibold text-gray-300 leading-tight truncate", "{meeting.title}" }
+                    if height_px > 30 {
+                        div { class: "text-[9px] text-gray-400 font-medium mt-0.5 truncate", "{time_str}" }
+                    }
+                }
+            }
+        }
+    });
+
+    let elements = blocks.into_iter().zip(layout).map(|((task, top_px, height_px, time_str), (column, columns))| {
         let task_id = task.id;
         let title = task.title.clone();
         let (block_bg, block_border) = quadrant_block_colors(&task.quadrant);
@@ -375,14 +956,45 @@ fn DayTaskBlocks(
         // When another task is being dragged, make all blocks pass-through
         // so HTML5 drop events land on the grid zones beneath.
         let block_pointer = if is_dragging { "pointer-events-none" } else { "" };
+        // Completed tasks fade out and get struck through
+        let block_done = if task.status == TaskStatus::Done { "opacity-50 line-through" } else { "" };
+        let width_pct = 100.0 / columns as f32;
+        let left_pct = column as f32 * width_pct;
 
         rsx! {
             div {
                 key: "{task_id}",
+                id: "task-{task_id}",
+                role: "button",
+                tabindex: 0,
+                aria_label: "{title}, {time_str}",
+                aria_haspopup: "menu",
+                // Enter picks the block up for the keyboard grid, F2 renames it
+                onkeydown: {
+                    let title = title.clone();
+                    move |evt: KeyboardEvent| {
+                        if opens_task_menu(&evt) {
+                            evt.prevent_default();
+                            menu_task.set(Some(task_id));
+                        } else if evt.key() == Key::F2 {
+                            editing_task_id.set(Some(task_id));
+                        } else if is_activation_key(&evt) {
+                            evt.prevent_default();
+                            // The block sits inside the grid, whose Enter would drop it right away
+                            evt.stop_propagation();
+                            pick_up(dragged_task_id, announcement, task_id, i18n.tf(PICKED_UP, &[("title", &title)]));
+                        }
+                    }
+                },
+                oncontextmenu: move |evt| {
+                    evt.prevent_default();
+                    menu_task.set(Some(task_id));
+                },
                 // ── SECTION: Task Block Container ────────────────
                 // z-20 so drop zones (z-10 idle / z-30 active) can layer correctly
-                class: "absolute inset-x-0 mx-1 rounded-md z-20 group/block transition-shadow duration-200 hover:shadow-lg border-l-[3px] outline outline-1 outline-white/10 {block_border} {block_bg} {block_pointer}",
-                style: "top: {top_px}px; height: {height_px}px;",
+                class: "absolute rounded-md z-20 group/block transition-shadow duration-200 hover:shadow-lg border-l-[3px] outline outline-1 outline-white/10 focus-visible:ring-2 focus-visible:ring-neon-cyan {block_border} {block_bg} {block_pointer} {block_done} {selection.reveal_class(task_id)}",
+                style: "top: {top_px}px; height: {height_px}px; left: calc({left_pct}% + 4px); width: calc({width_pct}% - 8px);",
+                onmounted: move |evt| selection.scroll_if_revealed(task_id, &evt.data()),
                 title: "{title}",
                 draggable: if editing_task_id() != Some(task_id) { "true" } else { "false" },
                 ondragstart: move |_| {
@@ -399,35 +1011,19 @@ fn DayTaskBlocks(
                 div { class: "px-2 py-1 h-full flex flex-col overflow-hidden min-w-0",
                     // Title rendering (normal vs inline input edit)
                     if editing_task_id() == Some(task_id) {
-                        input {
+                        InlineTitleInput {
+                            task_id,
+                            title: title.clone(),
                             class: "w-full bg-black/40 text-white text-[20px] font-semibold px-1 py-0.5 rounded border border-white/30 focus:outline-none focus:border-neon-cyan",
-                            value: "{title}",
-                            autofocus: true,
-                            onkeydown: move |e| {
-                                if e.key() == dioxus::events::Key::Enter {
-                                    editing_task_id.set(None);
-                                } else if e.key() == dioxus::events::Key::Escape {
-                                    editing_task_id.set(None);
-                                }
-                            },
-                            oninput: move |e| {
-                                let new_val = e.value();
-                                let mut tasks_sig = app_state.tasks;
-                                let mut tasks = tasks_sig.write();
-                                if let Some(t) = tasks.iter_mut().find(|x| x.id == task_id) {
-                                    t.title = new_val;
-                                }
-                                drop(tasks);
-                                app_state.save_tasks();
-                            },
-                            onblur: move |_| {
-                                editing_task_id.set(None);
-                            }
+                            onclose: move |_| editing_task_id.set(None),
                         }
                     } else {
                         div { 
                             class: "text-[11px] font-semibold text-white leading-tight overflow-hidden text-ellipsis whitespace-nowrap min-w-0 pr-1 cursor-grab active:cursor-grabbing", 
                             title: "{title}",
+                            if columns > 1 {
+                                span { class: "text-neon-amber mr-1", title: i18n.t("Overlaps another task"), "⚠" }
+                            }
                             "{title}" 
                         }
                     }
@@ -439,6 +1035,7 @@ fn DayTaskBlocks(
 
                 // Close button (hover reveal)
                 button {
+                    aria_label: i18n.t("Unschedule"),
                     class: "absolute top-0.5 right-0.5 w-5 h-5 rounded-full bg-black/30 hover:bg-black/60 text-white/50 hover:text-white flex items-center justify-center opacity-0 group-hover/block:opacity-100 transition-opacity backdrop-blur-sm z-10",
                     onclick: move |e| {
                         e.stop_propagation();
@@ -454,6 +1051,9 @@ fn DayTaskBlocks(
                     class: "absolute bottom-0 left-0 right-0 h-3 cursor-ns-resize opacity-40 group-hover/block:opacity-100 transition-opacity flex items-end justify-center pb-0.5 z-10",
                     onmousedown: move |e| {
                         e.stop_propagation();
+                        // One undo step for the whole resize
+                        app_state.checkpoint();
+                        stretch_origin.set(Some(Conflict::before_move(&app_state, task_id)));
                         stretching_task_id.set(Some(task_id));
                     },
                     div { class: "w-8 h-[3px] rounded-full bg-white/50 group-hover/block:bg-white/80" }
@@ -462,62 +1062,192 @@ fn DayTaskBlocks(
         }
     });
 
-    rsx! { {elements} }
+    rsx! {
+        {busy_elements}
+        {elements}
+    }
 }
 
 // ─── HELPER FUNCTIONS ────────────────────────────────────────────────────────
 
+/// Tasks eligible for the calendar (Do First + Schedule quadrants only)
+pub(crate) fn on_calendar(task: &Task) -> bool {
+    matches!(task.quadrant, Quadrant::DoFirst | Quadrant::Schedule)
+}
+
+/// Announcement when a task is picked up from the keyboard
+pub(crate) const PICKED_UP: &str = "Picked up {title}. Arrow keys choose where, Enter drops it, Escape cancels.";
+
+/// Enter or Space
+pub(crate) fn is_activation_key(evt: &KeyboardEvent) -> bool {
+    evt.key() == Key::Enter || evt.key() == Key::Character(" ".to_string())
+}
+
+/// Carries a task to the keyboard grid: same state as a mouse drag, then focus the drop zones
+pub(crate) fn pick_up(mut dragged_task_id: Signal<Option<uuid::Uuid>>, mut announcement: Signal<String>, id: uuid::Uuid, message: String) {
+    dragged_task_id.set(Some(id));
+    announcement.set(message);
+    document::eval("document.getElementById('calendar-grid')?.focus();");
+}
+
+/// Schedules a dropped task at `start`, keeping its length if it already had a slot.
+/// With "snap to working hours" on, a drop off-hours or on protected time moves to the next open slot.
+pub(crate) fn schedule_at(app_state: &AppState, id: uuid::Uuid, start: DateTime<Local>, prefs: &CalendarPrefs) {
+    let length = app_state.tasks.peek()
+        .iter()
+        .find(|t| t.id == id)
+        .and_then(|t| Some(t.scheduled_end? - t.scheduled_start?))
+        .unwrap_or(prefs.default_block());
+    let (from, to) = (start.naive_local(), (start + length).naive_local());
+    let off_hours = !planner::in_work_hours(prefs, from, to) || !planner::protected_hits(prefs, from, to).is_empty();
+    let start = match prefs.snap_to_work_hours && off_hours {
+        true => planner::next_free_slot(&[], &[], None, from, length, prefs)
+            .and_then(|slot| slot.and_local_timezone(Local).single())
+            .unwrap_or(start),
+        false => start,
+    };
+    app_state.update_task_schedule(id, Some(start), Some(start + length));
+}
+
+/// Start and end of a scheduled block (a missing end counts as the default block)
+fn block_span(task: &Task, default_block: chrono::Duration) -> Option<(DateTime<Local>, DateTime<Local>)> {
+    let start = task.scheduled_start?;
+    Some((start, task.scheduled_end.unwrap_or(start + default_block)))
+}
+
+/// Open calendar tasks other than `id` whose blocks share time with start..end
+pub(crate) fn overlapping(tasks: &[Task], id: uuid::Uuid, start: DateTime<Local>, end: DateTime<Local>, default_block: chrono::Duration) -> Vec<&Task> {
+    tasks
+        .iter()
+        .filter(|t| t.id != id && on_calendar(t) && t.status == TaskStatus::Todo)
+        .filter(|t| block_span(t, default_block).is_some_and(|(s, e)| s < end && start < e))
+        .collect()
+}
+
+/// Side-by-side placement of concurrent blocks, like Google Calendar: (column, columns in its group)
+/// for each span in input order. Spans are (top, bottom) in any unit.
+fn column_layout(spans: &[(u32, u32)]) -> Vec<(usize, usize)> {
+    let mut order: Vec<usize> = (0..spans.len()).collect();
+    order.sort_by_key(|&i| (spans[i].0, std::cmp::Reverse(spans[i].1)));
+
+    let mut layout = vec![(0, 1); spans.len()];
+    // Current group of transitively overlapping blocks, and where each of its columns ends
+    let mut group: Vec<usize> = Vec::new();
+    let mut column_ends: Vec<u32> = Vec::new();
+    let mut group_end = 0;
+    for i in order {
+        let (top, bottom) = spans[i];
+        if !group.is_empty() && top >= group_end {
+            for &j in &group {
+                layout[j].1 = column_ends.len();
+            }
+            group.clear();
+            column_ends.clear();
+        }
+        let column = match column_ends.iter().position(|&end| end <= top) {
+            Some(c) => {
+                column_ends[c] = bottom;
+                c
+            }
+            None => {
+                column_ends.push(bottom);
+                column_ends.len() - 1
+            }
+        };
+        layout[i].0 = column;
+        group_end = if group.is_empty() { bottom } else { group_end.max(bottom) };
+        group.push(i);
+    }
+    for &j in &group {
+        layout[j].1 = column_ends.len();
+    }
+    layout
+}
+
+/// Shaded bands of a day column as (top px, height px, protected block label); a band without a
+/// label is off-hours. Clipped to the visible `hours`.
+fn availability_bands(prefs: &CalendarPrefs, day: NaiveDate, hours: (u32, u32), hour_height: u32) -> Vec<(u32, u32, Option<String>)> {
+    let (first, last) = (hours.0 * 60, hours.1 * 60);
+    let band = |from: u32, to: u32| {
+        let (from, to) = (from.clamp(first, last), to.clamp(first, last));
+        (to > from).then(|| ((from - first) * hour_height / 60, (to - from) * hour_height / 60))
+    };
+
+    let mut bands = Vec::new();
+    match prefs.work_hours_on(day.weekday()) {
+        Some((start, end)) => {
+            bands.extend(band(first, start * 60).map(|(top, height)| (top, height, None)));
+            bands.extend(band(end * 60, last).map(|(top, height)| (top, height, None)));
+        }
+        None => bands.extend(band(first, last).map(|(top, height)| (top, height, None))),
+    }
+    for (label, start, end) in planner::protected_on(prefs, day) {
+        let minutes = |t: chrono::NaiveDateTime| ((t - day.and_time(NaiveTime::MIN)).num_minutes()) as u32;
+        if let Some((top, height)) = band(minutes(start), minutes(end)) {
+            bands.push((top, height, Some(label.to_string())));
+        }
+    }
+    bands
+}
+
+/// DOM id of a drop zone (used for aria-activedescendant)
+fn slot_id(day: NaiveDate, time: NaiveTime) -> String {
+    format!("slot-{day}-{}", time.format("%H%M"))
+}
+
+/// Spoken name of a drop zone: "Monday Mar 5, 2:15 PM"
+fn slot_label(i18n: &I18n, day: NaiveDate, time: NaiveTime) -> String {
+    format!("{} {}, {}", i18n.weekday(day.weekday()), i18n.day_month(day), i18n.time(&time))
+}
+
 /// Returns Tailwind classes for the sidebar quadrant badge.
-fn quadrant_badge_color(q: &Quadrant) -> &'static str {
+pub(crate) fn quadrant_badge_color(q: &Quadrant) -> &'static str {
     match q {
-        Quadrant::DoFirst => "bg-neon-pink/20 text-neon-pink",
-        Quadrant::Schedule => "bg-neon-cyan/20 text-neon-cyan",
-        Quadrant::Delegate => "bg-neon-amber/20 text-neon-amber",
+        Quadrant::DoFirst => "bg-quadrant-do-first/20 text-quadrant-do-first",
+        Quadrant::Schedule => "bg-quadrant-schedule/20 text-quadrant-schedule",
+        Quadrant::Delegate => "bg-quadrant-delegate/20 text-quadrant-delegate",
         Quadrant::Delete => "bg-space-700 text-gray-400",
         Quadrant::Unsorted => "bg-space-700 text-gray-400",
     }
 }
 
 /// Returns (background class, border-left class) for calendar task blocks.
-fn quadrant_block_colors(q: &Quadrant) -> (&'static str, &'static str) {
+pub(crate) fn quadrant_block_colors(q: &Quadrant) -> (&'static str, &'static str) {
     match q {
-        Quadrant::DoFirst => ("bg-pink-950/80 hover:bg-pink-900/90", "border-neon-pink"),
-        Quadrant::Schedule => ("bg-cyan-950/80 hover:bg-cyan-900/90", "border-neon-cyan"),
-        Quadrant::Delegate => ("bg-amber-950/80 hover:bg-amber-900/90", "border-neon-amber"),
-        Quadrant::Delete => ("bg-space-800 hover:bg-space-700", "border-gray-500"),
+        Quadrant::DoFirst => ("bg-quadrant-do-first/20 hover:bg-quadrant-do-first/30", "border-quadrant-do-first"),
+        Quadrant::Schedule => ("bg-quadrant-schedule/20 hover:bg-quadrant-schedule/30", "border-quadrant-schedule"),
+        Quadrant::Delegate => ("bg-quadrant-delegate/20 hover:bg-quadrant-delegate/30", "border-quadrant-delegate"),
+        Quadrant::Delete => ("bg-space-800 hover:bg-space-700", "border-quadrant-delete"),
         Quadrant::Unsorted => ("bg-space-800 hover:bg-space-700", "border-gray-500"),
     }
 }
 
-/// Exports all scheduled tasks as an ICS file via a native save dialog.
-async fn export_ics(tasks: &[Task]) {
-    let mut cal = ICalendar::new();
-
-    for t in tasks {
-        if let (Some(start), Some(end)) = (t.scheduled_start, t.scheduled_end) {
-            let mut event = Event::new();
-            event.summary(&t.title);
-            event.starts(start.with_timezone(&chrono::Utc));
-            event.ends(end.with_timezone(&chrono::Utc));
-
-            // Build description with Eisenhower status + notes
-            let status_label = match t.quadrant {
-                Quadrant::DoFirst => "Do First (Urgent & Important)",
-                Quadrant::Schedule => "Schedule (Important, Not Urgent)",
-                Quadrant::Delegate => "Delegate (Urgent, Not Important)",
-                Quadrant::Delete => "Delete (Neither)",
-                Quadrant::Unsorted => "Unsorted",
-            };
-            let notes = t.description.as_deref().unwrap_or("No notes.");
-            let desc = format!("Eisenhower Status: {}\n\nNotes:\n{}", status_label, notes);
-            event.description(&desc);
-
-            cal.push(event);
-        }
-    }
+/// Reads an .ics file picked in a native dialog into busy blocks. Importing the same calendar again
+/// replaces its blocks. Returns what happened, or None when the dialog was cancelled.
+pub(crate) async fn import_busy(app_state: AppState, i18n: I18n) -> Option<String> {
+    let handle = rfd::AsyncFileDialog::new()
+        .set_title(i18n.t("Import busy time (.ics)"))
+        .add_filter("iCalendar", &["ics"])
+        .pick_file()
+        .await?;
+    let file_name = handle.file_name();
+    let content = String::from_utf8_lossy(&handle.read().await).into_owned();
+    let stem = file_name.strip_suffix(".ics").unwrap_or(&file_name).to_string();
 
-    let ics_string = cal.to_string();
+    // The calendar's own name keeps re-imports of a renamed export together
+    let import = match ics::read_busy(&content, &stem, Local::now()) {
+        Ok(import) => import,
+        Err(e) => return Some(i18n.tf("Could not read {file}: {error}", &[("file", &file_name), ("error", &e)])),
+    };
+    let source = import.name.filter(|n| !n.trim().is_empty()).unwrap_or(stem);
+    let blocks: Vec<BusyBlock> = import.blocks.into_iter().map(|b| BusyBlock { source: source.clone(), ..b }).collect();
+    let count = blocks.len();
+    app_state.replace_busy(&source, blocks);
+    Some(i18n.tf("{source}: {count} busy blocks", &[("source", &source), ("count", &count)]))
+}
 
+/// Saves ICS text via a native save dialog
+pub(crate) async fn save_ics(ics_string: String) {
     if let Some(handle) = rfd::AsyncFileDialog::new()
         .set_file_name("errday_calendar.ics")
         .add_filter("iCalendar", &["ics"])
@@ -527,3 +1257,47 @@ async fn export_ics(tasks: &[Task]) {
         let _ = std::fs::write(handle.path(), ics_string);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn block(title: &str, quadrant: Quadrant, from: u32, to: Option<u32>) -> Task {
+        let at = |hour| Local.with_ymd_and_hms(2026, 3, 2, hour, 0, 0).unwrap();
+        let mut task = Task::new(title.to_string());
+        task.quadrant = quadrant;
+        task.scheduled_start = Some(at(from));
+        task.scheduled_end = to.map(at);
+        task
+    }
+
+    #[test]
+    fn concurrent_blocks_share_the_width_of_their_group() {
+        // Two overlapping blocks, a third that fits under the first, then one on its own
+        let spans = [(0, 60), (30, 90), (60, 120), (200, 260)];
+        assert_eq!(column_layout(&spans), vec![(0, 2), (1, 2), (0, 2), (0, 1)]);
+        // Longer blocks starting at the same time take the first column
+        assert_eq!(column_layout(&[(0, 30), (0, 90)]), vec![(1, 2), (0, 2)]);
+        assert!(column_layout(&[]).is_empty());
+    }
+
+    #[test]
+    fn overlaps_only_count_other_open_calendar_blocks() {
+        let hour = chrono::Duration::hours(1);
+        let moved = block("Moved", Quadrant::DoFirst, 9, Some(11));
+        let mut done = block("Done", Quadrant::Schedule, 9, Some(10));
+        done.status = TaskStatus::Done;
+        let tasks = vec![
+            moved.clone(),
+            block("Meeting", Quadrant::Schedule, 10, Some(12)),
+            block("No end", Quadrant::DoFirst, 8, None), // Counts as 8:00 - 9:00, so it only touches
+            block("Delegated", Quadrant::Delegate, 9, Some(10)),
+            block("After", Quadrant::DoFirst, 11, Some(12)),
+            done,
+        ];
+        let (start, end) = (moved.scheduled_start.unwrap(), moved.scheduled_end.unwrap());
+        let titles: Vec<&str> = overlapping(&tasks, moved.id, start, end, hour).iter().map(|t| t.title.as_str()).collect();
+        assert_eq!(titles, vec!["Meeting"]);
+    }
+}
@@ -0,0 +1,324 @@
+use dioxus::prelude::*;
+use std::collections::BTreeMap;
+use crate::components::{opens_task_menu, Shortcuts, TaskMenu};
+use crate::keymap::Action;
+use crate::models::{Task, TaskStatus};
+use crate::routes::Route;
+use crate::settings::SettingsState;
+use crate::store::AppState;
+use crate::views::calendar::{
+    is_activation_key, on_calendar, pick_up, quadrant_block_colors, schedule_at, CalendarMode,
+    CalendarModeSwitch, Conflict, ConflictBanner, InlineTitleInput, UnscheduledSidebar, PICKED_UP,
+};
+use chrono::{Datelike, Local, NaiveDate, NaiveTime};
+
+// ─── CONSTANTS ───────────────────────────────────────────────────────────────
+/// Chips shown in a day cell before "+N more"
+const MAX_CHIPS: usize = 3;
+/// Time given to a task dropped on a day off before it ever had one (kept inside the visible hours);
+/// on working days it starts when the working hours do
+const MONTH_DROP_HOUR: u32 = 9;
+
+/// MONTH VIEW: Six weeks around the month, scheduled tasks as compact chips.
+/// Dropping a task on a day moves it there and keeps its time of day.
+/// Keyboard: Enter on a task picks it up, arrow keys move between days, Enter drops it.
+#[component]
+pub fn CalendarMonth() -> Element {
+    let app_state = use_context::<AppState>();
+    let settings_state = use_context::<SettingsState>();
+    let navigator = use_navigator();
+    let prefs = settings_state.settings.read().calendar.clone();
+    let i18n = settings_state.i18n();
+    let (first_hour, end_hour) = prefs.visible_range();
+    let drop_hour = MONTH_DROP_HOUR.clamp(first_hour, end_hour - 1);
+
+    let today = Local::now().date_naive();
+    let mut month_start = use_signal(|| today.with_day(1).unwrap_or(today));
+
+    // Previous / next from the keyboard shortcut layer step whole months
+    let shortcuts = use_context::<Shortcuts>();
+    use_effect(move || {
+        let Some(action) = shortcuts.take_calendar_command() else { return };
+        let current = *month_start.peek();
+        month_start.set(match action {
+            Action::PreviousWeek => shift_month(current, -1),
+            Action::NextWeek => shift_month(current, 1),
+            _ => Local::now().date_naive().with_day(1).unwrap_or(current),
+        });
+    });
+
+    // ── Interaction State ───────────────────────────────────────────────────
+    let mut dragged_task_id = use_signal(|| None::<uuid::Uuid>);
+    let mut menu_task = use_signal(|| None::<uuid::Uuid>);
+    let mut announcement = use_signal(String::new);
+    let mut conflict = use_signal(|| None::<Conflict>);
+    let mut grid_focused = use_signal(|| false);
+    // Focused day as an index into the 42 cells
+    let mut day_cursor = use_signal(|| None::<usize>);
+
+    // ── Derived Data ────────────────────────────────────────────────────────
+    let first = *month_start.read();
+    let grid_start = prefs.week_start_for(first);
+    let days: Vec<NaiveDate> = (0..42).map(|i| grid_start + chrono::Duration::days(i)).collect();
+    let cursor = day_cursor().unwrap_or_else(|| days.iter().position(|d| *d == today).unwrap_or(first.signed_duration_since(grid_start).num_days() as usize));
+
+    let mut by_day: BTreeMap<NaiveDate, Vec<Task>> = BTreeMap::new();
+    for task in app_state.tasks.read().iter().filter(|t| on_calendar(t)) {
+        if let Some(start) = task.scheduled_start {
+            by_day.entry(start.date_naive()).or_default().push(task.clone());
+        }
+    }
+    for tasks in by_day.values_mut() {
+        tasks.sort_by_key(|t| t.scheduled_start);
+    }
+
+    // Moves a task to a day, keeping its time of day (or starting at the drop hour)
+    let mut drop_on = move |id: uuid::Uuid, day: NaiveDate| {
+        let time = app_state.tasks.peek()
+            .iter()
+            .find(|t| t.id == id)
+            .and_then(|t| t.scheduled_start)
+            .map(|s| s.time())
+            .unwrap_or_else(|| {
+                let hour = settings_state.settings.peek().calendar.work_hours_on(day.weekday()).map_or(drop_hour, |(start, _)| start);
+                NaiveTime::from_hms_opt(hour, 0, 0).unwrap_or_default()
+            });
+        if let Some(start) = day.and_time(time).and_local_timezone(Local).single() {
+            let moved = Conflict::before_move(&app_state, id);
+            schedule_at(&app_state, id, start, &settings_state.settings.peek().calendar);
+            conflict.set(Some(moved));
+        }
+    };
+
+    let weekday_names: Vec<&str> = days[..7].iter().map(|d| i18n.weekday_short(d.weekday())).collect();
+    let cursor_id = format!("month-day-{}", days[cursor]);
+
+    rsx! {
+        div { class: "flex h-full bg-space-900 text-gray-100 font-sans overflow-hidden select-none",
+            div { class: "sr-only", role: "status", aria_live: "polite", "{announcement}" }
+            if let Some(task_id) = menu_task() {
+                TaskMenu { task_id, onclose: move |_| menu_task.set(None) }
+            }
+
+            UnscheduledSidebar { dragged_task_id, menu_task, announcement }
+
+            div { class: "flex-1 flex flex-col min-w-0",
+                // ── Top Bar: Month navigation ───────────────────────────
+                div { class: "h-14 border-b border-space-700/60 bg-space-800/50 flex items-center px-4 gap-4 shrink-0",
+                    button {
+                        class: "w-12 h-12 rounded-full hover:bg-space-700 text-gray-400 hover:text-white flex items-center justify-center transition-colors text-2xl font-bold",
+                        aria_label: i18n.t("Previous month"),
+                        onclick: move |_| {
+                            month_start.set(shift_month(first, -1));
+                            day_cursor.set(None);
+                        },
+                        "‹"
+                    }
+                    button {
+                        class: "w-12 h-12 rounded-full hover:bg-space-700 text-gray-400 hover:text-white flex items-center justify-center transition-colors text-2xl font-bold",
+                        aria_label: i18n.t("Next month"),
+                        onclick: move |_| {
+                            month_start.set(shift_month(first, 1));
+                            day_cursor.set(None);
+                        },
+                        "›"
+                    }
+                    span { class: "text-base font-semibold text-white ml-2 capitalize", "{i18n.month_year(first)}" }
+                    CalendarModeSwitch { active: CalendarMode::Month, day_target: if first.month() == today.month() && first.year() == today.year() { today } else { first } }
+                    button {
+                        class: "text-sm font-semibold px-4 py-2 rounded-md border border-space-700/60 text-gray-300 hover:text-white hover:bg-space-700/40 transition-all",
+                        onclick: move |_| {
+                            month_start.set(Local::now().date_naive().with_day(1).unwrap_or(first));
+                            day_cursor.set(None);
+                        },
+                        "{i18n.t(\"Today\")}"
+                    }
+                }
+
+                ConflictBanner { conflict }
+
+                // ── Weekday names ───────────────────────────────────────
+                div { class: "grid grid-cols-7 border-b border-space-700/60 shrink-0", aria_hidden: true,
+                    for name in weekday_names {
+                        div { class: "py-2 text-center text-[11px] font-medium text-gray-500 uppercase tracking-widest", "{name}" }
+                    }
+                }
+
+                // ── Month Grid: 6 rows × 7 days ─────────────────────────
+                div { class: "flex-1 grid grid-rows-6 min-h-0 focus:outline-none",
+                    id: "calendar-grid",
+                    role: "grid",
+                    tabindex: 0,
+                    aria_label: "{i18n.month_year(first)}",
+                    aria_activedescendant: "{cursor_id}",
+                    onfocus: move |_| grid_focused.set(true),
+                    onblur: move |_| grid_focused.set(false),
+                    onkeydown: move |evt| {
+                        let moved = match evt.key() {
+                            Key::ArrowLeft => Some(cursor.saturating_sub(1)),
+                            Key::ArrowRight => Some((cursor + 1).min(41)),
+                            Key::ArrowUp => Some(cursor.saturating_sub(7)),
+                            Key::ArrowDown => Some((cursor + 7).min(41)),
+                            _ => None,
+                        };
+                        if let Some(next) = moved {
+                            evt.prevent_default();
+                            day_cursor.set(Some(next));
+                        } else if is_activation_key(&evt) {
+                            evt.prevent_default();
+                            let Some(id) = dragged_task_id() else { return };
+                            let day = grid_start + chrono::Duration::days(cursor as i64);
+                            drop_on(id, day);
+                            dragged_task_id.set(None);
+                            let title = app_state.tasks.peek().iter().find(|t| t.id == id).map(|t| t.title.clone()).unwrap_or_default();
+                            let when = format!("{} {}", i18n.weekday(day.weekday()), i18n.date(day));
+                            announcement.set(i18n.tf("Scheduled {title} on {when}.", &[("title", &title), ("when", &when)]));
+                        } else if evt.key() == Key::Escape && dragged_task_id().is_some() {
+                            dragged_task_id.set(None);
+                            announcement.set(i18n.t("Cancelled.").to_string());
+                        }
+                    },
+                    for week in days.chunks(7).map(|w| w.to_vec()) {
+                        div { key: "{week[0]}", class: "grid grid-cols-7 min-h-0", role: "row",
+                            for day in week {
+                                {
+                                    let index = day.signed_duration_since(grid_start).num_days() as usize;
+                                    let tasks = by_day.get(&day).cloned().unwrap_or_default();
+                                    let hidden = tasks.len().saturating_sub(MAX_CHIPS);
+                                    let in_month = day.month() == first.month();
+                                    let is_cursor = grid_focused() && index == cursor;
+                                    let cell_class = match (in_month, is_cursor) {
+                                        (_, true) => "ring-2 ring-inset ring-neon-cyan bg-neon-cyan/10",
+                                        (true, false) => "",
+                                        (false, false) => "bg-space-800/30 opacity-60",
+                                    };
+                                    let label = format!("{} {}, {}", i18n.weekday(day.weekday()), i18n.date(day), i18n.tf("{count} tasks", &[("count", &tasks.len())]));
+                                    rsx! {
+                                        div {
+                                            key: "{day}",
+                                            id: "month-day-{day}",
+                                            role: "gridcell",
+                                            aria_label: "{label}",
+                                            aria_selected: index == cursor,
+                                            class: "border-r border-b border-space-700/30 p-1.5 flex flex-col gap-1 min-h-0 overflow-hidden hover:bg-white/[0.02] transition-colors {cell_class}",
+                                            ondragover: move |e| e.prevent_default(),
+                                            ondrop: move |_| {
+                                                if let Some(id) = dragged_task_id() {
+                                                    drop_on(id, day);
+                                                    dragged_task_id.set(None);
+                                                }
+                                            },
+                                            // Day number opens the day view
+                                            button {
+                                                class: if day == today { "self-end text-xs font-bold w-6 h-6 rounded-full bg-neon-cyan text-space-900" } else { "self-end text-xs font-medium w-6 h-6 rounded-full text-gray-400 hover:text-white hover:bg-space-700" },
+                                                tabindex: -1,
+                                                onclick: move |_| {
+                                                    navigator.push(Route::CalendarDay { date: day });
+                                                },
+                                                "{day.day()}"
+                                            }
+                                            for task in tasks.into_iter().take(MAX_CHIPS) {
+                                                MonthChip { key: "{task.id}", task, dragged_task_id, menu_task, announcement }
+                                            }
+                                            if hidden > 0 {
+                                                button {
+                                                    class: "text-[10px] font-mono text-gray-500 hover:text-neon-cyan text-left px-1",
+                                                    tabindex: -1,
+                                                    onclick: move |_| {
+                                                        navigator.push(Route::CalendarDay { date: day });
+                                                    },
+                                                    {i18n.tf("+{count} more", &[("count", &hidden)])}
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// MONTH CHIP: One scheduled task inside a day cell (time + title).
+/// Drag it to another day, double-click (or F2) to rename, × to unschedule.
+#[component]
+fn MonthChip(
+    task: Task,
+    dragged_task_id: Signal<Option<uuid::Uuid>>,
+    menu_task: Signal<Option<uuid::Uuid>>,
+    announcement: Signal<String>,
+) -> Element {
+    let app_state = use_context::<AppState>();
+    let i18n = use_context::<SettingsState>().i18n();
+    let mut editing = use_signal(|| false);
+    let task_id = task.id;
+    let (chip_bg, chip_border) = quadrant_block_colors(&task.quadrant);
+    let chip_done = if task.status == TaskStatus::Done { "opacity-50 line-through" } else { "" };
+    let time = task.scheduled_start.map(|s| i18n.time(&s)).unwrap_or_default();
+
+    rsx! {
+        div {
+            id: "task-{task_id}",
+            role: "button",
+            tabindex: 0,
+            aria_label: "{task.title}, {time}",
+            aria_haspopup: "menu",
+            class: "group/chip relative flex items-center gap-1 rounded border-l-2 px-1.5 py-0.5 text-[11px] cursor-grab active:cursor-grabbing focus:outline-none focus-visible:ring-2 focus-visible:ring-neon-cyan {chip_bg} {chip_border} {chip_done}",
+            draggable: if editing() { "false" } else { "true" },
+            ondragstart: move |_| dragged_task_id.set(Some(task_id)),
+            ondoubleclick: move |e| {
+                e.stop_propagation();
+                editing.set(true);
+            },
+            oncontextmenu: move |evt| {
+                evt.prevent_default();
+                menu_task.set(Some(task_id));
+            },
+            onkeydown: {
+                let title = task.title.clone();
+                move |evt: KeyboardEvent| {
+                    if opens_task_menu(&evt) {
+                        evt.prevent_default();
+                        menu_task.set(Some(task_id));
+                    } else if evt.key() == Key::F2 {
+                        editing.set(true);
+                    } else if is_activation_key(&evt) {
+                        evt.prevent_default();
+                        evt.stop_propagation();
+                        pick_up(dragged_task_id, announcement, task_id, i18n.tf(PICKED_UP, &[("title", &title)]));
+                    }
+                }
+            },
+            span { class: "font-mono text-white/60 shrink-0", "{time}" }
+            if editing() {
+                InlineTitleInput {
+                    task_id,
+                    title: task.title.clone(),
+                    class: "flex-1 min-w-0 bg-black/40 text-white px-1 rounded border border-white/30 focus:outline-none focus:border-neon-cyan",
+                    onclose: move |_| editing.set(false),
+                }
+            } else {
+                span { class: "flex-1 min-w-0 truncate text-white font-medium", title: "{task.title}", "{task.title}" }
+            }
+            button {
+                class: "shrink-0 w-4 h-4 rounded-full text-white/50 hover:text-white hover:bg-black/40 opacity-0 group-hover/chip:opacity-100 transition-opacity leading-none",
+                aria_label: i18n.t("Unschedule"),
+                tabindex: -1,
+                onclick: move |e| {
+                    e.stop_propagation();
+                    app_state.update_task_schedule(task_id, None, None);
+                },
+                "×"
+            }
+        }
+    }
+}
+
+/// The first of the month `delta` months away
+fn shift_month(first: NaiveDate, delta: i32) -> NaiveDate {
+    let months = first.year() * 12 + first.month0() as i32 + delta;
+    NaiveDate::from_ymd_opt(months.div_euclid(12), months.rem_euclid(12) as u32 + 1, 1).unwrap_or(first)
+}
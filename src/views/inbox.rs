@@ -1,4 +1,10 @@
 use dioxus::prelude::*;
+use std::collections::HashSet;
+use crate::capture;
+use crate::components::{heat_class, use_card_registration, AgeBadge, IcsImportDialog, LassoArea, ScoreAssistant, Selection};
+use crate::ics::{self, TaskItem};
+use crate::models::{Quadrant, Task, TaskStatus};
+use crate::settings::SettingsState;
 use crate::store::AppState;
 
 /// INBOX VIEW: The "Brainstorm" where users capture new tasks before sorting them
@@ -7,84 +13,307 @@ pub fn Inbox() -> Element {
     // Access the global state (The Brain) and set up local state for the input field
     let app_state = use_context::<AppState>();
     let mut input_val = use_signal(|| "".to_string());
+    // Pasted items whose default (include unless already ticked) the user has flipped
+    let mut toggled = use_signal(HashSet::<usize>::new);
+    // The task currently open in the urgency/importance questionnaire
+    let mut assessing = use_signal(|| None::<Task>);
+    // Calendar items read from an .ics file and waiting in the import preview, with the file's name
+    let mut importing = use_signal(|| None::<(String, Vec<TaskItem>)>);
+    let mut import_error = use_signal(|| None::<String>);
+
+    let selection = use_context::<Selection>();
+    let settings_state = use_context::<SettingsState>();
+    let i18n = settings_state.i18n();
+
+    // Start each visit with a clean selection
+    use_effect(move || selection.clear());
+
+    // The "Stale" smart filter: open tasks anywhere that nobody has touched for N days
+    let mut show_stale = use_signal(|| false);
+    let stale_after = settings_state.settings.read().stale_after_days;
+
+    // The backlog: unsorted tasks that are still open, in capture order
+    let unsorted: Vec<Task> = app_state.tasks.read()
+        .iter()
+        .filter(|t| t.quadrant == Quadrant::Unsorted && t.status == TaskStatus::Todo)
+        .cloned()
+        .collect();
+    let mut stale: Vec<Task> = app_state.tasks.read()
+        .iter()
+        .filter(|t| t.status == TaskStatus::Todo && t.idle_days() >= stale_after)
+        .cloned()
+        .collect();
+    stale.sort_by_key(|t| t.last_touched());
+    let unsorted_count = unsorted.len();
+    let stale_count = stale.len();
+    let listed = if show_stale() { stale } else { unsorted };
+    let order: Vec<uuid::Uuid> = listed.iter().map(|t| t.id).collect();
+
+    // Detect a multi-line paste and preview how it would be split
+    let is_bulk = capture::is_multiline(&input_val.read());
+    let items = if is_bulk { capture::parse_capture(&input_val.read()) } else { Vec::new() };
+    let included_count = items
+        .iter()
+        .enumerate()
+        .filter(|(i, item)| item.checked == toggled.read().contains(i))
+        .count();
+
+    // Saves the whole input as one task: first line is the title, the rest its description
+    let mut capture_single = move || {
+        let text = input_val.read().trim().to_string();
+        if text.is_empty() {
+            return;
+        }
+        let mut lines = text.lines();
+        let title = lines.next().unwrap_or_default().trim().to_string();
+        let rest = lines.collect::<Vec<_>>().join("\n").trim().to_string();
+        if rest.is_empty() {
+            app_state.add_task(title);
+        } else {
+            let mut task = Task::new(title);
+            task.description = Some(rest);
+            app_state.add_tasks(vec![task]);
+        }
+        input_val.set("".to_string());
+        toggled.write().clear();
+    };
+
+    // Reads the events and to-dos of an .ics file into the import preview
+    let import_ics = move |_| {
+        spawn(async move {
+            let Some(handle) = rfd::AsyncFileDialog::new()
+                .set_title(i18n.t("Import tasks"))
+                .add_filter("iCalendar", &["ics"])
+                .pick_file()
+                .await
+            else {
+                return;
+            };
+            let file = handle.file_name();
+            match ics::read_items(&String::from_utf8_lossy(&handle.read().await)) {
+                Ok(items) => {
+                    import_error.set(None);
+                    importing.set(Some((file, items)));
+                }
+                Err(e) => import_error.set(Some(i18n.tf("Could not read {file}: {error}", &[("file", &file), ("error", &e)]))),
+            }
+        });
+    };
 
     rsx! {
         div { class: "flex-1 h-full p-8 overflow-y-auto",
             div { class: "w-full max-w-6xl mx-auto space-y-8",
                 // PAGE HEADER: Displays the mission title
                 div { class: "border-b border-gray-800 pb-6",
-                    h1 { class: "text-4xl font-bold mb-2 text-white font-sans tracking-tight", "MISSION CONTROL" }
-                    h2 { class: "text-xl font-mono text-neon-cyan/80 tracking-widest", "BRAIN DUMP // CAPTURE" }
+                    h1 { class: "text-4xl font-bold mb-2 text-white font-sans tracking-tight", "{i18n.t(\"MISSION CONTROL\")}" }
+                    h2 { class: "text-xl font-mono text-neon-cyan/80 tracking-widest", "{i18n.t(\"BRAIN DUMP // CAPTURE\")}" }
                 }
                 
-                // DATA CAPTURE AREA: Where users type in new tasks
+                // DATA CAPTURE AREA: Where users type (or paste) new tasks
                 div { class: "flex gap-4 items-end",
                     div { class: "flex-1 relative group",
                         // A subtle glowing effect when hovering over the input
                         div { class: "absolute -inset-0.5 bg-gradient-to-r from-neon-cyan/50 to-purple-600/50 rounded-lg blur opacity-0 group-hover:opacity-100 transition duration-500" }
-                        input {
-                            class: "relative w-full bg-space-900 border border-space-700 rounded-lg p-3 text-lg text-white placeholder-gray-600 focus:border-neon-cyan focus:outline-none focus:ring-1 focus:ring-neon-cyan/50 transition-all font-sans",
-                            placeholder: "What's on your mind?",
+                        // A textarea so pasted lists keep their line breaks
+                        textarea {
+                            id: "capture-input",
+                            class: "relative block w-full bg-space-900 border border-space-700 rounded-lg p-3 text-lg text-white placeholder-gray-600 focus:border-neon-cyan focus:outline-none focus:ring-1 focus:ring-neon-cyan/50 transition-all font-sans resize-none",
+                            placeholder: i18n.t("What's on your mind?"),
+                            rows: if is_bulk { "6" } else { "1" },
                             value: "{input_val}",
-                            // Update the local state as the user types
-                            oninput: move |evt| input_val.set(evt.value()),
-                            // Handle the 'Enter' key to quickly save tasks
+                            // Update the local state as the user types (and forget old split choices)
+                            oninput: move |evt| {
+                                input_val.set(evt.value());
+                                toggled.write().clear();
+                            },
+                            // Handle the 'Enter' key to quickly save tasks (Shift+Enter adds a line)
                             onkeydown: move |evt| {
-                                if evt.key() == Key::Enter && !input_val.read().trim().is_empty() {
-                                    app_state.add_task(input_val.read().clone());
-                                    input_val.set("".to_string());
+                                if evt.key() == Key::Enter && !evt.modifiers().shift() {
+                                    evt.prevent_default();
+                                    // Pasted lists wait for the user to pick an option below
+                                    if !capture::is_multiline(&input_val.read()) {
+                                        capture_single();
+                                    }
                                 }
                             }
                         }
                     }
-                    button {
-                        class: "btn-primary h-[54px] flex items-center justify-center whitespace-nowrap",
-                        onclick: move |_| {
-                            if !input_val.read().trim().is_empty() {
-                                app_state.add_task(input_val.read().clone());
-                                input_val.set("".to_string());
+                    if !is_bulk {
+                        button {
+                            class: "btn-primary h-[54px] flex items-center justify-center whitespace-nowrap",
+                            onclick: move |_| capture_single(),
+                            "{i18n.t(\"Capture Task\")}"
+                        }
+                        button {
+                            class: "h-[54px] px-4 rounded-lg border border-space-700 text-gray-400 hover:text-white hover:bg-space-700/40 transition-all text-sm font-bold uppercase tracking-wide whitespace-nowrap",
+                            title: i18n.t("Import events and to-dos from an .ics file"),
+                            onclick: import_ics,
+                            "{i18n.t(\"Import .ics\")}"
+                        }
+                    }
+                }
+                if let Some(error) = import_error() {
+                    p { class: "text-xs font-mono text-red-400", role: "alert", "{error}" }
+                }
+
+                // ICS IMPORT PREVIEW: Pick what becomes a task and where it goes
+                if let Some((file, items)) = importing() {
+                    IcsImportDialog { file, items, onclose: move |_| importing.set(None) }
+                }
+
+                // BULK PASTE PREVIEW: Offered when the input holds several lines
+                if is_bulk {
+                    div { class: "glass-panel rounded-xl p-6 space-y-4",
+                        div { class: "flex justify-between items-center",
+                            div {
+                                h3 { class: "text-lg font-bold text-white tracking-tight", "{i18n.t(\"Split into separate tasks?\")}" }
+                                p { class: "hud-text", {i18n.tf("{included} OF {total} ITEMS SELECTED", &[("included", &included_count), ("total", &items.len())])} }
+                            }
+                            div { class: "flex gap-3",
+                                button {
+                                    class: "px-4 py-2 rounded border border-space-700 text-gray-400 hover:text-white hover:bg-space-700/40 transition-all text-sm font-bold uppercase tracking-wide",
+                                    onclick: move |_| capture_single(),
+                                    "{i18n.t(\"Keep as one\")}"
+                                }
+                                button {
+                                    class: "btn-primary",
+                                    disabled: included_count == 0,
+                                    onclick: move |_| {
+                                        let items = capture::parse_capture(&input_val.read());
+                                        let new_tasks: Vec<Task> = items
+                                            .into_iter()
+                                            .enumerate()
+                                            .filter(|(i, item)| item.checked == toggled.read().contains(i))
+                                            .map(|(_, item)| {
+                                                let mut task = Task::new(item.title.clone());
+                                                task.description = item.description();
+                                                task
+                                            })
+                                            .collect();
+                                        // One batched write for the whole list
+                                        app_state.add_tasks(new_tasks);
+                                        input_val.set("".to_string());
+                                        toggled.write().clear();
+                                    },
+                                    {i18n.tf("Split into {count} tasks", &[("count", &included_count)])}
+                                }
                             }
-                        },
-                        "Capture Task"
+                        }
+                        ul { class: "space-y-2",
+                            for (i, item) in items.iter().cloned().enumerate() {
+                                {
+                                    // Ticked checkboxes start out excluded; clicking flips the choice
+                                    let included = item.checked == toggled.read().contains(&i);
+                                    rsx! {
+                                        li {
+                                            key: "{i}",
+                                            class: if included { "flex gap-3 items-start p-3 rounded-lg bg-space-900/60 border border-space-700 cursor-pointer" } else { "flex gap-3 items-start p-3 rounded-lg border border-dashed border-space-700 opacity-40 cursor-pointer" },
+                                            onclick: move |_| {
+                                                let mut toggled = toggled.write();
+                                                if !toggled.remove(&i) {
+                                                    toggled.insert(i);
+                                                }
+                                            },
+                                            input { r#type: "checkbox", class: "mt-1 accent-neon-cyan pointer-events-none", checked: included }
+                                            div { class: "flex-1 min-w-0",
+                                                p { class: "text-gray-200 font-medium", "{item.title}" }
+                                                for note in item.notes.iter() {
+                                                    p { class: "text-xs text-gray-500 font-mono pl-3 border-l border-space-700 mt-1", "{note}" }
+                                                }
+                                            }
+                                            if item.checked {
+                                                span { class: "text-[10px] font-mono text-gray-500 uppercase tracking-widest", "{i18n.t(\"Already done\")}" }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                // PRIORITY QUESTIONNAIRE: Optional help deciding where a task belongs
+                if let Some(task) = assessing() {
+                    ScoreAssistant { task, onclose: move |_| assessing.set(None) }
+                }
+
+                // LIST FILTER: The unsorted backlog, or every task that has gone stale
+                div { class: "flex gap-2",
+                    for (label, count, stale_tab) in [(i18n.t("Unsorted"), unsorted_count, false), (i18n.t("Stale"), stale_count, true)] {
+                        button {
+                            class: if show_stale() == stale_tab { "text-xs font-mono font-bold tracking-widest uppercase px-3 py-1.5 rounded border border-neon-cyan text-neon-cyan bg-neon-cyan/10" } else { "text-xs font-mono font-bold tracking-widest uppercase px-3 py-1.5 rounded border border-space-700 text-gray-500 hover:text-white transition-colors" },
+                            onclick: move |_| {
+                                show_stale.set(stale_tab);
+                                selection.clear();
+                            },
+                            "{label} ({count})"
+                        }
+                    }
+                }
+
+                // STALE CLEANUP: Offer to archive or delete everything past the threshold
+                if show_stale() {
+                    div { class: "glass-panel rounded-xl p-4 flex items-center justify-between gap-4 text-sm",
+                        div { class: "flex items-center gap-2 text-gray-400",
+                            span { "{i18n.t(\"Untouched for\")}" }
+                            input {
+                                r#type: "number",
+                                min: "1",
+                                class: "w-16 bg-space-900 border border-space-700 rounded px-2 py-1 text-white font-mono focus:border-neon-cyan focus:outline-none",
+                                value: "{stale_after}",
+                                onchange: move |evt| {
+                                    if let Ok(days) = evt.value().trim().parse::<i64>() {
+                                        settings_state.set_stale_after_days(days);
+                                    }
+                                },
+                            }
+                            span { "{i18n.t(\"days or more\")}" }
+                        }
+                        if !order.is_empty() {
+                            div { class: "flex gap-3",
+                                button {
+                                    class: "px-4 py-2 rounded border border-space-700 text-gray-300 hover:text-white hover:bg-space-700/40 transition-all text-xs font-bold uppercase tracking-wide",
+                                    onclick: {
+                                        let ids: HashSet<uuid::Uuid> = order.iter().copied().collect();
+                                        move |_| app_state.archive_tasks(&ids)
+                                    },
+                                    {i18n.tf("Archive {count}", &[("count", &order.len())])}
+                                }
+                                button {
+                                    class: "px-4 py-2 rounded border border-red-500/50 text-red-400 hover:bg-red-500/10 transition-all text-xs font-bold uppercase tracking-wide",
+                                    onclick: {
+                                        let ids: HashSet<uuid::Uuid> = order.iter().copied().collect();
+                                        move |_| app_state.delete_tasks(&ids)
+                                    },
+                                    {i18n.tf("Delete {count}", &[("count", &order.len())])}
+                                }
+                            }
+                        }
                     }
                 }
 
                 // THE INBOX LIST: Shows all tasks that haven't been sorted into the matrix yet
-                if !app_state.tasks.read().iter().any(|t| matches!(t.quadrant, crate::models::Quadrant::Unsorted)) {
+                if listed.is_empty() {
                     // Displayed when the system is clear
                     div { class: "text-center py-20 text-gray-600 font-mono border border-dashed border-gray-800 rounded-xl bg-space-800/20",
-                        "// SYSTEM CLEAR - NO PENDING TASKS"
+                        if show_stale() { {i18n.t("// ALL FRESH - NOTHING STALE")} } else { {i18n.t("// SYSTEM CLEAR - NO PENDING TASKS")} }
                     }
                 } else {
-                    div { class: "glass-panel rounded-xl overflow-hidden",
+                    // Drag across the table (outside a row) to lasso several rows
+                    LassoArea { class: "glass-panel rounded-xl overflow-hidden select-none",
                         table { class: "data-table",
                             thead {
                                 tr {
-                                    th { "Task Description" }
-                                    th { class: "w-32 text-right", "Status" }
-                                    th { class: "w-32 text-right", "Actions" }
+                                    th { "{i18n.t(\"Task Description\")}" }
+                                    th { class: "w-32 text-right", "{i18n.t(\"Age\")}" }
+                                    th { class: "w-32 text-right", "{i18n.t(\"Status\")}" }
+                                    th { class: "w-44 text-right", "{i18n.t(\"Actions\")}" }
                                 }
                             }
                             tbody {
-                                // Filter for unsorted tasks and render a row for each
-                                for task in app_state.tasks.read().iter().filter(|t| matches!(t.quadrant, crate::models::Quadrant::Unsorted)).cloned() {
-                                    tr {
-                                        key: "{task.id}",
-                                        td { 
-                                            class: "text-lg font-medium",
-                                            span { class: "text-neon-cyan mr-3 font-mono", "::" }
-                                            "{task.title}" 
-                                        }
-                                        td { class: "text-right font-mono text-xs text-gray-500", "UNSORTED" }
-                                        td { class: "text-right",
-                                            button { class: "text-xs hover:text-red-500 text-gray-600 transition-colors uppercase tracking-wider font-bold",
-                                                onclick: move |_| {
-                                                    app_state.delete_task(task.id);
-                                                },
-                                                "Delete"
-                                            }
-                                        }
-                                    }
+                                // Render a row for each listed task; click, Ctrl/Cmd-click or Shift-click to select
+                                for task in listed {
+                                    InboxRow { key: "{task.id}", task, order: order.clone(), assessing }
                                 }
                             }
                         }
@@ -94,3 +323,66 @@ pub fn Inbox() -> Element {
         }
     }
 }
+
+/// INBOX ROW: One backlog task in the table; click, Ctrl/Cmd-click or Shift-click to select.
+/// Its own component so the row can leave the lasso when it goes away.
+#[component]
+fn InboxRow(task: Task, order: Vec<uuid::Uuid>, assessing: Signal<Option<Task>>) -> Element {
+    let app_state = use_context::<AppState>();
+    let selection = use_context::<Selection>();
+    let i18n = use_context::<SettingsState>().i18n();
+    let mounted = use_card_registration(task.id);
+
+    rsx! {
+        tr {
+            class: if selection.contains(task.id) { "bg-neon-cyan/10 cursor-pointer {heat_class(task.idle_days())}" } else { "cursor-pointer {heat_class(task.idle_days())} {selection.reveal_class(task.id)}" },
+            // Let the lasso measure this row (and forget it once the row is gone)
+            onmounted: move |evt| mounted.register(evt.data()),
+            onmousedown: move |evt| evt.stop_propagation(),
+            onclick: {
+                let order = order.clone();
+                move |evt: MouseEvent| selection.click(task.id, evt.modifiers(), &order)
+            },
+            td { 
+                class: "text-lg font-medium",
+                span { class: "text-neon-cyan mr-3 font-mono", "::" }
+                "{task.title}" 
+                for tag in task.tags.iter() {
+                    span { class: "ml-2 text-[10px] font-mono px-1.5 py-0.5 rounded bg-space-800 text-gray-400 align-middle", "#{tag}" }
+                }
+            }
+            td { class: "text-right",
+                AgeBadge { task: task.clone() }
+            }
+            td { class: "text-right font-mono text-xs text-gray-500",
+                // Once scored, show where the questionnaire points
+                if task.quadrant != Quadrant::Unsorted {
+                    "{i18n.t(task.quadrant.label()).to_uppercase()}"
+                } else if let Some(suggested) = task.suggested_quadrant() {
+                    span { class: "text-neon-cyan", "→ {i18n.t(suggested.label()).to_uppercase()}" }
+                } else {
+                    "{i18n.t(\"Unsorted\").to_uppercase()}"
+                }
+            }
+            td { class: "text-right space-x-4",
+                button { class: "text-xs hover:text-neon-cyan text-gray-600 transition-colors uppercase tracking-wider font-bold",
+                    onclick: {
+                        let task = task.clone();
+                        move |evt: MouseEvent| {
+                            evt.stop_propagation();
+                            assessing.set(Some(task.clone()));
+                        }
+                    },
+                    "{i18n.t(\"Assess\")}"
+                }
+                button { class: "text-xs hover:text-red-500 text-gray-600 transition-colors uppercase tracking-wider font-bold",
+                    onclick: move |evt| {
+                        evt.stop_propagation();
+                        app_state.delete_task(task.id);
+                    },
+                    "{i18n.t(\"Delete\")}"
+                }
+            }
+        }
+    }
+}
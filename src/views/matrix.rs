@@ -1,22 +1,39 @@
 use dioxus::prelude::*;
-use crate::models::Quadrant;
+use dioxus::html::geometry::{ClientPoint, PixelsRect};
+use crate::components::{LassoArea, Selection, TaskCard};
+use crate::models::{Quadrant, TaskStatus};
+use crate::routes::Route;
+use crate::settings::SettingsState;
 use crate::store::AppState;
+use std::collections::HashSet;
 
 /// THE MATRIX DASHBOARD: Shows the four-quadrant Eisenhower Matrix for task prioritization
 #[component]
 pub fn Matrix() -> Element {
     // Stage 1: Connect to global app data and prepare a signal to track which task is being dragged
-    let _app_state = use_context::<AppState>();
+    let selection = use_context::<Selection>();
     let dragged_id = use_signal(|| None::<uuid::Uuid>);
+    let mut plot_mode = use_signal(|| false);
+    let i18n = use_context::<SettingsState>().i18n();
+
+    // Start each visit with a clean selection
+    use_effect(move || selection.clear());
 
     rsx! {
-        div { class: "flex h-full",
+        // Dragging across empty space lassos cards in every quadrant
+        LassoArea { class: "flex h-full select-none",
             // THE BACKLOG SIDEBAR: Contains all "Unsorted" tasks waiting to be placed in the matrix
             div { class: "w-80 bg-space-900 border-r border-space-700 p-8 flex flex-col h-full",
                 div { class: "mb-6 pb-6 border-b border-space-800 flex justify-between items-center group",
                     div {
-                        h2 { class: "text-lg font-bold text-white tracking-tight", "Backlog" }
-                        p { class: "text-xs font-mono text-neon-cyan/80 tracking-widest", "UNSORTED MINDS" }
+                        h2 { class: "text-lg font-bold text-white tracking-tight", "{i18n.t(\"Backlog\")}" }
+                        p { class: "text-xs font-mono text-neon-cyan/80 tracking-widest", "{i18n.t(\"UNSORTED MINDS\")}" }
+                    }
+                    // Jump into keyboard triage for the whole backlog
+                    Link {
+                        to: Route::Triage {},
+                        class: "text-[10px] font-mono font-bold tracking-widest uppercase px-3 py-2 rounded border border-neon-cyan/50 text-neon-cyan hover:bg-neon-cyan/10 transition-colors",
+                        "{i18n.t(\"Triage\")}"
                     }
                 }
                 
@@ -32,39 +49,58 @@ pub fn Matrix() -> Element {
                 }
             }
 
-            // THE MATRIX GRID: A 2x2 grid representing the core prioritization logic
-            div { class: "flex-1 py-10 px-8 grid grid-cols-2 grid-rows-2 gap-3",
-                // Top-Left: Do First (Urgent & Important)
-                QuadrantBox { 
-                    title: "DO FIRST", 
-                    subtitle: "URGENT & IMPORTANT", 
-                    quadrant: Quadrant::DoFirst, 
-                    color: "border-neon-pink text-neon-pink", 
-                    dragged_id: dragged_id,
-                }
-                // Top-Right: Schedule (Important, Not Urgent)
-                QuadrantBox { 
-                    title: "SCHEDULE", 
-                    subtitle: "IMPORTANT, NOT URGENT", 
-                    quadrant: Quadrant::Schedule, 
-                    color: "border-neon-cyan text-neon-cyan", 
-                    dragged_id: dragged_id,
-                }
-                // Bottom-Left: Delegate (Urgent, Not Important)
-                QuadrantBox { 
-                    title: "DELEGATE", 
-                    subtitle: "URGENT, NOT IMPORTANT",
-                    quadrant: Quadrant::Delegate, 
-                    color: "border-neon-amber text-neon-amber", 
-                    dragged_id: dragged_id,
+            div { class: "flex-1 flex flex-col min-w-0",
+                // VIEW SWITCHER: Classic 2x2 grid or continuous urgency/importance plot
+                div { class: "flex justify-end gap-2 px-8 pt-6",
+                    for (label, is_plot) in [("Grid", false), ("Plot", true)] {
+                        button {
+                            class: if plot_mode() == is_plot { "text-[10px] font-mono font-bold tracking-widest uppercase px-3 py-1.5 rounded border border-neon-cyan text-neon-cyan bg-neon-cyan/10" } else { "text-[10px] font-mono font-bold tracking-widest uppercase px-3 py-1.5 rounded border border-space-700 text-gray-500 hover:text-white transition-colors" },
+                            onclick: move |_| plot_mode.set(is_plot),
+                            "{i18n.t(label)}"
+                        }
+                    }
                 }
-                // Bottom-Right: Delete (Neither)
-                QuadrantBox { 
-                    title: "DELETE", 
-                    subtitle: "NEITHER",
-                    quadrant: Quadrant::Delete, 
-                    color: "border-space-700 text-gray-400", 
-                    dragged_id: dragged_id, 
+
+                if plot_mode() {
+                    MatrixPlot { dragged_id: dragged_id }
+                } else {
+                    // THE MATRIX GRID: A 2x2 grid representing the core prioritization logic
+                    div { class: "flex-1 pt-4 pb-10 px-8 grid grid-cols-2 grid-rows-2 gap-3",
+                        role: "group",
+                        aria_label: i18n.t("Eisenhower matrix"),
+                        // Top-Left: Do First (Urgent & Important)
+                        QuadrantBox { 
+                            title: "DO FIRST", 
+                            subtitle: "URGENT & IMPORTANT", 
+                            quadrant: Quadrant::DoFirst, 
+                            color: "border-quadrant-do-first text-quadrant-do-first", 
+                            dragged_id: dragged_id,
+                        }
+                        // Top-Right: Schedule (Important, Not Urgent)
+                        QuadrantBox { 
+                            title: "SCHEDULE", 
+                            subtitle: "IMPORTANT, NOT URGENT", 
+                            quadrant: Quadrant::Schedule, 
+                            color: "border-quadrant-schedule text-quadrant-schedule", 
+                            dragged_id: dragged_id,
+                        }
+                        // Bottom-Left: Delegate (Urgent, Not Important)
+                        QuadrantBox { 
+                            title: "DELEGATE", 
+                            subtitle: "URGENT, NOT IMPORTANT",
+                            quadrant: Quadrant::Delegate, 
+                            color: "border-quadrant-delegate text-quadrant-delegate", 
+                            dragged_id: dragged_id,
+                        }
+                        // Bottom-Right: Delete (Neither)
+                        QuadrantBox { 
+                            title: "DELETE", 
+                            subtitle: "NEITHER",
+                            quadrant: Quadrant::Delete, 
+                            color: "border-quadrant-delete text-quadrant-delete", 
+                            dragged_id: dragged_id, 
+                        }
+                    }
                 }
             }
         }
@@ -82,27 +118,49 @@ fn QuadrantBox(
     is_backlog: Option<bool>,
 ) -> Element {
     let app_state = use_context::<AppState>();
+    let selection = use_context::<Selection>();
+    let settings_state = use_context::<SettingsState>();
+    let i18n = settings_state.i18n();
     
     // Track if a task is currently being dragged over this specific box
     let is_drop_target = use_signal(|| false);
 
     let drop_target_class = if is_drop_target() { "bg-space-700/50" } else { "" };
     
-    // Get all tasks matching this quadrant
-    let tasks: Vec<crate::models::Task> = app_state.tasks.read().iter()
-        .filter(|t| t.quadrant == quadrant)
+    // Get all tasks matching this quadrant, highest urgency + importance first
+    let mut tasks: Vec<crate::models::Task> = app_state.tasks.read().iter()
+        .filter(|t| t.quadrant == quadrant && t.status == TaskStatus::Todo)
         .cloned()
         .collect();
+    tasks.sort_by(|a, b| b.priority_score().total_cmp(&a.priority_score()));
+    // Card order on screen, used for shift-click ranges
+    let order: Vec<uuid::Uuid> = tasks.iter().map(|t| t.id).collect();
+
+    // WIP limit for this quadrant and whether it is already exceeded
+    let mut editing_limit = use_signal(|| false);
+    let limit = settings_state.settings.read().wip_limits.get(&quadrant);
+    let open_count = tasks.len();
+    let over_limit = limit.is_some_and(|l| open_count > l);
+    let delete_growth = if quadrant == Quadrant::Delete { settings_state.delete_growth() } else { None };
+
+    // Read out by screen readers when focus enters the box
+    let region_label = if is_backlog.unwrap_or(false) {
+        i18n.t("Backlog").to_string()
+    } else {
+        i18n.tf("{quadrant}: {count} tasks", &[("quadrant", &i18n.t(quadrant.label())), ("count", &open_count)])
+    };
 
     let container_class = if is_backlog.unwrap_or(false) {
         format!("flex flex-col h-full transition-colors {}", drop_target_class)
     } else {
-        format!("bg-[#101423] rounded-2xl p-8 flex flex-col h-full border-2 transition-all duration-300 relative overflow-visible shadow-sm {} {}", color, drop_target_class)
+        format!("bg-space-800 rounded-2xl p-8 flex flex-col h-full border-2 transition-all duration-300 relative overflow-visible shadow-sm {} {}", color, drop_target_class)
     };
 
     rsx! {
         div {
             class: "{container_class}",
+            role: "region",
+            aria_label: "{region_label}",
             // Handle dragging over: and dropping into this box
             ondragover: move |evt| {
                 evt.prevent_default(); // Required to allow a drop
@@ -113,17 +171,27 @@ fn QuadrantBox(
                 let mut is_drop_target = is_drop_target;
                 is_drop_target.set(false);
             },
-            ondrop: move |_| {
-                let mut is_drop_target = is_drop_target;
-                is_drop_target.set(false);
-                let id = *dragged_id.read();
-                // Step 1: Detect which task was dropped
-                if let Some(id) = id {
-                    // Step 2: Update the task's location in global state
-                    app_state.update_task_quadrant(id, quadrant.clone());
-                    // Step 3: Reset the drag tracking signal
-                    let mut dragged_id = dragged_id;
-                    dragged_id.set(None);
+            ondrop: {
+                let quadrant = quadrant.clone();
+                move |_| {
+                    let mut is_drop_target = is_drop_target;
+                    is_drop_target.set(false);
+                    let id = *dragged_id.read();
+                    // Step 1: Detect which task was dropped
+                    if let Some(id) = id {
+                        // Step 2: Work out what moves (dragging a selected card carries the whole selection)
+                        let ids: HashSet<uuid::Uuid> = if selection.contains(id) {
+                            selection.snapshot()
+                        } else {
+                            HashSet::from([id])
+                        };
+                        selection.clear();
+                        // Step 3: Move them (the demote prompt steps in if the WIP limit would break)
+                        app_state.move_to_quadrant(&ids, quadrant.clone(), &settings_state.settings.peek().wip_limits);
+                        // Step 4: Reset the drag tracking signal
+                        let mut dragged_id = dragged_id;
+                        dragged_id.set(None);
+                    }
                 }
             },
             
@@ -131,47 +199,273 @@ fn QuadrantBox(
             if !is_backlog.unwrap_or(false) {
                 div { class: "flex justify-between items-start mb-6 border-b border-white pb-3 relative z-20",
                     div {
-                        h3 { class: "text-2xl font-bold tracking-widest leading-none mb-1 text-current", "{title}" }
+                        h3 { class: "text-2xl font-bold tracking-widest leading-none mb-1 text-current", "{i18n.t(title)}" }
                         if let Some(sub) = subtitle {
-                            p { class: "text-[10px] font-mono tracking-widest uppercase text-current", "{sub}" }
+                            p { class: "text-[10px] font-mono tracking-widest uppercase text-current", "{i18n.t(sub)}" }
+                        }
+                    }
+                    // WIP COUNTER: "open / limit", click to change the limit (empty = no limit)
+                    if editing_limit() {
+                        input {
+                            r#type: "number",
+                            min: "1",
+                            class: "w-16 bg-space-900 border border-current rounded px-2 py-1 text-sm font-mono text-current focus:outline-none",
+                            value: limit.map(|l| l.to_string()).unwrap_or_default(),
+                            autofocus: true,
+                            onmousedown: move |evt| evt.stop_propagation(),
+                            onchange: {
+                                let quadrant = quadrant.clone();
+                                move |evt: FormEvent| {
+                                    let new_limit = evt.value().trim().parse::<usize>().ok().filter(|l| *l > 0);
+                                    settings_state.set_wip_limit(&quadrant, new_limit);
+                                    editing_limit.set(false);
+                                }
+                            },
+                            onblur: move |_| editing_limit.set(false),
+                        }
+                    } else {
+                        button {
+                            class: if over_limit { "text-sm font-mono font-bold px-2 py-1 rounded bg-red-500/20 text-red-400 border border-red-500/60" } else { "text-sm font-mono font-bold px-2 py-1 rounded text-current opacity-70 hover:opacity-100 transition-opacity" },
+                            title: i18n.t("Click to set the WIP limit"),
+                            onmousedown: move |evt| evt.stop_propagation(),
+                            onclick: move |_| editing_limit.set(true),
+                            if let Some(limit) = limit { "{open_count}/{limit}" } else { "{open_count}" }
+                        }
+                    }
+                }
+
+                // PURGE NUDGE: The Delete sector has grown week after week without being emptied
+                if let Some(counts) = delete_growth.clone() {
+                    div { class: "mb-4 p-3 rounded-lg border border-neon-amber/50 bg-neon-amber/10 text-neon-amber text-xs flex items-center justify-between gap-3 relative z-20",
+                        onmousedown: move |evt| evt.stop_propagation(),
+                        span { class: "font-mono",
+                            {i18n.tf(
+                                "Growing {weeks} weeks running ({counts}). Time to purge?",
+                                &[("weeks", &counts.len()), ("counts", &counts.iter().map(|c| c.to_string()).collect::<Vec<_>>().join(" → "))],
+                            )}
+                        }
+                        div { class: "flex gap-2 shrink-0",
+                            button {
+                                class: "px-2 py-1 rounded border border-neon-amber/60 hover:bg-neon-amber/20 font-bold uppercase tracking-wider",
+                                onclick: move |_| {
+                                    let ids: HashSet<uuid::Uuid> = app_state.tasks.read()
+                                        .iter()
+                                        .filter(|t| t.quadrant == Quadrant::Delete && t.status == TaskStatus::Todo)
+                                        .map(|t| t.id)
+                                        .collect();
+                                    app_state.delete_tasks(&ids);
+                                },
+                                "{i18n.t(\"Purge all\")}"
+                            }
+                            button {
+                                class: "px-2 py-1 rounded text-gray-400 hover:text-white uppercase tracking-wider",
+                                onclick: move |_| settings_state.snooze_delete_nudge(),
+                                "{i18n.t(\"Later\")}"
+                            }
                         }
                     }
                 }
             }
             
             // List the tasks inside this section
+            // Cards are focusable; their menu (Menu key / Shift+F10) moves them without dragging
             div { class: "flex-1 overflow-y-auto space-y-4 min-h-0 z-10 pr-2 scrollbar-thin scrollbar-thumb-space-600 scrollbar-track-transparent hover:scrollbar-thumb-space-500",
+                role: "list",
                 if tasks.is_empty() {
                     div { class: "h-full flex items-center justify-center text-gray-400 text-[11px] font-mono tracking-widest",
-                        "Empty Sector"
+                        "{i18n.t(\"Empty Sector\")}"
                     }
                 }
                 for task in tasks {
-                    div {
-                        key: "{task.id.to_string()}",
-                        class: "bg-[#101423] border border-space-700 p-4 rounded-lg group cursor-move hover:border-current transition-all relative hover:translate-x-1",
-                        draggable: true,
-                        // Update the global 'dragging' signal when the user picks up this task
-                        ondragstart: move |_| {
-                            let mut dragged_id = dragged_id;
-                            dragged_id.set(Some(task.id));
-                        },
-                        
-                        div { class: "flex justify-between items-start gap-3",
-                            span { class: "text-sm font-medium text-current leading-relaxed", "{task.title}" }
-                            div { class: "opacity-0 group-hover:opacity-100 transition-opacity",
-                                button { 
-                                    class: "text-gray-500 hover:text-red-500 w-5 h-5 flex items-center justify-center rounded hover:bg-space-800 transition-colors",
-                                    onclick: move |_| {
-                                        app_state.delete_task(task.id);
+                    TaskCard { key: "{task.id}", task, order: order.clone(), dragged_id }
+                }
+            }
+        }
+    }
+}
+
+/// MATRIX PLOT: Every sorted task as a draggable dot on continuous urgency / importance axes.
+/// Urgent sits on the left and important at the top, matching the 2x2 grid layout.
+#[component]
+fn MatrixPlot(dragged_id: Signal<Option<uuid::Uuid>>) -> Element {
+    let app_state = use_context::<AppState>();
+    let settings_state = use_context::<SettingsState>();
+    let i18n = settings_state.i18n();
+
+    // The plot's on-screen rectangle, needed to turn mouse positions into scores
+    let mut plot_el = use_signal(|| None::<std::rc::Rc<MountedData>>);
+    let mut plot_rect = use_signal(|| None::<PixelsRect>);
+    // The dot being dragged and where it currently hovers (urgency, importance)
+    let mut moving_id = use_signal(|| None::<uuid::Uuid>);
+    let mut moving_pos = use_signal(|| None::<(f32, f32)>);
+
+    // Re-measure the plot (it resizes with the window)
+    let measure = move || {
+        if let Some(el) = plot_el() {
+            spawn(async move {
+                if let Ok(rect) = el.get_client_rect().await {
+                    plot_rect.set(Some(rect));
+                }
+            });
+        }
+    };
+
+    // Converts a mouse position into (urgency, importance) inside the plot
+    let to_scores = move |point: ClientPoint| -> Option<(f32, f32)> {
+        let rect = plot_rect()?;
+        let x = ((point.x - rect.origin.x) / rect.size.width).clamp(0.0, 1.0) as f32;
+        let y = ((point.y - rect.origin.y) / rect.size.height).clamp(0.0, 1.0) as f32;
+        Some((1.0 - x, 1.0 - y))
+    };
+
+    // Saves the new coordinates and moves the task if it crossed a midline (one undo step)
+    let place = move |id: uuid::Uuid, urgency: f32, importance: f32| {
+        app_state.place_task(id, urgency, importance, &settings_state.settings.peek().wip_limits);
+    };
+
+    let dots: Vec<(crate::models::Task, f32, f32)> = {
+        let tasks = app_state.tasks.read();
+        let mut unscored_seen = [0usize; 4];
+        tasks.iter()
+            .filter(|t| t.quadrant != Quadrant::Unsorted && t.status == TaskStatus::Todo)
+            .map(|t| {
+                let (u, i) = match (t.urgency, t.importance) {
+                    (Some(u), Some(i)) => (u, i),
+                    // Unscored tasks cluster around the centre of their quadrant
+                    _ => {
+                        let slot = quadrant_slot(&t.quadrant);
+                        let n = unscored_seen[slot];
+                        unscored_seen[slot] += 1;
+                        fallback_scores(&t.quadrant, n)
+                    }
+                };
+                (t.clone(), u, i)
+            })
+            .collect()
+    };
+
+    rsx! {
+        div { class: "flex-1 pt-4 pb-10 px-8 flex flex-col min-h-0",
+            div { class: "flex-1 flex gap-3 min-h-0",
+                // Y-AXIS LABEL
+                div { class: "flex items-center",
+                    span { class: "text-[10px] font-mono tracking-widest text-gray-500 -rotate-90 whitespace-nowrap", "{i18n.t(\"MORE IMPORTANT →\")}" }
+                }
+                div {
+                    class: if moving_id().is_some() { "flex-1 relative bg-space-800 rounded-2xl border-2 border-space-700 overflow-hidden cursor-grabbing select-none" } else { "flex-1 relative bg-space-800 rounded-2xl border-2 border-space-700 overflow-hidden select-none" },
+                    onmounted: move |evt| {
+                        plot_el.set(Some(evt.data()));
+                        measure();
+                    },
+                    onresize: move |_| measure(),
+                    // The plot has its own dragging, so keep the lasso out of it
+                    onmousedown: move |evt| evt.stop_propagation(),
+                    onmousemove: move |evt| {
+                        if moving_id().is_some() {
+                            moving_pos.set(to_scores(evt.client_coordinates()));
+                        }
+                    },
+                    onmouseup: move |evt| {
+                        if let Some(id) = moving_id() {
+                            if let Some((u, i)) = to_scores(evt.client_coordinates()) {
+                                place(id, u, i);
+                            }
+                        }
+                        moving_id.set(None);
+                        moving_pos.set(None);
+                    },
+                    onmouseleave: move |_| {
+                        moving_id.set(None);
+                        moving_pos.set(None);
+                    },
+                    // Backlog cards can be dropped straight onto a spot in the plot
+                    ondragover: move |evt| evt.prevent_default(),
+                    ondrop: move |evt| {
+                        let mut dragged_id = dragged_id;
+                        if let Some(id) = dragged_id() {
+                            if let Some((u, i)) = to_scores(evt.client_coordinates()) {
+                                place(id, u, i);
+                            }
+                            dragged_id.set(None);
+                        }
+                    },
+
+                    // Midlines and sector labels
+                    div { class: "absolute inset-y-0 left-1/2 border-l border-dashed border-space-700 pointer-events-none" }
+                    div { class: "absolute inset-x-0 top-1/2 border-t border-dashed border-space-700 pointer-events-none" }
+                    span { class: "absolute top-3 left-4 text-xs font-bold tracking-widest text-quadrant-do-first/60 pointer-events-none", "{i18n.t(\"DO FIRST\")}" }
+                    span { class: "absolute top-3 right-4 text-xs font-bold tracking-widest text-quadrant-schedule/60 pointer-events-none", "{i18n.t(\"SCHEDULE\")}" }
+                    span { class: "absolute bottom-3 left-4 text-xs font-bold tracking-widest text-quadrant-delegate/60 pointer-events-none", "{i18n.t(\"DELEGATE\")}" }
+                    span { class: "absolute bottom-3 right-4 text-xs font-bold tracking-widest text-gray-500 pointer-events-none", "{i18n.t(\"DELETE\")}" }
+
+                    for (task, u, i) in dots {
+                        {
+                            let task_id = task.id;
+                            let is_moving = moving_id() == Some(task_id);
+                            // While dragging, the dot follows the mouse
+                            let (u, i) = if is_moving { moving_pos().unwrap_or((u, i)) } else { (u, i) };
+                            let left = (1.0 - u) * 100.0;
+                            let top = (1.0 - i) * 100.0;
+                            let color = quadrant_dot_color(&Quadrant::from_scores(u, i));
+                            let fill = if task.urgency.is_some() { "bg-current" } else { "bg-transparent" };
+                            let layer = if is_moving { "z-30 pointer-events-none" } else { "z-10" };
+                            rsx! {
+                                div {
+                                    key: "{task_id}",
+                                    class: "absolute -translate-x-1/2 -translate-y-1/2 flex items-center gap-2 group cursor-grab {color} {layer}",
+                                    style: "left: {left}%; top: {top}%;",
+                                    title: "{task.title}",
+                                    onmousedown: move |evt| {
+                                        evt.prevent_default();
+                                        measure();
+                                        moving_id.set(Some(task_id));
                                     },
-                                    "×" 
+                                    span { class: "w-3.5 h-3.5 rounded-full border-2 border-current shrink-0 {fill}" }
+                                    span { class: "text-[11px] text-gray-300 group-hover:text-white max-w-[160px] truncate", "{task.title}" }
                                 }
                             }
                         }
                     }
                 }
             }
+            // X-AXIS LABEL
+            div { class: "text-center pt-2",
+                span { class: "text-[10px] font-mono tracking-widest text-gray-500", "{i18n.t(\"← MORE URGENT\")}" }
+            }
         }
     }
 }
+
+/// Index of a quadrant in small per-quadrant arrays
+fn quadrant_slot(q: &Quadrant) -> usize {
+    match q {
+        Quadrant::DoFirst => 0,
+        Quadrant::Schedule => 1,
+        Quadrant::Delegate => 2,
+        Quadrant::Delete | Quadrant::Unsorted => 3,
+    }
+}
+
+/// Starting spot for an unscored task: a small grid around the centre of its quadrant
+fn fallback_scores(q: &Quadrant, n: usize) -> (f32, f32) {
+    let (u, i) = match q {
+        Quadrant::DoFirst => (0.75, 0.75),
+        Quadrant::Schedule => (0.25, 0.75),
+        Quadrant::Delegate => (0.75, 0.25),
+        Quadrant::Delete | Quadrant::Unsorted => (0.25, 0.25),
+    };
+    let dx = ((n % 3) as f32 - 1.0) * 0.08;
+    let dy = ((n / 3 % 3) as f32 - 1.0) * 0.08;
+    (u + dx, i + dy)
+}
+
+/// Text color for plot dots, matching the quadrant box borders
+fn quadrant_dot_color(q: &Quadrant) -> &'static str {
+    match q {
+        Quadrant::DoFirst => "text-quadrant-do-first",
+        Quadrant::Schedule => "text-quadrant-schedule",
+        Quadrant::Delegate => "text-quadrant-delegate",
+        Quadrant::Delete => "text-quadrant-delete",
+        Quadrant::Unsorted => "text-gray-400",
+    }
+}
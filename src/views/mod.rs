@@ -1,13 +1,25 @@
 /// VIEWS MODULE: This connects all our individual page files
 pub mod inbox;    // Brainstorm page
 pub mod matrix;   // Eisenhower Matrix page
-pub mod calendar; // Calendar page
+pub mod calendar; // Calendar page (week and day)
+pub mod calendar_month; // Calendar month grid
+pub mod agenda;   // Calendar agenda list
 pub mod credits;  // System Info page
 pub mod tutorial; // Flight Manual page
+pub mod triage;   // Keyboard sorting page
+pub mod archive;  // Finished-task history page
+pub mod perspective; // Saved filter page
+pub mod settings; // Preferences page
 
 // Re-export so they can be accessed directly as 'crate::views::Inbox', etc.
 pub use inbox::Inbox;
 pub use matrix::Matrix;
-pub use calendar::Calendar;
+pub use calendar::{Calendar, CalendarDay};
+pub use calendar_month::CalendarMonth;
+pub use agenda::CalendarAgenda;
 pub use credits::Credits;
 pub use tutorial::Tutorial;
+pub use triage::Triage;
+pub use archive::Archive;
+pub use perspective::Perspective;
+pub use settings::Settings;
@@ -0,0 +1,317 @@
+use dioxus::prelude::*;
+use chrono::{Local, NaiveDate};
+use crate::components::{LassoArea, Selection, TaskCard};
+use crate::filters::{DateField, DateWindow, SavedFilter};
+use crate::models::{Quadrant, Task, TaskStatus};
+use crate::routes::Route;
+use crate::settings::SettingsState;
+use crate::store::AppState;
+
+/// PERSPECTIVE VIEW: Lists every task matching one saved filter, with the filter's editor on top
+#[component]
+pub fn Perspective(id: uuid::Uuid) -> Element {
+    let app_state = use_context::<AppState>();
+    let settings_state = use_context::<SettingsState>();
+    let i18n = settings_state.i18n();
+    let selection = use_context::<Selection>();
+    let navigator = use_navigator();
+    let dragged_id = use_signal(|| None::<uuid::Uuid>);
+    let mut editing = use_signal(|| false);
+
+    // Start each visit with a clean selection
+    use_effect(move || selection.clear());
+
+    let Some(filter) = settings_state.settings.read().saved_filters.iter().find(|f| f.id == id).cloned() else {
+        return rsx! {
+            div { class: "flex flex-col items-center justify-center h-full text-center p-8 gap-6",
+                p { class: "text-gray-500 font-mono", "{i18n.t(\"// FILTER NOT FOUND\")}" }
+                Link { to: Route::Matrix {}, class: "btn-primary", "{i18n.t(\"Back to Matrix\")}" }
+            }
+        };
+    };
+
+    // Stage 1: Run the filter over the active tasks, highest priority first
+    let today = Local::now().date_naive();
+    let week_starts_on = settings_state.settings.read().calendar.week_starts_on;
+    let mut tasks: Vec<Task> = app_state.tasks.read()
+        .iter()
+        .filter(|t| filter.matches(t, today, week_starts_on))
+        .cloned()
+        .collect();
+    tasks.sort_by(|a, b| b.priority_score().total_cmp(&a.priority_score()));
+    let order: Vec<uuid::Uuid> = tasks.iter().map(|t| t.id).collect();
+
+    rsx! {
+        div { class: "flex-1 h-full p-8 overflow-y-auto",
+            div { class: "w-full max-w-4xl mx-auto space-y-8",
+                // PAGE HEADER: Filter name, match count and controls
+                div { class: "border-b border-gray-800 pb-6 flex justify-between items-end",
+                    div {
+                        h1 { class: "text-4xl font-bold mb-2 text-white font-sans tracking-tight", "{filter.name}" }
+                        h2 { class: "text-xl font-mono text-neon-cyan/80 tracking-widest", {i18n.tf("PERSPECTIVE // {count} TASKS", &[("count", &tasks.len())])} }
+                    }
+                    div { class: "flex gap-3",
+                        button {
+                            class: "px-4 py-2 rounded border border-space-700 text-gray-300 hover:text-white transition-colors text-xs font-bold uppercase tracking-wide",
+                            onclick: move |_| editing.toggle(),
+                            if editing() { "{i18n.t(\"Done\")}" } else { "{i18n.t(\"Edit Filter\")}" }
+                        }
+                        button {
+                            class: "px-4 py-2 rounded border border-red-500/50 text-red-400 hover:bg-red-500/10 transition-all text-xs font-bold uppercase tracking-wide",
+                            onclick: move |_| {
+                                settings_state.delete_filter(id);
+                                navigator.push(Route::Matrix {});
+                            },
+                            "{i18n.t(\"Delete\")}"
+                        }
+                    }
+                }
+
+                if editing() {
+                    FilterEditor { filter: filter.clone() }
+                }
+
+                // THE RESULTS: Same cards as the matrix quadrants, tinted with their quadrant color
+                if tasks.is_empty() {
+                    div { class: "text-center py-20 text-gray-600 font-mono border border-dashed border-gray-800 rounded-xl bg-space-800/20",
+                        "{i18n.t(\"// NO TASKS MATCH THIS FILTER\")}"
+                    }
+                } else {
+                    LassoArea { class: "select-none",
+                        div { class: "space-y-4", role: "list", aria_label: "{filter.name}",
+                            for task in tasks {
+                                div { key: "{task.id}", class: "{quadrant_text_color(&task.quadrant)}", role: "none",
+                                    TaskCard { task: task.clone(), order: order.clone(), dragged_id }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// FILTER EDITOR: Every criterion of a saved filter; each change is saved right away
+#[component]
+fn FilterEditor(filter: SavedFilter) -> Element {
+    let settings_state = use_context::<SettingsState>();
+    let i18n = settings_state.i18n();
+    let app_state = use_context::<AppState>();
+
+    // Applies one change to the filter and saves it
+    let update = {
+        let filter = filter.clone();
+        move |change: &dyn Fn(&mut SavedFilter)| {
+            let mut updated = filter.clone();
+            change(&mut updated);
+            settings_state.save_filter(updated);
+        }
+    };
+
+    // Known projects, offered as suggestions
+    let mut projects: Vec<String> = app_state.tasks.read().iter().filter_map(|t| t.project.clone()).collect();
+    projects.sort();
+    projects.dedup();
+
+    let quadrant_options = [
+        (Quadrant::DoFirst, "Do First"),
+        (Quadrant::Schedule, "Schedule"),
+        (Quadrant::Delegate, "Delegate"),
+        (Quadrant::Delete, "Delete"),
+        (Quadrant::Unsorted, "Inbox"),
+    ];
+    let status_value = match filter.status {
+        None => "any",
+        Some(TaskStatus::Todo) => "todo",
+        Some(TaskStatus::Done) => "done",
+    };
+    let window_value = match filter.window {
+        DateWindow::Any => "any",
+        DateWindow::Overdue => "overdue",
+        DateWindow::Today => "today",
+        DateWindow::ThisWeek => "week",
+        DateWindow::NextDays(_) => "next",
+        DateWindow::Between(..) => "custom",
+    };
+    let (range_from, range_to) = match filter.window {
+        DateWindow::Between(from, to) => (from, to),
+        _ => (None, None),
+    };
+    let date_input = |d: Option<NaiveDate>| d.map(|d| d.format("%Y-%m-%d").to_string()).unwrap_or_default();
+
+    let field_class = "bg-space-900 border border-space-700 rounded px-2 py-1 text-white focus:border-neon-cyan focus:outline-none";
+
+    rsx! {
+        div { class: "glass-panel rounded-xl p-6 grid grid-cols-[8rem_1fr] gap-x-6 gap-y-4 items-center text-sm",
+            span { class: "hud-text", "{i18n.t(\"NAME\")}" }
+            input { class: "{field_class}", value: "{filter.name}",
+                onchange: {
+                    let update = update.clone();
+                    move |evt: FormEvent| {
+                        let name = evt.value().trim().to_string();
+                        if !name.is_empty() {
+                            update(&|f| f.name = name.clone());
+                        }
+                    }
+                },
+            }
+
+            span { class: "hud-text", "{i18n.t(\"TEXT\")}" }
+            input { class: "{field_class}", placeholder: i18n.t("Words in the title or notes"), value: "{filter.text}",
+                onchange: {
+                    let update = update.clone();
+                    move |evt: FormEvent| update(&|f| f.text = evt.value())
+                },
+            }
+
+            span { class: "hud-text", "{i18n.t(\"QUADRANTS\")}" }
+            div { class: "flex flex-wrap gap-2",
+                for (quadrant, label) in quadrant_options {
+                    button {
+                        class: if filter.quadrants.contains(&quadrant) { "text-xs px-3 py-1 rounded border border-neon-cyan bg-neon-cyan/10 text-neon-cyan" } else { "text-xs px-3 py-1 rounded border border-space-700 text-gray-400 hover:text-white" },
+                        onclick: {
+                            let update = update.clone();
+                            move |_| update(&|f| {
+                                if f.quadrants.contains(&quadrant) {
+                                    f.quadrants.retain(|q| *q != quadrant);
+                                } else {
+                                    f.quadrants.push(quadrant.clone());
+                                }
+                            })
+                        },
+                        "{i18n.t(label)}"
+                    }
+                }
+                span { class: "text-[10px] font-mono text-gray-600 self-center", "{i18n.t(\"(none picked = all)\")}" }
+            }
+
+            span { class: "hud-text", "{i18n.t(\"STATUS\")}" }
+            select { class: "{field_class} w-40", value: "{status_value}",
+                onchange: {
+                    let update = update.clone();
+                    move |evt: FormEvent| update(&|f| f.status = match evt.value().as_str() {
+                        "todo" => Some(TaskStatus::Todo),
+                        "done" => Some(TaskStatus::Done),
+                        _ => None,
+                    })
+                },
+                option { value: "todo", "{i18n.t(\"Not done\")}" }
+                option { value: "done", "{i18n.t(\"Done\")}" }
+                option { value: "any", "{i18n.t(\"Any\")}" }
+            }
+
+            span { class: "hud-text", "{i18n.t(\"TAGS\")}" }
+            input { class: "{field_class}", placeholder: i18n.t("fundraising, hiring"), value: "{filter.tags.join(\", \")}",
+                onchange: {
+                    let update = update.clone();
+                    move |evt: FormEvent| update(&|f| {
+                        f.tags = evt.value()
+                            .split(',')
+                            .map(|t| t.trim().trim_start_matches('#').to_string())
+                            .filter(|t| !t.is_empty())
+                            .collect();
+                    })
+                },
+            }
+
+            span { class: "hud-text", "{i18n.t(\"PROJECT\")}" }
+            div {
+                input { class: "{field_class} w-64", list: "filter-projects", placeholder: i18n.t("Any project"),
+                    value: "{filter.project.clone().unwrap_or_default()}",
+                    onchange: {
+                        let update = update.clone();
+                        move |evt: FormEvent| update(&|f| {
+                            let project = evt.value().trim().to_string();
+                            f.project = if project.is_empty() { None } else { Some(project) };
+                        })
+                    },
+                }
+                datalist { id: "filter-projects",
+                    for project in projects {
+                        option { value: "{project}" }
+                    }
+                }
+            }
+
+            span { class: "hud-text", "{i18n.t(\"DATE\")}" }
+            div { class: "flex flex-wrap items-center gap-2",
+                select { class: "{field_class}",
+                    value: if filter.date_field == DateField::Due { "due" } else { "scheduled" },
+                    onchange: {
+                        let update = update.clone();
+                        move |evt: FormEvent| update(&|f| {
+                            f.date_field = if evt.value() == "scheduled" { DateField::Scheduled } else { DateField::Due };
+                        })
+                    },
+                    option { value: "due", "{i18n.t(\"Due date\")}" }
+                    option { value: "scheduled", "{i18n.t(\"Scheduled date\")}" }
+                }
+                select { class: "{field_class}", value: "{window_value}",
+                    onchange: {
+                        let update = update.clone();
+                        move |evt: FormEvent| update(&|f| f.window = match evt.value().as_str() {
+                            "overdue" => DateWindow::Overdue,
+                            "today" => DateWindow::Today,
+                            "week" => DateWindow::ThisWeek,
+                            "next" => DateWindow::NextDays(7),
+                            "custom" => DateWindow::Between(None, None),
+                            _ => DateWindow::Any,
+                        })
+                    },
+                    option { value: "any", "{i18n.t(\"Any time\")}" }
+                    option { value: "overdue", "{i18n.t(\"Overdue\")}" }
+                    option { value: "today", "{i18n.t(\"Today\")}" }
+                    option { value: "week", "{i18n.t(\"This week\")}" }
+                    option { value: "next", "{i18n.t(\"Next N days\")}" }
+                    option { value: "custom", "{i18n.t(\"Between…\")}" }
+                }
+                if let DateWindow::NextDays(days) = filter.window {
+                    input { r#type: "number", min: "1", class: "{field_class} w-20", value: "{days}",
+                        onchange: {
+                            let update = update.clone();
+                            move |evt: FormEvent| {
+                                if let Ok(days) = evt.value().trim().parse::<i64>() {
+                                    update(&|f| f.window = DateWindow::NextDays(days.max(1)));
+                                }
+                            }
+                        },
+                    }
+                    span { class: "text-gray-500", "{i18n.t(\"days\")}" }
+                }
+                if window_value == "custom" {
+                    input { r#type: "date", class: "{field_class}", value: "{date_input(range_from)}",
+                        onchange: {
+                            let update = update.clone();
+                            move |evt: FormEvent| {
+                                let from = NaiveDate::parse_from_str(&evt.value(), "%Y-%m-%d").ok();
+                                update(&|f| f.window = DateWindow::Between(from, range_to));
+                            }
+                        },
+                    }
+                    span { class: "text-gray-500", "{i18n.t(\"to\")}" }
+                    input { r#type: "date", class: "{field_class}", value: "{date_input(range_to)}",
+                        onchange: {
+                            let update = update.clone();
+                            move |evt: FormEvent| {
+                                let to = NaiveDate::parse_from_str(&evt.value(), "%Y-%m-%d").ok();
+                                update(&|f| f.window = DateWindow::Between(range_from, to));
+                            }
+                        },
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Text color that tints a card like its matrix quadrant
+fn quadrant_text_color(q: &Quadrant) -> &'static str {
+    match q {
+        Quadrant::DoFirst => "text-neon-pink",
+        Quadrant::Schedule => "text-neon-cyan",
+        Quadrant::Delegate => "text-neon-amber",
+        Quadrant::Delete => "text-gray-400",
+        Quadrant::Unsorted => "text-gray-200",
+    }
+}
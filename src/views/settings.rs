@@ -0,0 +1,621 @@
+use dioxus::prelude::*;
+use chrono::Weekday;
+use crate::caldav::{self, CalDavState};
+use crate::components::Shortcuts;
+use crate::feed::{self, FeedState};
+use crate::i18n::{Clock, Locale};
+use crate::models::Quadrant;
+use crate::settings::{ProtectedBlock, SettingsState, WorkHours};
+use crate::store::AppState;
+use crate::theme::{Theme, SYSTEM_THEME};
+
+/// SETTINGS VIEW: Every preference stored in settings.json, saved as soon as it changes
+#[component]
+pub fn Settings() -> Element {
+    let settings_state = use_context::<SettingsState>();
+    let mut shortcuts = use_context::<Shortcuts>();
+    let feed_state = use_context::<FeedState>();
+    let app_state = use_context::<AppState>();
+    let caldav = use_context::<CalDavState>();
+    let settings = settings_state.settings.read().clone();
+    let calendar = settings.calendar.clone();
+    let i18n = settings_state.i18n();
+    let mut theme_error = use_signal(|| None::<String>);
+    let mut keyring_error = use_signal(|| None::<String>);
+    // Credentials for a plain http:// server on another machine would travel unencrypted
+    let in_clear = !settings.caldav.username.is_empty()
+        && caldav::Client::new(&settings.caldav.url, "", "").is_ok_and(|client| client.sends_in_clear());
+    let theme_names: Vec<String> = Theme::built_in()
+        .into_iter()
+        .chain(settings.custom_themes.iter().cloned())
+        .map(|t| t.name)
+        .collect();
+
+    // Reads a theme file and switches to it
+    let import_theme = move |_| {
+        spawn(async move {
+            let Some(handle) = rfd::AsyncFileDialog::new()
+                .set_title(i18n.t("Import theme"))
+                .add_filter(i18n.t("Theme"), &["json"])
+                .pick_file()
+                .await
+            else {
+                return;
+            };
+            match serde_json::from_slice::<Theme>(&handle.read().await) {
+                Ok(theme) => match settings_state.import_theme(theme) {
+                    Ok(()) => theme_error.set(None),
+                    Err(e) => theme_error.set(Some(i18n.tf("Not a theme file: {error}", &[("error", &e)]))),
+                },
+                Err(e) => theme_error.set(Some(i18n.tf("Not a theme file: {error}", &[("error", &e)]))),
+            }
+        });
+    };
+
+    // Writes the theme currently in use to a file, as a starting point for a custom one
+    let export_theme = move |_| {
+        let theme = {
+            let settings = settings_state.settings.read();
+            Theme::resolve(&settings.theme, &settings.custom_themes)
+        };
+        spawn(async move {
+            let Some(handle) = rfd::AsyncFileDialog::new()
+                .set_title(i18n.t("Export theme"))
+                .set_file_name(format!("{}.json", theme.name))
+                .add_filter(i18n.t("Theme"), &["json"])
+                .save_file()
+                .await
+            else {
+                return;
+            };
+            let written = serde_json::to_string_pretty(&theme)
+                .map_err(|e| e.to_string())
+                .and_then(|content| std::fs::write(handle.path(), content).map_err(|e| e.to_string()));
+            match written {
+                Ok(()) => theme_error.set(None),
+                Err(e) => theme_error.set(Some(i18n.tf("Could not save the theme: {error}", &[("error", &e)]))),
+            }
+        });
+    };
+
+    let field_class = "bg-space-900 border border-space-700 rounded px-2 py-1 text-white font-mono focus:border-neon-cyan focus:outline-none";
+    let weekdays = [Weekday::Mon, Weekday::Sun, Weekday::Sat];
+    // All seven days, in the calendar's order
+    let full_week: Vec<Weekday> = std::iter::successors(Some(calendar.week_starts_on), |d| Some(d.succ())).take(7).collect();
+    let wip_rows = [
+        (Quadrant::DoFirst, "Do First"),
+        (Quadrant::Schedule, "Schedule"),
+        (Quadrant::Delegate, "Delegate"),
+        (Quadrant::Delete, "Delete"),
+    ];
+
+    rsx! {
+        div { class: "flex-1 h-full p-8 overflow-y-auto",
+            div { class: "w-full max-w-3xl mx-auto space-y-8",
+                // PAGE HEADER
+                div { class: "border-b border-gray-800 pb-6",
+                    h1 { class: "text-4xl font-bold mb-2 text-white font-sans tracking-tight", "{i18n.t(\"SETTINGS\")}" }
+                    h2 { class: "text-xl font-mono text-neon-cyan/80 tracking-widest", "{i18n.t(\"SYSTEM // PREFERENCES\")}" }
+                }
+
+                // LOAD PROBLEM: settings.json was there but couldn't be read
+                if let Some(problem) = settings_state.load_problem.read().clone() {
+                    div { class: "border border-red-500 rounded p-4 text-sm text-red-400 font-mono",
+                        {i18n.tf("settings.json could not be read: {error}", &[("error", &problem.message)])}
+                    }
+                }
+
+                // APPEARANCE: Color theme
+                SettingsSection { title: i18n.t("Appearance"),
+                    SettingRow { label: i18n.t("Theme"), hint: i18n.t("\"System\" follows the light/dark setting of your OS"),
+                        select { class: "{field_class}", value: "{settings.theme}",
+                            onchange: move |evt| settings_state.update(|s| s.theme = evt.value()),
+                            option { value: SYSTEM_THEME, "{i18n.t(\"System\")}" }
+                            for name in theme_names {
+                                option { key: "{name}", value: "{name}", "{name}" }
+                            }
+                        }
+                    }
+                    SettingRow { label: i18n.t("Theme files"), hint: i18n.t("JSON with a name and #rrggbb colors; missing colors come from Deep Space"),
+                        button {
+                            class: "px-3 py-1 rounded border border-space-700 text-gray-300 hover:text-white text-xs font-bold uppercase tracking-wide",
+                            onclick: import_theme,
+                            "{i18n.t(\"Import…\")}"
+                        }
+                        button {
+                            class: "px-3 py-1 rounded border border-space-700 text-gray-300 hover:text-white text-xs font-bold uppercase tracking-wide",
+                            onclick: export_theme,
+                            "{i18n.t(\"Export…\")}"
+                        }
+                    }
+                    if let Some(error) = theme_error() {
+                        p { class: "text-xs font-mono text-red-400", "{error}" }
+                    }
+                }
+
+                // LANGUAGE & REGION: Interface language and how dates and times are written
+                SettingsSection { title: i18n.t("Language & region"),
+                    SettingRow { label: i18n.t("Language"), hint: i18n.t("Also resets the clock and first day of the week to the language's usual ones"),
+                        select { class: "{field_class}", value: "{settings.locale.code()}",
+                            onchange: move |evt| {
+                                if let Some(locale) = Locale::from_code(&evt.value()) {
+                                    settings_state.set_locale(locale);
+                                }
+                            },
+                            for locale in Locale::ALL {
+                                option { value: "{locale.code()}", "{locale.native_name()}" }
+                            }
+                        }
+                    }
+                    SettingRow { label: i18n.t("Clock"), hint: i18n.t("How times appear in the calendar and tooltips"),
+                        select { class: "{field_class}", value: if settings.clock == Clock::H12 { "12h" } else { "24h" },
+                            onchange: move |evt| {
+                                let clock = if evt.value() == "12h" { Clock::H12 } else { Clock::H24 };
+                                settings_state.update(|s| s.clock = clock);
+                            },
+                            option { value: "12h", "{i18n.t(\"12-hour\")}" }
+                            option { value: "24h", "{i18n.t(\"24-hour\")}" }
+                        }
+                    }
+                }
+
+                // CALENDAR: Grid layout and drop behaviour
+                SettingsSection { title: i18n.t("Calendar"),
+                    SettingRow { label: i18n.t("Visible hours"), hint: i18n.t("Hours outside this range are hidden from the week grid"),
+                        select { class: "{field_class}", value: "{calendar.day_start_hour}",
+                            onchange: move |evt| {
+                                if let Ok(hour) = evt.value().parse::<u32>() {
+                                    settings_state.update(|s| s.calendar.day_start_hour = hour);
+                                }
+                            },
+                            for hour in 0..24u32 {
+                                option { value: "{hour}", "{i18n.hour(hour)}" }
+                            }
+                        }
+                        span { class: "text-gray-500", "{i18n.t(\"to\")}" }
+                        select { class: "{field_class}", value: "{calendar.day_end_hour}",
+                            onchange: move |evt| {
+                                if let Ok(hour) = evt.value().parse::<u32>() {
+                                    settings_state.update(|s| s.calendar.day_end_hour = hour);
+                                }
+                            },
+                            for hour in 1..=24u32 {
+                                option { value: "{hour}", "{i18n.hour(hour)}" }
+                            }
+                        }
+                    }
+                    SettingRow { label: i18n.t("Week starts on"), hint: i18n.t("First column of the week grid"),
+                        select { class: "{field_class}", value: "{calendar.week_starts_on}",
+                            onchange: move |evt| {
+                                if let Ok(day) = evt.value().parse::<Weekday>() {
+                                    settings_state.update(|s| s.calendar.week_starts_on = day);
+                                }
+                            },
+                            for day in weekdays {
+                                option { value: "{day}", "{i18n.weekday(day)}" }
+                            }
+                        }
+                    }
+                    SettingRow { label: i18n.t("Default block"), hint: i18n.t("Length of a task the first time it is scheduled"),
+                        input { r#type: "number", min: "5", step: "5", class: "{field_class} w-20", value: "{calendar.default_block_minutes}",
+                            onchange: move |evt| {
+                                if let Ok(minutes) = evt.value().trim().parse::<i64>() {
+                                    settings_state.update(|s| s.calendar.default_block_minutes = minutes);
+                                }
+                            },
+                        }
+                        span { class: "text-gray-500", "{i18n.t(\"minutes\")}" }
+                    }
+                    SettingRow { label: i18n.t("Snap to"), hint: i18n.t("Resolution for dropping and resizing blocks"),
+                        select { class: "{field_class}", value: "{calendar.snap_minutes}",
+                            onchange: move |evt| {
+                                if let Ok(minutes) = evt.value().parse::<u32>() {
+                                    settings_state.update(|s| s.calendar.snap_minutes = minutes);
+                                }
+                            },
+                            for minutes in [5u32, 10, 15, 30, 60] {
+                                option { value: "{minutes}", "{minutes} min" }
+                            }
+                        }
+                    }
+                }
+
+                // AVAILABILITY: Working hours and protected time, shaded on the calendar
+                SettingsSection { title: i18n.t("Availability"),
+                    div { class: "space-y-2 text-sm",
+                        p { class: "text-gray-200 font-medium", "{i18n.t(\"Working hours\")}" }
+                        p { class: "text-xs text-gray-500", "{i18n.t(\"Shaded on the calendar; Plan my week and drops stay inside them\")}" }
+                        for day in full_week.iter().copied() {
+                            {
+                                let hours = calendar.work_hours_on(day);
+                                let (start, end) = hours.unwrap_or((9, 17));
+                                rsx! {
+                                    div { key: "{day}", class: "flex items-center gap-3",
+                                        label { class: "w-32 flex items-center gap-2 text-gray-300",
+                                            input {
+                                                r#type: "checkbox",
+                                                checked: hours.is_some(),
+                                                onchange: move |evt| {
+                                                    settings_state.update(|s| {
+                                                        s.calendar.work_hours.retain(|w| w.day != day);
+                                                        if evt.checked() {
+                                                            s.calendar.work_hours.push(WorkHours { day, start_hour: start, end_hour: end });
+                                                        }
+                                                    });
+                                                },
+                                            }
+                                            "{i18n.weekday(day)}"
+                                        }
+                                        select { class: "{field_class}", value: "{start}", disabled: hours.is_none(),
+                                            onchange: move |evt| {
+                                                if let Ok(hour) = evt.value().parse::<u32>() {
+                                                    settings_state.update(|s| {
+                                                        if let Some(w) = s.calendar.work_hours.iter_mut().find(|w| w.day == day) {
+                                                            w.start_hour = hour;
+                                                        }
+                                                    });
+                                                }
+                                            },
+                                            for hour in 0..24u32 {
+                                                option { value: "{hour}", "{i18n.hour(hour)}" }
+                                            }
+                                        }
+                                        span { class: "text-gray-500", "{i18n.t(\"to\")}" }
+                                        select { class: "{field_class}", value: "{end}", disabled: hours.is_none(),
+                                            onchange: move |evt| {
+                                                if let Ok(hour) = evt.value().parse::<u32>() {
+                                                    settings_state.update(|s| {
+                                                        if let Some(w) = s.calendar.work_hours.iter_mut().find(|w| w.day == day) {
+                                                            w.end_hour = hour;
+                                                        }
+                                                    });
+                                                }
+                                            },
+                                            for hour in 1..=24u32 {
+                                                option { value: "{hour}", "{i18n.hour(hour)}" }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    SettingRow { label: i18n.t("Off-hours drops"), hint: i18n.t("What happens to a task dropped outside working hours or on protected time"),
+                        select { class: "{field_class}", value: if calendar.snap_to_work_hours { "snap" } else { "warn" },
+                            onchange: move |evt| {
+                                let snap = evt.value() == "snap";
+                                settings_state.update(|s| s.calendar.snap_to_work_hours = snap);
+                            },
+                            option { value: "warn", "{i18n.t(\"Warn\")}" }
+                            option { value: "snap", "{i18n.t(\"Move to the next open slot\")}" }
+                        }
+                    }
+                    div { class: "space-y-2 text-sm",
+                        p { class: "text-gray-200 font-medium", "{i18n.t(\"Protected blocks\")}" }
+                        p { class: "text-xs text-gray-500", {i18n.t("Recurring focus time such as \"Deep work 9–11\" or \"No meetings\" all Friday")} }
+                        for (i, block) in calendar.protected_blocks.iter().cloned().enumerate() {
+                            {
+                                let all_day = block.start_minute == 0 && block.end_minute == 24 * 60;
+                                rsx! {
+                                    div { key: "{i}", class: "flex flex-wrap items-center gap-2 py-1",
+                                        input {
+                                            class: "{field_class} w-36",
+                                            value: "{block.label}",
+                                            aria_label: i18n.t("Label"),
+                                            onchange: move |evt| {
+                                                let label = evt.value();
+                                                settings_state.update(|s| {
+                                                    if let Some(block) = s.calendar.protected_blocks.get_mut(i) {
+                                                        block.label = label;
+                                                    }
+                                                });
+                                            },
+                                        }
+                                        for day in full_week.iter().copied() {
+                                            {
+                                                let on = block.days.contains(&day);
+                                                rsx! {
+                                                    button {
+                                                        class: if on { "px-1.5 py-0.5 rounded border border-neon-purple text-neon-purple text-xs" } else { "px-1.5 py-0.5 rounded border border-space-700 text-gray-500 text-xs hover:text-gray-300" },
+                                                        aria_pressed: on,
+                                                        title: "{i18n.weekday(day)}",
+                                                        onclick: move |_| {
+                                                            settings_state.update(|s| {
+                                                                let Some(block) = s.calendar.protected_blocks.get_mut(i) else { return };
+                                                                let days = &mut block.days;
+                                                                if let Some(pos) = days.iter().position(|d| *d == day) {
+                                                                    days.remove(pos);
+                                                                } else {
+                                                                    days.push(day);
+                                                                }
+                                                            });
+                                                        },
+                                                        "{i18n.weekday_short(day)}"
+                                                    }
+                                                }
+                                            }
+                                        }
+                                        label { class: "flex items-center gap-1 text-xs text-gray-400",
+                                            input {
+                                                r#type: "checkbox",
+                                                checked: all_day,
+                                                onchange: move |evt| {
+                                                    let (start, end) = if evt.checked() { (0, 24 * 60) } else { (9 * 60, 11 * 60) };
+                                                    settings_state.update(|s| {
+                                                        if let Some(block) = s.calendar.protected_blocks.get_mut(i) {
+                                                            block.start_minute = start;
+                                                            block.end_minute = end;
+                                                        }
+                                                    });
+                                                },
+                                            }
+                                            "{i18n.t(\"All day\")}"
+                                        }
+                                        if !all_day {
+                                            input {
+                                                r#type: "time",
+                                                class: "{field_class}",
+                                                value: "{clock_value(block.start_minute)}",
+                                                onchange: move |evt| {
+                                                    if let Some(minute) = parse_clock(&evt.value()) {
+                                                        settings_state.update(|s| {
+                                                            if let Some(block) = s.calendar.protected_blocks.get_mut(i) {
+                                                                block.start_minute = minute;
+                                                            }
+                                                        });
+                                                    }
+                                                },
+                                            }
+                                            span { class: "text-gray-500", "{i18n.t(\"to\")}" }
+                                            input {
+                                                r#type: "time",
+                                                class: "{field_class}",
+                                                value: "{clock_value(block.end_minute)}",
+                                                onchange: move |evt| {
+                                                    if let Some(minute) = parse_clock(&evt.value()) {
+                                                        settings_state.update(|s| {
+                                                            if let Some(block) = s.calendar.protected_blocks.get_mut(i) {
+                                                                block.end_minute = minute;
+                                                            }
+                                                        });
+                                                    }
+                                                },
+                                            }
+                                        }
+                                        button {
+                                            class: "w-6 h-6 rounded-full text-gray-500 hover:text-white hover:bg-space-700 transition-colors",
+                                            aria_label: i18n.t("Remove"),
+                                            onclick: move |_| {
+                                                settings_state.update(|s| {
+                                                    if i < s.calendar.protected_blocks.len() {
+                                                        s.calendar.protected_blocks.remove(i);
+                                                    }
+                                                });
+                                            },
+                                            "×"
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                        button {
+                            class: "px-3 py-1 rounded border border-space-700 text-gray-300 hover:text-white text-xs font-bold uppercase tracking-wide",
+                            onclick: move |_| settings_state.update(|s| s.calendar.protected_blocks.push(ProtectedBlock::default())),
+                            "{i18n.t(\"Add protected block\")}"
+                        }
+                    }
+                }
+
+                // CALENDAR FEED: Live subscription for calendar apps on this computer
+                SettingsSection { title: i18n.t("Calendar feed"),
+                    SettingRow { label: i18n.t("Serve a calendar feed"), hint: i18n.t("Calendar apps on this computer can subscribe to the export (same choices as Export Calendar)"),
+                        input {
+                            r#type: "checkbox",
+                            checked: settings.feed.enabled,
+                            onchange: move |evt| {
+                                settings_state.update(|s| {
+                                    s.feed.enabled = evt.checked();
+                                    if s.feed.token.is_empty() {
+                                        s.feed.token = feed::new_token();
+                                    }
+                                });
+                            },
+                        }
+                    }
+                    SettingRow { label: i18n.t("Port"), hint: i18n.t("Only reachable from this computer (127.0.0.1)"),
+                        input { r#type: "number", min: "1024", max: "65535", class: "{field_class} w-24", value: "{settings.feed.port}",
+                            onchange: move |evt| {
+                                if let Some(port) = evt.value().trim().parse::<u16>().ok().filter(|p| *p >= 1024) {
+                                    settings_state.update(|s| s.feed.port = port);
+                                }
+                            },
+                        }
+                    }
+                    if settings.feed.enabled {
+                        {
+                            let url = feed::feed_url(settings.feed.port, &settings.feed.token);
+                            rsx! {
+                                div { class: "space-y-2 text-sm",
+                                    div { class: "flex items-center gap-2",
+                                        input {
+                                            r#type: "text",
+                                            readonly: true,
+                                            aria_label: i18n.t("Feed URL"),
+                                            class: "{field_class} flex-1 text-xs",
+                                            value: "{url}",
+                                            onfocus: move |_| { document::eval("document.activeElement?.select();"); },
+                                        }
+                                        button {
+                                            class: "px-3 py-1 rounded border border-space-700 text-gray-300 hover:text-white text-xs font-bold uppercase tracking-wide",
+                                            onclick: {
+                                                let url = url.clone();
+                                                move |_| { document::eval(&format!("navigator.clipboard.writeText({url:?});")); }
+                                            },
+                                            "{i18n.t(\"Copy\")}"
+                                        }
+                                        button {
+                                            class: "px-3 py-1 rounded border border-space-700 text-gray-300 hover:text-white text-xs font-bold uppercase tracking-wide",
+                                            title: i18n.t("Existing subscriptions stop working"),
+                                            onclick: move |_| settings_state.update(|s| s.feed.token = feed::new_token()),
+                                            "{i18n.t(\"New link\")}"
+                                        }
+                                    }
+                                    if let Some(error) = (feed_state.error)() {
+                                        p { class: "text-xs font-mono text-red-400", role: "alert", "{error}" }
+                                    } else if feed_state.is_running() {
+                                        p { class: "text-xs text-gray-500", {i18n.t("Serving. Keep the link private: anyone with it can read your schedule.")} }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                // CALDAV SYNC: Two-way sync with a self-hosted calendar server
+                SettingsSection { title: i18n.t("CalDAV sync"),
+                    SettingRow { label: i18n.t("Collection URL"), hint: i18n.t("A calendar on Radicale, Nextcloud or similar (empty = off)"),
+                        input { r#type: "url", class: "{field_class} w-72 text-xs", placeholder: "https://cloud.example.com/remote.php/dav/calendars/me/tasks/", value: "{settings.caldav.url}",
+                            onchange: move |evt| settings_state.update(|s| s.caldav.url = evt.value().trim().to_string()),
+                        }
+                    }
+                    if in_clear {
+                        p { class: "text-xs font-mono text-red-400", role: "alert",
+                            {i18n.t("This address is plain http://, so the username and password cross the network unencrypted. Use https:// unless the server runs on this computer.")}
+                        }
+                    }
+                    SettingRow { label: i18n.t("Username"), hint: i18n.t("Leave empty if the server asks for none"),
+                        input { r#type: "text", autocomplete: "username", class: "{field_class} w-48", value: "{settings.caldav.username}",
+                            onchange: move |evt| settings_state.update(|s| s.caldav.username = evt.value()),
+                        }
+                    }
+                    SettingRow { label: i18n.t("Password"), hint: i18n.t("Saved in the system keyring; prefer an app password"),
+                        input { r#type: "password", autocomplete: "current-password", class: "{field_class} w-48",
+                            placeholder: if settings.caldav.in_keyring { i18n.t("Saved in keyring") } else { "" },
+                            value: "{settings.caldav.password}",
+                            onchange: move |evt| match settings_state.set_caldav_password(evt.value()) {
+                                Ok(()) => keyring_error.set(None),
+                                Err(e) => keyring_error.set(Some(e)),
+                            },
+                        }
+                    }
+                    if !settings.caldav.password.is_empty() {
+                        p { class: "text-xs font-mono text-red-400", role: "alert",
+                            "{i18n.t(\"No system keyring is available, so the password is stored unencrypted in settings.json.\")}"
+                            if let Some(error) = keyring_error() { " ({error})" }
+                        }
+                    }
+                    SettingRow { label: i18n.t("Sync now"), hint: i18n.t("Sends the tasks Export Calendar covers and takes back times moved on the server"),
+                        button {
+                            class: "px-3 py-1 rounded border border-space-700 text-gray-300 hover:text-white text-xs font-bold uppercase tracking-wide disabled:opacity-40",
+                            disabled: settings.caldav.url.is_empty() || (caldav.running)(),
+                            onclick: move |_| { spawn(caldav.sync_now(app_state, settings_state)); },
+                            "{i18n.t(\"Sync\")}"
+                        }
+                    }
+                    if let Some(status) = (caldav.status)() {
+                        p { class: "text-xs font-mono text-gray-500", role: "status", "{status}" }
+                    }
+                }
+
+                // WORKFLOW: Limits and clean-up
+                SettingsSection { title: i18n.t("Workflow"),
+                    for (quadrant, label) in wip_rows {
+                        SettingRow { label: i18n.tf("{quadrant} limit", &[("quadrant", &i18n.t(label))]), hint: i18n.t("Open tasks allowed before the matrix asks to demote (empty = no limit)"),
+                            input { r#type: "number", min: "1", class: "{field_class} w-20",
+                                value: settings.wip_limits.get(&quadrant).map(|l| l.to_string()).unwrap_or_default(),
+                                onchange: move |evt| {
+                                    let limit = evt.value().trim().parse::<usize>().ok().filter(|l| *l > 0);
+                                    settings_state.set_wip_limit(&quadrant, limit);
+                                },
+                            }
+                        }
+                    }
+                    SettingRow { label: i18n.t("Stale after"), hint: i18n.t("Untouched open tasks show up in the Stale filter"),
+                        input { r#type: "number", min: "1", class: "{field_class} w-20", value: "{settings.stale_after_days}",
+                            onchange: move |evt| {
+                                if let Ok(days) = evt.value().trim().parse::<i64>() {
+                                    settings_state.set_stale_after_days(days);
+                                }
+                            },
+                        }
+                        span { class: "text-gray-500", "{i18n.t(\"days\")}" }
+                    }
+                    SettingRow { label: i18n.t("Archive done after"), hint: i18n.t("Finished tasks move to the Archive (0 = right away)"),
+                        input { r#type: "number", min: "0", class: "{field_class} w-20", value: "{settings.archive_after_days}",
+                            onchange: move |evt| {
+                                if let Ok(days) = evt.value().trim().parse::<i64>() {
+                                    settings_state.set_archive_after_days(days);
+                                }
+                            },
+                        }
+                        span { class: "text-gray-500", "{i18n.t(\"days\")}" }
+                    }
+                }
+
+                // WINDOW & KEYBOARD
+                SettingsSection { title: i18n.t("Window"),
+                    SettingRow { label: i18n.t("Window size"), hint: i18n.t("Applied the next time Errday starts"),
+                        input { r#type: "number", min: "1000", step: "10", class: "{field_class} w-24", value: "{settings.window_width}",
+                            onchange: move |evt| {
+                                if let Ok(width) = evt.value().trim().parse::<f64>() {
+                                    settings_state.update(|s| s.window_width = width.max(1000.0));
+                                }
+                            },
+                        }
+                        span { class: "text-gray-500", "×" }
+                        input { r#type: "number", min: "700", step: "10", class: "{field_class} w-24", value: "{settings.window_height}",
+                            onchange: move |evt| {
+                                if let Ok(height) = evt.value().trim().parse::<f64>() {
+                                    settings_state.update(|s| s.window_height = height.max(700.0));
+                                }
+                            },
+                        }
+                    }
+                    SettingRow { label: i18n.t("Keyboard shortcuts"), hint: i18n.t("Rebind them under \"keymap\" in settings.json"),
+                        button {
+                            class: "px-3 py-1 rounded border border-space-700 text-gray-300 hover:text-white text-xs font-bold uppercase tracking-wide",
+                            onclick: move |_| shortcuts.cheat_sheet_open.set(true),
+                            "{i18n.t(\"Show shortcuts\")}"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Minutes after midnight as the "HH:MM" value of a time input
+fn clock_value(minutes: u32) -> String {
+    format!("{:02}:{:02}", minutes / 60, minutes % 60)
+}
+
+/// A time input's "HH:MM" as minutes after midnight
+fn parse_clock(value: &str) -> Option<u32> {
+    let (hours, minutes) = value.split_once(':')?;
+    Some(hours.parse::<u32>().ok()? * 60 + minutes.get(..2)?.parse::<u32>().ok()?)
+}
+
+/// SETTINGS SECTION: A titled panel of rows
+#[component]
+fn SettingsSection(title: &'static str, children: Element) -> Element {
+    rsx! {
+        div { class: "glass-panel rounded-xl p-6 space-y-4",
+            p { class: "hud-text", "{title}" }
+            {children}
+        }
+    }
+}
+
+/// SETTING ROW: Label and explanation on the left, controls on the right
+#[component]
+fn SettingRow(label: String, hint: &'static str, children: Element) -> Element {
+    rsx! {
+        div { class: "flex justify-between items-center gap-6 text-sm",
+            div {
+                p { class: "text-gray-200 font-medium", "{label}" }
+                p { class: "text-xs text-gray-500", "{hint}" }
+            }
+            div { class: "flex items-center gap-2 shrink-0", {children} }
+        }
+    }
+}
@@ -0,0 +1,332 @@
+use dioxus::prelude::*;
+use std::collections::HashSet;
+use std::rc::Rc;
+use crate::components::ScoreAssistant;
+use crate::models::{Quadrant, Task, TaskStatus};
+use crate::routes::Route;
+use crate::settings::SettingsState;
+use crate::store::AppState;
+
+/// TRIAGE STEP: What happened to one task during the session (None = skipped)
+#[derive(Clone, PartialEq)]
+struct TriageStep {
+    id: uuid::Uuid,
+    title: String,
+    outcome: Option<Quadrant>,
+    scores: (Option<f32>, Option<f32>), // (urgency, importance) before the decision, restored by undo
+    checkpoint: Option<u64>,            // The store checkpoint taken by the move, see AppState::undo_if_last
+}
+
+/// TRIAGE VIEW: Shows one Unsorted task at a time and sorts it with a single key press
+/// 1-4 = Do First / Schedule / Delegate / Delete, S = skip, E = edit, Q = assess, Backspace = undo
+#[component]
+pub fn Triage() -> Element {
+    let app_state = use_context::<AppState>();
+    let settings_state = use_context::<SettingsState>();
+    let i18n = settings_state.i18n();
+
+    // Stage 1: Take a snapshot of the backlog so the queue doesn't shift while sorting
+    let mut queue = use_signal(|| unsorted_ids(&app_state));
+    let mut position = use_signal(|| 0usize);
+    let mut history = use_signal(Vec::<TriageStep>::new);
+    // A decision held back by the WIP demote prompt; it only counts once the task has really moved
+    let mut awaiting = use_signal(|| None::<(usize, TriageStep)>);
+
+    // Stage 2: Inline title editing and keyboard focus
+    let mut editing = use_signal(|| false);
+    let mut edit_val = use_signal(String::new);
+    let mut focus_target = use_signal(|| None::<Rc<MountedData>>);
+    // Optional urgency/importance questionnaire for the current task
+    let mut assessing = use_signal(|| None::<Task>);
+
+    let current = next_in_queue(&app_state, &queue.read(), position());
+    let total = queue.read().len();
+    let done_count = history.read().len();
+
+    // Records a decision for the current task and moves on to the next one.
+    // `placed` carries the questionnaire's (urgency, importance) when it picked the quadrant.
+    let mut decide = move |outcome: Option<Quadrant>, placed: Option<(f32, f32)>| {
+        let Some((i, task)) = next_in_queue(&app_state, &queue.read(), position()) else { return };
+        let mut step = TriageStep {
+            id: task.id,
+            title: task.title,
+            outcome: outcome.clone(),
+            scores: (task.urgency, task.importance),
+            checkpoint: None,
+        };
+        if let Some(quadrant) = outcome {
+            let limits = settings_state.settings.peek().wip_limits.clone();
+            match placed {
+                Some((urgency, importance)) => app_state.place_task(task.id, urgency, importance, &limits),
+                None => app_state.move_to_quadrant(&HashSet::from([task.id]), quadrant, &limits),
+            }
+            if !left_backlog(&app_state, task.id) {
+                // Over a WIP limit: wait for the demote prompt (see the effect below)
+                awaiting.set(Some((i, step)));
+                return;
+            }
+            step.checkpoint = app_state.last_checkpoint();
+        }
+        history.write().push(step);
+        position.set(i + 1);
+    };
+
+    // Once the demote prompt closes, count the held-back decision if the task moved; after a cancel it stays current
+    use_effect(move || {
+        let pending = app_state.pending_move.read().is_some();
+        if pending || awaiting.read().is_none() {
+            return;
+        }
+        let Some((i, mut step)) = awaiting.write().take() else { return };
+        if left_backlog(&app_state, step.id) {
+            step.checkpoint = app_state.last_checkpoint();
+            history.write().push(step);
+            position.set(i + 1);
+        }
+    });
+
+    // Steps back one decision. When the move is still the latest change, the app-wide undo reverts it
+    // (bringing back anything the demote prompt moved down or purged for it); after later edits
+    // (a rename, saved scores) only this task goes back to the backlog, so those edits stay.
+    let mut undo = move || {
+        let Some(step) = history.write().pop() else { return };
+        if step.outcome.is_some() {
+            let undone = step.checkpoint.is_some_and(|serial| app_state.undo_if_last(serial));
+            if !undone {
+                app_state.unsort_task(step.id, step.scores.0, step.scores.1);
+            }
+        }
+        if let Some(i) = queue.read().iter().position(|id| *id == step.id) {
+            position.set(i);
+        }
+    };
+
+    // Puts keyboard focus back on the triage card after editing
+    let refocus = move || {
+        if let Some(el) = focus_target() {
+            spawn(async move {
+                let _ = el.set_focus(true).await;
+            });
+        }
+    };
+
+    rsx! {
+        div {
+            class: "flex-1 h-full p-8 overflow-y-auto focus:outline-none",
+            tabindex: "0",
+            onmounted: move |evt| {
+                let el = evt.data();
+                focus_target.set(Some(el.clone()));
+                spawn(async move {
+                    let _ = el.set_focus(true).await;
+                });
+            },
+            onkeydown: move |evt| {
+                // While editing, assessing or asking what to demote, the open control handles its own keys
+                if editing() || assessing().is_some() || app_state.pending_move.read().is_some() {
+                    return;
+                }
+                match evt.key() {
+                    Key::Character(c) => match c.to_lowercase().as_str() {
+                        "1" => decide(Some(Quadrant::DoFirst), None),
+                        "2" => decide(Some(Quadrant::Schedule), None),
+                        "3" => decide(Some(Quadrant::Delegate), None),
+                        "4" => decide(Some(Quadrant::Delete), None),
+                        "s" => decide(None, None),
+                        "q" => {
+                            if let Some((_, task)) = next_in_queue(&app_state, &queue.read(), position()) {
+                                assessing.set(Some(task));
+                            }
+                        }
+                        "e" => {
+                            if let Some((_, task)) = next_in_queue(&app_state, &queue.read(), position()) {
+                                evt.prevent_default();
+                                edit_val.set(task.title);
+                                editing.set(true);
+                            }
+                        }
+                        _ => {}
+                    },
+                    Key::Backspace => {
+                        evt.prevent_default();
+                        undo();
+                    }
+                    _ => {}
+                }
+            },
+
+            div { class: "w-full max-w-3xl mx-auto space-y-8",
+                // PAGE HEADER: Progress through the backlog
+                div { class: "border-b border-gray-800 pb-6 flex justify-between items-end",
+                    div {
+                        h1 { class: "text-4xl font-bold mb-2 text-white font-sans tracking-tight", "{i18n.t(\"TRIAGE\")}" }
+                        h2 { class: "text-xl font-mono text-neon-cyan/80 tracking-widest", "{i18n.t(\"ONE TASK // ONE DECISION\")}" }
+                    }
+                    span { class: "hud-text", {i18n.tf("{done} / {total} PROCESSED", &[("done", &done_count.min(total)), ("total", &total)])} }
+                }
+
+                if let Some(task) = assessing() {
+                    ScoreAssistant {
+                        task,
+                        onclose: move |_| {
+                            assessing.set(None);
+                            refocus();
+                        },
+                        // "Move to …" is a triage decision like the number keys
+                        onplace: move |(urgency, importance): (f32, f32)| {
+                            decide(Some(Quadrant::from_scores(urgency, importance)), Some((urgency, importance)));
+                        },
+                    }
+                }
+
+                if let Some((_, task)) = current {
+                    // THE CURRENT TASK: Big and centered so nothing else competes for attention
+                    div { class: "glass-panel rounded-2xl p-12 text-center space-y-4",
+                        if editing() {
+                            input {
+                                class: "w-full bg-space-900 border border-neon-cyan rounded-lg p-3 text-2xl text-white text-center focus:outline-none",
+                                value: "{edit_val}",
+                                autofocus: true,
+                                oninput: move |evt| edit_val.set(evt.value()),
+                                onkeydown: move |evt| {
+                                    if evt.key() == Key::Enter {
+                                        let title = edit_val.read().trim().to_string();
+                                        if !title.is_empty() {
+                                            app_state.update_task_title(task.id, title);
+                                        }
+                                        editing.set(false);
+                                        refocus();
+                                    } else if evt.key() == Key::Escape {
+                                        editing.set(false);
+                                        refocus();
+                                    }
+                                },
+                            }
+                        } else {
+                            p { class: "text-3xl font-bold text-white leading-snug", "{task.title}" }
+                        }
+                        if let Some(desc) = task.description.clone() {
+                            p { class: "text-sm text-gray-400 whitespace-pre-line", "{desc}" }
+                        }
+                    }
+
+                    // KEY LEGEND: Every action doubles as a clickable button
+                    div { class: "grid grid-cols-4 gap-3",
+                        TriageKey { key_label: "1", label: i18n.t("Do First"), color: "border-quadrant-do-first text-quadrant-do-first", onpress: move |_| decide(Some(Quadrant::DoFirst), None) }
+                        TriageKey { key_label: "2", label: i18n.t("Schedule"), color: "border-quadrant-schedule text-quadrant-schedule", onpress: move |_| decide(Some(Quadrant::Schedule), None) }
+                        TriageKey { key_label: "3", label: i18n.t("Delegate"), color: "border-quadrant-delegate text-quadrant-delegate", onpress: move |_| decide(Some(Quadrant::Delegate), None) }
+                        TriageKey { key_label: "4", label: i18n.t("Delete"), color: "border-quadrant-delete text-quadrant-delete", onpress: move |_| decide(Some(Quadrant::Delete), None) }
+                    }
+                    div { class: "flex justify-center gap-8 font-mono text-xs text-gray-500 uppercase tracking-widest",
+                        span { "[S] {i18n.t(\"Skip\")}" }
+                        span { "[E] {i18n.t(\"Edit\")}" }
+                        span { "[Q] {i18n.t(\"Assess\")}" }
+                        span { "[⌫] {i18n.t(\"Undo\")}" }
+                    }
+                } else {
+                    // SUMMARY: Where everything went once the queue is empty
+                    TriageSummary { steps: history.read().clone() }
+                    div { class: "flex justify-center gap-4",
+                        if !unsorted_ids(&app_state).is_empty() {
+                            button {
+                                class: "btn-primary",
+                                onclick: move |_| {
+                                    queue.set(unsorted_ids(&app_state));
+                                    position.set(0);
+                                    history.write().clear();
+                                },
+                                "{i18n.t(\"Triage Remaining\")}"
+                            }
+                        }
+                        Link { to: Route::Matrix {}, class: "btn-primary", "{i18n.t(\"Back to Matrix\")}" }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// TRIAGE KEY: One of the four quadrant buttons, labelled with its keyboard shortcut
+#[component]
+fn TriageKey(key_label: &'static str, label: &'static str, color: &'static str, onpress: EventHandler<MouseEvent>) -> Element {
+    rsx! {
+        button {
+            class: "bg-space-800 border-2 rounded-xl p-4 flex flex-col items-center gap-1 hover:bg-space-700 transition-colors {color}",
+            onclick: move |evt| onpress.call(evt),
+            span { class: "text-2xl font-mono font-bold", "{key_label}" }
+            span { class: "text-[10px] font-mono tracking-widest uppercase", "{label}" }
+        }
+    }
+}
+
+/// TRIAGE SUMMARY: Counts and lists the tasks per destination
+#[component]
+fn TriageSummary(steps: Vec<TriageStep>) -> Element {
+    let i18n = use_context::<SettingsState>().i18n();
+    let groups: Vec<(&'static str, Vec<String>)> = [
+        Some(Quadrant::DoFirst),
+        Some(Quadrant::Schedule),
+        Some(Quadrant::Delegate),
+        Some(Quadrant::Delete),
+        None,
+    ]
+    .into_iter()
+    .map(|outcome| {
+        let label = i18n.t(outcome.as_ref().map(|q| q.label()).unwrap_or("Skipped"));
+        let titles = steps.iter()
+            .filter(|s| s.outcome == outcome)
+            .map(|s| s.title.clone())
+            .collect();
+        (label, titles)
+    })
+    .collect();
+
+    rsx! {
+        div { class: "glass-panel rounded-2xl p-8 space-y-6",
+            div { class: "text-center",
+                h3 { class: "text-2xl font-bold text-white tracking-tight", "{i18n.t(\"Backlog Cleared\")}" }
+                p { class: "hud-text mt-1", {i18n.tf("{count} TASKS PROCESSED", &[("count", &steps.len())])} }
+            }
+            div { class: "grid grid-cols-5 gap-3",
+                for (label, titles) in groups {
+                    div { class: "bg-space-900/60 border border-space-700 rounded-lg p-4 space-y-2",
+                        div { class: "flex justify-between items-baseline",
+                            span { class: "text-[10px] font-mono tracking-widest uppercase text-gray-400", "{label}" }
+                            span { class: "text-xl font-bold text-white", "{titles.len()}" }
+                        }
+                        for title in titles {
+                            p { class: "text-xs text-gray-300 truncate", title: "{title}", "{title}" }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Finds the next task in the queue that still exists and is still unsorted
+fn next_in_queue(app_state: &AppState, queue: &[uuid::Uuid], from: usize) -> Option<(usize, Task)> {
+    let tasks = app_state.tasks.read();
+    queue.iter()
+        .enumerate()
+        .skip(from)
+        .find_map(|(i, id)| {
+            tasks.iter()
+                .find(|t| t.id == *id && t.quadrant == Quadrant::Unsorted)
+                .map(|t| (i, t.clone()))
+        })
+}
+
+/// Whether the task has left the backlog (moved to a quadrant, or purged by the demote prompt)
+fn left_backlog(app_state: &AppState, id: uuid::Uuid) -> bool {
+    !app_state.tasks.peek().iter().any(|t| t.id == id && t.quadrant == Quadrant::Unsorted)
+}
+
+/// Lists the ids of every task still waiting in the backlog
+fn unsorted_ids(app_state: &AppState) -> Vec<uuid::Uuid> {
+    app_state.tasks.read()
+        .iter()
+        .filter(|t| t.quadrant == Quadrant::Unsorted && t.status == TaskStatus::Todo)
+        .map(|t| t.id)
+        .collect()
+}
@@ -1,21 +1,48 @@
 /** @type {import('tailwindcss').Config} */
+// Theme colors come from CSS variables holding "R G B" triplets, so opacity modifiers still work
+const themed = (name) => `rgb(var(--${name}) / <alpha-value>)`;
+
 module.exports = {
   mode: "all",
   darkMode: 'class',
   content: ["./src/**/*.{rs,html,css}", "./dist/**/*.html"],
   theme: {
     extend: {
+      // Every color is a CSS variable so themes can swap them at runtime (see src/theme.rs)
       colors: {
+        white: themed('white'),
+        gray: {
+          100: themed('gray-100'),
+          200: themed('gray-200'),
+          300: themed('gray-300'),
+          400: themed('gray-400'),
+          500: themed('gray-500'),
+          600: themed('gray-600'),
+          700: themed('gray-700'),
+          800: themed('gray-800'),
+          900: themed('gray-900'),
+        },
+        red: {
+          400: themed('red-400'),
+          500: themed('red-500'),
+        },
         space: {
-          900: '#0B0D17', // Deep Space (Background)
-          800: '#15192b', // Space Panel (Cards)
-          700: '#232942', // Orbital (Borders/Inactive)
+          900: themed('space-900'), // Deep Space (Background)
+          800: themed('space-800'), // Space Panel (Cards)
+          700: themed('space-700'), // Orbital (Borders/Inactive)
         },
         neon: {
-          cyan: '#00f0ff', // Primary Action
-          amber: '#ffaa00', // Warning/Attention
-          green: '#00ff9d', // Success
-          pink: '#ff00d4', // Urgent
+          cyan: themed('neon-cyan'), // Primary Action
+          amber: themed('neon-amber'), // Warning/Attention
+          green: themed('neon-green'), // Success
+          pink: themed('neon-pink'), // Urgent
+          purple: themed('neon-purple'), // Matrix navigation
+        },
+        quadrant: {
+          'do-first': themed('quadrant-do-first'),
+          schedule: themed('quadrant-schedule'),
+          delegate: themed('quadrant-delegate'),
+          delete: themed('quadrant-delete'),
         }
       },
       fontFamily: {